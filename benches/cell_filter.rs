@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Margin, Rect};
+use ratatui::style::Color;
+use tachyonfx::{CellFilter, CellIterator};
+
+fn layout_filter() -> CellFilter {
+    let layout = Layout::vertical([
+        Constraint::Length(10),
+        Constraint::Fill(1),
+        Constraint::Length(10),
+    ]);
+
+    CellFilter::AllOf(vec![
+        CellFilter::Layout(layout, 1),
+        CellFilter::Outer(Margin::new(4, 2)),
+    ])
+}
+
+fn iterate(buf: &mut Buffer, area: Rect, filter: &CellFilter) {
+    CellIterator::new(buf, area, Some(filter.clone()))
+        .for_each(|(_, cell)| { black_box(cell); });
+}
+
+fn cell_filter(c: &mut Criterion) {
+    let area = Rect::new(0, 0, 400, 150);
+    let mut buf = Buffer::empty(area);
+
+    let mut group = c.benchmark_group("cell_filter_400x150");
+
+    group.bench_function("all", |b| {
+        b.iter(|| iterate(&mut buf, area, &CellFilter::All))
+    });
+
+    // positions are precomputed and cached across iterations
+    let filter = layout_filter();
+    group.bench_function("static_layout", |b| {
+        b.iter(|| iterate(&mut buf, area, &filter))
+    });
+
+    // positions are evaluated per cell, against the areas resolved per iterator
+    let filter = CellFilter::AllOf(vec![
+        layout_filter(),
+        CellFilter::apply_position_fn(|pos| pos.x % 2 == 0),
+    ]);
+    group.bench_function("layout_with_position_fn", |b| {
+        b.iter(|| iterate(&mut buf, area, &filter))
+    });
+
+    let filter = CellFilter::AllOf(vec![layout_filter(), CellFilter::FgColor(Color::Reset)]);
+    group.bench_function("layout_with_fg_color", |b| {
+        b.iter(|| iterate(&mut buf, area, &filter))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, cell_filter);
+criterion_main!(benches);
@@ -22,7 +22,7 @@ pub struct OpenWindow {
 }
 
 impl OpenWindow {
-    fn window_block(&self) -> Block {
+    fn window_block(&self) -> Block<'_> {
         Block::new()
             .borders(Borders::ALL)
             .title_style(self.title_style)
@@ -121,7 +121,7 @@ impl Shader for OpenWindow {
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
-        self.pre_render_fx.as_ref().map(Effect::cell_selection).flatten()
+        self.pre_render_fx.as_ref().and_then(Effect::cell_selection)
     }
 
     fn reset(&mut self) {
@@ -408,10 +408,7 @@ impl EventHandler {
     }
 
     fn try_next(&self) -> Option<AppEvent> {
-        match self.receiver.try_recv() {
-            Ok(e) => Some(e),
-            Err(_) => None
-        }
+        self.receiver.try_recv().ok()
     }
 
     pub(crate) fn receive_events<F>(&self, mut f: F)
@@ -441,8 +438,10 @@ fn baseline_rects() -> EffectTimelineRects {
         chart: Rect::new(35, 0, 65, 32),
         cell_filter: Rect::new(25, 0, 6, 32),
         areas: Rect::new(31, 0, 4, 32),
+        interpolation: Rect::new(35, 0, 0, 32),
         legend: Rect::new(35, 34, 29, 6),
         cell_filter_legend: Rect::new(35, 34, 9, 2),
         areas_legend: Rect::new(48, 34, 16, 2),
+        interpolation_legend: Rect::new(64, 34, 0, 2),
     }
 }
\ No newline at end of file
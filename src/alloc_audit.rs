@@ -0,0 +1,173 @@
+//! Allocation counting for auditing effects, available with the `alloc-audit` feature.
+//!
+//! Effects are processed every frame, so they should avoid allocating once they are
+//! up and running. Install [CountingAllocator] as the global allocator, then use
+//! [count_allocations] to count the allocations made by a `process` call:
+//!
+//! ```no_compile
+//! use tachyonfx::alloc_audit::{count_allocations, CountingAllocator};
+//!
+//! #[global_allocator]
+//! static ALLOCATOR: CountingAllocator = CountingAllocator;
+//!
+//! let (_, allocations) = count_allocations(|| effect.process(delta, &mut buf, area));
+//! debug_assert_eq!(allocations, 0);
+//! ```
+//!
+//! In debug builds, effects can also be checked as they are processed: within
+//! [deny_allocations], any `Effect::process` call which allocates panics, naming the
+//! offending effect.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    static DENY_ALLOCATIONS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A global allocator delegating to the [System] allocator, counting the
+/// allocations made by each thread.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        record_allocation();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        record_allocation();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn record_allocation() {
+    // the thread local may already be destroyed during thread teardown
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+/// Returns the number of allocations made by the current thread. Always 0
+/// unless [CountingAllocator] is installed as the global allocator.
+pub fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+/// Calls `f`, returning its result together with the number of allocations
+/// made by the current thread during the call.
+pub fn count_allocations<R>(f: impl FnOnce() -> R) -> (R, usize) {
+    let before = allocations();
+    let result = f();
+    (result, allocations() - before)
+}
+
+/// Calls `f`, during which any effect allocating while being processed by the current
+/// thread panics. Only checked in debug builds, and requires [CountingAllocator] to be
+/// installed as the global allocator.
+///
+/// Effects typically allocate scratch buffers on their first frame, so process them
+/// at least once before denying allocations.
+pub fn deny_allocations<R>(f: impl FnOnce() -> R) -> R {
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DENY_ALLOCATIONS.with(|deny| deny.set(self.0));
+        }
+    }
+
+    let _restore = Restore(DENY_ALLOCATIONS.with(|deny| deny.replace(true)));
+    f()
+}
+
+/// Called by `Effect::process` in debug builds, after processing the effect; panics if
+/// allocations are denied and the effect allocated since `allocations_before`.
+#[cfg(debug_assertions)]
+pub(crate) fn check_process(name: &str, allocations_before: usize) {
+    if DENY_ALLOCATIONS.with(Cell::get) {
+        let allocations = allocations() - allocations_before;
+        assert_eq!(allocations, 0, "{name} allocated {allocations} times during process");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+
+    use crate::fx::Direction;
+    use crate::{fx, Duration, Effect, Interpolation, Shader};
+
+    use super::*;
+
+    fn assert_steady_state_allocation_free(mut fx: Effect) {
+        let area = Rect::new(0, 0, 40, 10);
+        let mut buf = Buffer::empty(area);
+
+        // the first frame may allocate scratch buffers
+        fx.process(Duration::from_millis(10), &mut buf, area);
+
+        let (_, allocations) = count_allocations(|| {
+            fx.process(Duration::from_millis(10), &mut buf, area)
+        });
+        assert_eq!(allocations, 0, "{} allocated during process", fx.name());
+    }
+
+    #[test]
+    fn test_count_allocations() {
+        let (v, allocations) = count_allocations(|| vec![1, 2, 3]);
+        assert_eq!(v.len(), 3);
+        assert_eq!(allocations, 1);
+    }
+
+    #[test]
+    fn test_deny_allocations() {
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+
+        let mut fx = fx::fade_to_fg(Color::Red, (100, Interpolation::Linear));
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        deny_allocations(|| fx.process(Duration::from_millis(10), &mut buf, area));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "shader_fn allocated 1 times during process")]
+    fn test_deny_allocations_panics_on_allocation() {
+        let area = Rect::new(0, 0, 4, 1);
+        let mut buf = Buffer::empty(area);
+
+        let mut fx = fx::effect_fn((), 100, |_, _, _| {
+            std::hint::black_box(Box::new(1));
+        });
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        deny_allocations(|| fx.process(Duration::from_millis(10), &mut buf, area));
+    }
+
+    #[test]
+    fn test_sweep_and_slide_reuse_scratch_buffers() {
+        let timer = (1000, Interpolation::Linear);
+        assert_steady_state_allocation_free(
+            fx::sweep_in(Direction::UpToDown, 10, 5, Color::Black, timer));
+        assert_steady_state_allocation_free(
+            fx::slide_in(Direction::DownToUp, 10, 5, Color::Black, timer));
+    }
+
+    #[test]
+    fn test_containers_are_allocation_free() {
+        let timer = (1000, Interpolation::Linear);
+        assert_steady_state_allocation_free(fx::parallel(&[
+            fx::fade_to_fg(Color::Red, timer),
+            fx::sequence(&[fx::sleep(5), fx::dissolve(timer)]),
+        ]));
+    }
+}
@@ -1,7 +1,29 @@
 use ratatui::layout::Rect;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub(crate) struct BoundingBox {
+use crate::Interpolatable;
+
+/// A rectangle with fractional coordinates and size.
+///
+/// Terminal cells only allow for whole-cell positions, so animating an area directly
+/// on [Rect]s accumulates rounding errors. A `BoundingBox` keeps track of the exact
+/// geometry, and is only snapped to the cell grid when converted back to a [Rect]
+/// with [to_rect](BoundingBox::to_rect). This is how the `translate` and `tween_area`
+/// effects move and resize their areas smoothly.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{BoundingBox, Interpolatable};
+///
+/// let screen = Rect::new(0, 0, 80, 24);
+/// let from = BoundingBox::from_rect(Rect::new(10, 5, 20, 10));
+/// let to = from.translate(5.0, 2.0).scale(0.5, 0.5);
+///
+/// let halfway = from.lerp(&to, 0.5);
+/// assert_eq!(halfway.to_rect(screen), Some(Rect::new(15, 7, 15, 8)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BoundingBox {
     pub x: f32,
     pub y: f32,
     pub width: f32,
@@ -9,12 +31,13 @@ pub(crate) struct BoundingBox {
 }
 
 impl BoundingBox {
-    #[allow(dead_code)]
-    pub(crate) fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+    /// Creates a new bounding box.
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
         Self { x, y, width, height }
     }
 
-    pub(crate) fn from_rect(rect: Rect) -> Self {
+    /// Creates a bounding box covering the same cells as the [Rect].
+    pub fn from_rect(rect: Rect) -> Self {
         Self {
             x: rect.x as f32,
             y: rect.y as f32,
@@ -23,7 +46,10 @@ impl BoundingBox {
         }
     }
 
-    pub(crate) fn as_rect(&self, screen: Rect) -> Option<Rect> {
+    /// Snaps the bounding box to the cell grid, clipping any part extending past the
+    /// top or left edge of the screen. Returns `None` if the bounding box lies
+    /// entirely outside of the screen.
+    pub fn to_rect(&self, screen: Rect) -> Option<Rect> {
         match () {
             _ if self.x + self.width < screen.x as f32  => None,
             _ if self.y + self.height < screen.y as f32 => None,
@@ -35,16 +61,58 @@ impl BoundingBox {
                 Some(Rect::new(
                     self.x.max(0.0).round() as u16,
                     self.y.max(0.0).round() as u16,
-                    self.width.round() as u16 - dx,
-                    self.height.round() as u16 - dy
+                    (self.width.round() as u16).saturating_sub(dx),
+                    (self.height.round() as u16).saturating_sub(dy),
                 ))
             },
         }
     }
 
+    /// Moves the bounding box by the given offset.
     pub fn translate(self, dx: f32, dy: f32) -> Self {
         Self { x: self.x + dx, y: self.y + dy, ..self }
     }
+
+    /// Scales the size of the bounding box by the given factors, keeping its
+    /// center in place.
+    pub fn scale(self, sx: f32, sy: f32) -> Self {
+        let width = self.width * sx;
+        let height = self.height * sy;
+        Self {
+            x: self.x + (self.width - width) / 2.0,
+            y: self.y + (self.height - height) / 2.0,
+            width,
+            height,
+        }
+    }
+}
+
+impl From<Rect> for BoundingBox {
+    fn from(rect: Rect) -> Self {
+        Self::from_rect(rect)
+    }
+}
+
+impl Interpolatable<BoundingBox> for BoundingBox {
+    fn lerp(&self, target: &BoundingBox, alpha: f32) -> BoundingBox {
+        BoundingBox {
+            x: self.x.lerp(&target.x, alpha),
+            y: self.y.lerp(&target.y, alpha),
+            width: self.width.lerp(&target.width, alpha),
+            height: self.height.lerp(&target.height, alpha),
+        }
+    }
+}
+
+impl Interpolatable<Rect> for Rect {
+    fn lerp(&self, target: &Rect, alpha: f32) -> Rect {
+        Rect {
+            x: self.x.lerp(&target.x, alpha),
+            y: self.y.lerp(&target.y, alpha),
+            width: self.width.lerp(&target.width, alpha),
+            height: self.height.lerp(&target.height, alpha),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -55,14 +123,14 @@ mod tests {
     fn test_to_rect_clamped() {
         let bb = BoundingBox::new(-1.0, -2.0, 3.0, 4.0);
         let screen = Rect::new(0, 0, 10, 10);
-        assert_eq!(bb.as_rect(screen), Some(Rect::new(0, 0, 2, 2)));
+        assert_eq!(bb.to_rect(screen), Some(Rect::new(0, 0, 2, 2)));
     }
 
     #[test]
     fn test_to_rect_outside() {
         let bb = BoundingBox::new(-1.0, -2.0, 3.0, 4.0);
         let screen = Rect::new(5, 5, 10, 10);
-        assert_eq!(bb.as_rect(screen), None);
+        assert_eq!(bb.to_rect(screen), None);
     }
 
     #[test]
@@ -74,4 +142,20 @@ mod tests {
         assert_eq!(bb.width, 3.0);
         assert_eq!(bb.height, 4.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_scale() {
+        let bb = BoundingBox::new(2.0, 2.0, 4.0, 6.0).scale(1.5, 0.5);
+        assert_eq!(bb, BoundingBox::new(1.0, 3.5, 6.0, 3.0));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let from = BoundingBox::new(0.0, 0.0, 10.0, 10.0);
+        let to = BoundingBox::new(10.0, 4.0, 2.0, 0.0);
+        assert_eq!(from.lerp(&to, 0.25), BoundingBox::new(2.5, 1.0, 8.0, 7.5));
+
+        let rect = Rect::new(0, 0, 10, 10).lerp(&Rect::new(10, 4, 2, 0), 0.25);
+        assert_eq!(rect, Rect::new(3, 1, 8, 8));
+    }
+}
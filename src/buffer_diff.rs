@@ -0,0 +1,98 @@
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Position;
+use ratatui::style::Style;
+
+/// A cell which differs between two buffers, as returned by [buffer_diff].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellDiff {
+    /// The position of the cell.
+    pub position: Position,
+    /// The cell in the first buffer, or an empty cell if outside its area.
+    pub before: Cell,
+    /// The cell in the second buffer, or an empty cell if outside its area.
+    pub after: Cell,
+}
+
+/// Returns the cells which differ between two buffers, in row-major order.
+///
+/// Both buffers are compared over the union of their areas, with positions outside
+/// the area of a buffer treated as empty cells. Useful for effects animating changes
+/// between frames, and for describing test failures.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Position;
+/// use tachyonfx::buffer_diff;
+///
+/// let before = Buffer::with_lines(["abc"]);
+/// let after = Buffer::with_lines(["abd"]);
+///
+/// let diff = buffer_diff(&before, &after);
+/// assert_eq!(diff.len(), 1);
+/// assert_eq!(diff[0].position, Position::new(2, 0));
+/// assert_eq!(diff[0].after.symbol(), "d");
+/// ```
+pub fn buffer_diff(a: &Buffer, b: &Buffer) -> Vec<CellDiff> {
+    let cell_of = |buf: &Buffer, pos: Position| buf.cell(pos).cloned().unwrap_or_default();
+
+    a.area.union(b.area)
+        .positions()
+        .filter_map(|position| {
+            let before = cell_of(a, position);
+            let after = cell_of(b, position);
+            (before != after).then_some(CellDiff { position, before, after })
+        })
+        .collect()
+}
+
+/// Returns a copy of the buffer with the style of each differing cell patched by
+/// `highlight`, e.g. to render the changes between two frames.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::{buffer_diff, highlight_diff};
+///
+/// let before = Buffer::with_lines(["abc"]);
+/// let after = Buffer::with_lines(["abd"]);
+///
+/// let highlighted = highlight_diff(&after, &buffer_diff(&before, &after), Style::new().bg(Color::Red));
+/// assert_eq!(highlighted[(2, 0)].bg, Color::Red);
+/// assert_eq!(highlighted[(1, 0)].bg, Color::Reset);
+/// ```
+pub fn highlight_diff(buf: &Buffer, diff: &[CellDiff], highlight: Style) -> Buffer {
+    let mut buf = buf.clone();
+    for d in diff {
+        if let Some(cell) = buf.cell_mut(d.position) {
+            cell.set_style(highlight);
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn test_diff_of_different_areas() {
+        let a = Buffer::with_lines(["ab"]);
+        let mut b = Buffer::empty(Rect::new(0, 0, 3, 1));
+        b[(0, 0)].set_symbol("a");
+        b[(1, 0)].set_symbol("b").set_fg(Color::Red);
+
+        let positions: Vec<_> = buffer_diff(&a, &b).iter().map(|d| d.position).collect();
+        assert_eq!(positions, [Position::new(1, 0)]);
+
+        b[(2, 0)].set_symbol("c");
+        let diff = buffer_diff(&a, &b);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff[1].before, Cell::default());
+    }
+}
@@ -0,0 +1,127 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{ref_count, RefCount};
+
+/// A pool of reusable auxiliary buffers, for use with effects such as
+/// [offscreen_buffer](crate::fx::offscreen_buffer) and [translate_buf](crate::fx::translate_buf).
+///
+/// The pool retains every buffer it hands out. Once all other references to a buffer
+/// have been dropped - typically when the effect using it completes and is discarded -
+/// the buffer is recycled by the next call to [acquire](BufferPool::acquire), instead
+/// of allocating a new one.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{fx, BufferPool, Interpolation};
+///
+/// let mut pool = BufferPool::default();
+/// let screen = Rect::new(0, 0, 80, 24);
+///
+/// let fx = fx::offscreen_buffer(fx::dissolve((300, Interpolation::Linear)), pool.acquire(screen));
+/// assert_eq!(pool.available(), 0);
+///
+/// drop(fx);
+/// assert_eq!(pool.available(), 1);
+/// let _recycled = pool.acquire(Rect::new(0, 0, 40, 10));
+/// assert_eq!(pool.len(), 1);
+/// ```
+#[derive(Default)]
+pub struct BufferPool {
+    buffers: Vec<RefCount<Buffer>>,
+}
+
+impl BufferPool {
+    /// Returns an empty buffer covering `area`, recycling an idle buffer from the pool
+    /// if there is one. Idle buffers which can hold the area without reallocating are
+    /// preferred.
+    pub fn acquire(&mut self, area: Rect) -> RefCount<Buffer> {
+        let cells = area.area() as usize;
+        let recycled = self.idle_buffers()
+            .find(|b| with_buffer(b, |buf| buf.content.capacity() >= cells))
+            .or_else(|| self.idle_buffers().next())
+            .cloned();
+
+        match recycled {
+            Some(buffer) => {
+                with_buffer(&buffer, |buf| {
+                    buf.resize(area);
+                    buf.reset();
+                });
+                buffer
+            }
+            None => {
+                let buffer = ref_count(Buffer::empty(area));
+                self.buffers.push(buffer.clone());
+                buffer
+            }
+        }
+    }
+
+    /// Returns the number of buffers in the pool, including those in use.
+    pub fn len(&self) -> usize {
+        self.buffers.len()
+    }
+
+    /// Returns true if the pool holds no buffers.
+    pub fn is_empty(&self) -> bool {
+        self.buffers.is_empty()
+    }
+
+    /// Returns the number of idle buffers, ready to be recycled.
+    pub fn available(&self) -> usize {
+        self.idle_buffers().count()
+    }
+
+    /// Releases all idle buffers from the pool.
+    pub fn shrink(&mut self) {
+        self.buffers.retain(|b| !is_idle(b));
+    }
+
+    fn idle_buffers(&self) -> impl Iterator<Item = &RefCount<Buffer>> {
+        self.buffers.iter().filter(|b| is_idle(b))
+    }
+}
+
+fn is_idle(buffer: &RefCount<Buffer>) -> bool {
+    RefCount::strong_count(buffer) == 1
+}
+
+fn with_buffer<R>(buffer: &RefCount<Buffer>, f: impl FnOnce(&mut Buffer) -> R) -> R {
+    #[cfg(not(feature = "sendable"))]
+    return f(&mut buffer.borrow_mut());
+    #[cfg(feature = "sendable")]
+    return f(&mut buffer.lock().unwrap());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recycles_idle_buffers() {
+        let mut pool = BufferPool::default();
+
+        let a = pool.acquire(Rect::new(0, 0, 10, 10));
+        let b = pool.acquire(Rect::new(0, 0, 2, 2));
+        assert_eq!((pool.len(), pool.available()), (2, 0));
+
+        with_buffer(&a, |buf| { buf[(0, 0)].set_char('x'); });
+        drop(a);
+        drop(b);
+        assert_eq!(pool.available(), 2);
+
+        // the larger of the idle buffers is recycled, and cleared
+        let c = pool.acquire(Rect::new(0, 0, 5, 5));
+        assert_eq!(pool.len(), 2);
+        with_buffer(&c, |buf| {
+            assert_eq!(buf.area, Rect::new(0, 0, 5, 5));
+            assert!(buf.content.capacity() >= 100);
+            assert_eq!(buf[(0, 0)].symbol(), " ");
+        });
+
+        pool.shrink();
+        assert_eq!((pool.len(), pool.available()), (1, 0));
+    }
+}
@@ -1,13 +1,19 @@
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Offset, Position};
+use ratatui::layout::{Offset, Position, Rect};
 use ratatui::style::{Color, Modifier, Style};
 
+use crate::fx::ColorPalette;
+use crate::{RefCount, WeakRefCount};
+
 /// A trait for rendering the contents of one buffer onto another.
 ///
-/// This trait is primarily implemented for `Rc<RefCell<Buffer>>`, allowing
-/// for efficient rendering of one buffer's contents onto another at a specified offset.
+/// This trait is implemented for `Buffer` and shared buffers, i.e. `Rc<RefCell<Buffer>>`,
+/// `Arc<Mutex<Buffer>>` and `Arc<RwLock<Buffer>>`, allowing for efficient rendering of
+/// one buffer's contents onto another at a specified offset. A region of a buffer is
+/// rendered with `(&Buffer, Rect)`, placing the top-left corner of the region at the offset.
 /// This is useful for composing complex UI layouts or implementing effects that involve
 /// rendering one buffer onto another.
 ///
@@ -34,20 +40,88 @@ impl BufferRenderer for Rc<RefCell<Buffer>> {
     }
 }
 
-#[cfg(feature = "sendable")]
-impl BufferRenderer for crate::RefCount<Buffer> {
+impl BufferRenderer for Arc<Mutex<Buffer>> {
     fn render_buffer(&self, offset: Offset, buf: &mut Buffer) {
         (*self.lock().unwrap())
             .render_buffer(offset, buf);
     }
 }
 
+impl BufferRenderer for Arc<RwLock<Buffer>> {
+    fn render_buffer(&self, offset: Offset, buf: &mut Buffer) {
+        (*self.read().unwrap())
+            .render_buffer(offset, buf);
+    }
+}
+
 impl BufferRenderer for Buffer {
     fn render_buffer(&self, offset: Offset, buf: &mut Buffer) {
         blit_buffer(self, buf, offset);
     }
 }
 
+impl BufferRenderer for (&Buffer, Rect) {
+    fn render_buffer(&self, offset: Offset, buf: &mut Buffer) {
+        let (src, region) = *self;
+        let region = region.intersection(src.area);
+
+        for pos in region.positions() {
+            let x = offset.x + (pos.x - region.x) as i32;
+            let y = offset.y + (pos.y - region.y) as i32;
+            let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) else { continue };
+
+            let src_cell = &src[pos];
+            if let Some(cell) = buf.cell_mut(Position::new(x, y)).filter(|_| !src_cell.skip) {
+                *cell = src_cell.clone();
+            }
+        }
+    }
+}
+
+/// A shared reference to an auxiliary buffer, held either strongly or weakly.
+///
+/// Effects holding a weak reference don't keep the buffer alive, e.g. after the screen
+/// owning it is gone; such effects quietly skip rendering once the buffer is dropped.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Offset, Rect};
+/// use tachyonfx::{fx, ref_count, ref_count_weak};
+///
+/// let aux_buffer = ref_count(Buffer::empty(Rect::new(0, 0, 40, 10)));
+/// let translate = fx::translate_buf(Offset { x: 0, y: 5 }, ref_count_weak(&aux_buffer), 500);
+/// ```
+#[derive(Clone, Debug)]
+pub enum BufferRef {
+    /// Keeps the buffer alive for as long as the reference exists.
+    Strong(RefCount<Buffer>),
+    /// Doesn't keep the buffer alive.
+    Weak(WeakRefCount<Buffer>),
+}
+
+impl BufferRef {
+    /// Returns the buffer, unless it has been dropped.
+    pub fn upgrade(&self) -> Option<RefCount<Buffer>> {
+        match self {
+            BufferRef::Strong(buffer) => Some(buffer.clone()),
+            BufferRef::Weak(buffer)   => buffer.upgrade(),
+        }
+    }
+}
+
+impl From<RefCount<Buffer>> for BufferRef {
+    fn from(buffer: RefCount<Buffer>) -> Self {
+        BufferRef::Strong(buffer)
+    }
+}
+
+impl From<WeakRefCount<Buffer>> for BufferRef {
+    fn from(buffer: WeakRefCount<Buffer>) -> Self {
+        BufferRef::Weak(buffer)
+    }
+}
+
 /// Copies the contents of a source buffer onto a destination buffer with a specified offset.
 ///
 /// This function performs a "blit" operation, copying cells from the source buffer to the
@@ -59,7 +133,7 @@ impl BufferRenderer for Buffer {
 /// * `src` - The source buffer to copy from.
 /// * `dst` - The destination buffer to copy into. This buffer is modified in-place.
 /// * `offset` - The offset at which to place the top-left corner of the source buffer
-///              relative to the destination buffer. Can be negative.
+///   relative to the destination buffer. Can be negative.
 ///
 /// # Behavior
 ///
@@ -110,6 +184,55 @@ pub fn blit_buffer(
     }
 }
 
+/// Formatting options for [render_as_ansi_string_with].
+///
+/// The default options produce the same output as [render_as_ansi_string]: 24-bit colors,
+/// with the full style re-emitted on each style change and a reset at the end of each
+/// line. Enabling `run_length_styles` and `skip_trailing_reset` produces considerably
+/// smaller output, e.g. for recordings and file exports.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{render_as_ansi_string_with, AnsiRenderOptions};
+///
+/// let buf = Buffer::empty(Rect::new(0, 0, 80, 24));
+/// let ansi = render_as_ansi_string_with(&buf, AnsiRenderOptions {
+///     run_length_styles: true,
+///     skip_trailing_reset: true,
+///     ..AnsiRenderOptions::default()
+/// });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AnsiRenderOptions {
+    /// Quantizes colors to the nearest color of the palette, using the same mapping as
+    /// [fx::quantize_colors](crate::fx::quantize_colors); `None` emits RGB colors as
+    /// 24-bit colors. With [ColorPalette::Ansi256], colors are emitted as 256-color
+    /// codes. With [ColorPalette::Ansi16], the basic SGR color codes (30-37, 90-97 and
+    /// 40-47, 100-107) are emitted, for terminals and pagers with limited color support.
+    pub palette: Option<ColorPalette>,
+    /// Skips resetting the style at the end of each line, letting the style carry over
+    /// to the next line. A single reset is emitted at the end of the output.
+    pub skip_trailing_reset: bool,
+    /// Emits only the changed attributes on style changes, instead of resetting and
+    /// re-emitting the full style. Falls back to a full reset when an attribute is removed.
+    pub run_length_styles: bool,
+    /// Terminates the last line with a newline.
+    pub trailing_newline: bool,
+}
+
+impl Default for AnsiRenderOptions {
+    fn default() -> Self {
+        Self {
+            palette: None,
+            skip_trailing_reset: false,
+            run_length_styles: false,
+            trailing_newline: true,
+        }
+    }
+}
+
 /// Converts a `Buffer` to an ANSI-encoded string representation.
 ///
 /// This function takes a `Buffer` and converts it to a string that includes ANSI escape codes
@@ -124,79 +247,172 @@ pub fn blit_buffer(
 ///
 /// A `String` containing the styled representation of the buffer's content.
 pub fn render_as_ansi_string(buffer: &Buffer) -> String {
+    render_as_ansi_string_with(buffer, AnsiRenderOptions::default())
+}
+
+/// Converts a `Buffer` to an ANSI-encoded string representation, formatted according
+/// to the given [AnsiRenderOptions].
+pub fn render_as_ansi_string_with(buffer: &Buffer, options: AnsiRenderOptions) -> String {
     let mut s = String::new();
     let mut style = Style::default();
+    let basic_colors = options.palette == Some(ColorPalette::Ansi16);
 
-    for y in 0..buffer.area.height {
+    let height = buffer.area.height;
+    for y in 0..height {
         for x in 0..buffer.area.width {
             let cell = buffer.cell(Position::new(x, y)).unwrap();
-            if cell.style() != style {
-                s.push_str("\x1b[0m"); // reset
-                s.push_str(&escape_code_of(cell.style()));
-                style = cell.style();
+            let cell_style = options.convert(cell.style());
+            if cell_style != style {
+                if options.run_length_styles {
+                    s.push_str(&style_transition(style, cell_style, basic_colors));
+                } else {
+                    s.push_str(RESET);
+                    s.push_str(&escape_code_of(cell_style, basic_colors));
+                }
+                style = cell_style;
             }
             s.push_str(cell.symbol());
         }
-        s.push_str("\x1b[0m");
-        s.push('\n');
 
-        // need to reset the style at the end of each line,
-        // so that the style correctly carries over to the next line
-        style = Style::default();
+        let last_line = y + 1 == height;
+        if !options.skip_trailing_reset || (last_line && style != Style::default()) {
+            s.push_str(RESET);
+
+            // need to reset the style at the end of each line,
+            // so that the style correctly carries over to the next line
+            style = Style::default();
+        }
+
+        if !last_line || options.trailing_newline {
+            s.push('\n');
+        }
     }
     s
 }
 
-fn escape_code_of(style: Style) -> String {
+const RESET: &str = "\x1b[0m";
+
+impl AnsiRenderOptions {
+    /// Creates the default options, emitting RGB colors as 24-bit colors if `truecolor`
+    /// is true, or as codes of the 256-color palette otherwise. Shorthand for setting
+    /// `palette` to `None` or [ColorPalette::Ansi256].
+    pub fn truecolor(truecolor: bool) -> Self {
+        Self {
+            palette: (!truecolor).then_some(ColorPalette::Ansi256),
+            ..Self::default()
+        }
+    }
+
+    /// Converts the colors of the style according to the options.
+    fn convert(&self, style: Style) -> Style {
+        let Some(palette) = self.palette.as_ref() else { return style };
+
+        // named colors are supported everywhere, indexed colors only by the 256-color palette
+        let quantize = |c: Color| match c {
+            Color::Rgb(..) => palette.nearest(c),
+            Color::Indexed(_) if *palette != ColorPalette::Ansi256 => palette.nearest(c),
+            c => c,
+        };
+
+        Style {
+            fg: style.fg.map(quantize),
+            bg: style.bg.map(quantize),
+            ..style
+        }
+    }
+}
+
+/// Returns the escape codes for changing from one style to another, emitting only the
+/// changed attributes unless an attribute is removed, which requires a full reset.
+fn style_transition(from: Style, to: Style, basic_colors: bool) -> String {
+    let is_unset = |c: Option<Color>| matches!(c, None | Some(Color::Reset));
+    let removes_color = |from: Option<Color>, to: Option<Color>| !is_unset(from) && is_unset(to);
+
+    let removed_modifiers = from.add_modifier - to.add_modifier;
+    if !removed_modifiers.is_empty() || removes_color(from.fg, to.fg) || removes_color(from.bg, to.bg) {
+        return format!("{RESET}{}", escape_code_of(to, basic_colors));
+    }
+
+    let mut result = String::new();
+    if let Some(fg) = to.fg.filter(|_| to.fg != from.fg && !is_unset(to.fg)) {
+        result.push_str(&color_code(fg, true, basic_colors));
+    }
+    if let Some(bg) = to.bg.filter(|_| to.bg != from.bg && !is_unset(to.bg)) {
+        result.push_str(&color_code(bg, false, basic_colors));
+    }
+    result.push_str(&modifier_codes(to.add_modifier - from.add_modifier));
+
+    result
+}
+
+fn escape_code_of(style: Style, basic_colors: bool) -> String {
     let mut result = String::new();
 
     // Foreground color
     if let Some(color) = style.fg {
         if color != Color::Reset {
-            result.push_str(&color_code(color, true));
+            result.push_str(&color_code(color, true, basic_colors));
         }
     }
 
     // Background color
     if let Some(color) = style.bg {
         if color != Color::Reset {
-            result.push_str(&color_code(color, false));
+            result.push_str(&color_code(color, false, basic_colors));
         }
     }
 
-    // Modifiers
-    if style.add_modifier.contains(Modifier::BOLD) {
+    result.push_str(&modifier_codes(style.add_modifier));
+    result
+}
+
+fn modifier_codes(modifiers: Modifier) -> String {
+    let mut result = String::new();
+
+    if modifiers.contains(Modifier::BOLD) {
         result.push_str("\x1b[1m");
     }
-    if style.add_modifier.contains(Modifier::DIM) {
+    if modifiers.contains(Modifier::DIM) {
         result.push_str("\x1b[2m");
     }
-    if style.add_modifier.contains(Modifier::ITALIC) {
+    if modifiers.contains(Modifier::ITALIC) {
         result.push_str("\x1b[3m");
     }
-    if style.add_modifier.contains(Modifier::UNDERLINED) {
+    if modifiers.contains(Modifier::UNDERLINED) {
         result.push_str("\x1b[4m");
     }
-    if style.add_modifier.contains(Modifier::SLOW_BLINK) {
+    if modifiers.contains(Modifier::SLOW_BLINK) {
         result.push_str("\x1b[5m");
     }
-    if style.add_modifier.contains(Modifier::RAPID_BLINK) {
+    if modifiers.contains(Modifier::RAPID_BLINK) {
         result.push_str("\x1b[6m");
     }
-    if style.add_modifier.contains(Modifier::REVERSED) {
+    if modifiers.contains(Modifier::REVERSED) {
         result.push_str("\x1b[7m");
     }
-    if style.add_modifier.contains(Modifier::HIDDEN) {
+    if modifiers.contains(Modifier::HIDDEN) {
         result.push_str("\x1b[8m");
     }
-    if style.add_modifier.contains(Modifier::CROSSED_OUT) {
+    if modifiers.contains(Modifier::CROSSED_OUT) {
         result.push_str("\x1b[9m");
     }
 
     result
 }
 
-fn color_code(color: Color, foreground: bool) -> String {
+/// Returns the escape code of the color. With `basic_colors`, the 16 named colors use
+/// the basic SGR codes instead of the 256-color codes.
+fn color_code(color: Color, foreground: bool, basic_colors: bool) -> String {
+    if let Some(index) = ansi16_index(color).filter(|_| basic_colors) {
+        let code = match (foreground, index < 8) {
+            (true, true)   => 30 + index,
+            (true, false)  => 90 + index - 8,
+            (false, true)  => 40 + index,
+            (false, false) => 100 + index - 8,
+        };
+        return format!("\x1b[{}m", code);
+    }
+
     let base = if foreground { 38 } else { 48 };
     match color {
         Color::Reset        => "\x1b[0m".to_string(),
@@ -221,6 +437,30 @@ fn color_code(color: Color, foreground: bool) -> String {
     }
 }
 
+/// Returns the index of the color in the 16-color palette, if it's part of it.
+fn ansi16_index(color: Color) -> Option<u8> {
+    match color {
+        Color::Black        => Some(0),
+        Color::Red          => Some(1),
+        Color::Green        => Some(2),
+        Color::Yellow       => Some(3),
+        Color::Blue         => Some(4),
+        Color::Magenta      => Some(5),
+        Color::Cyan         => Some(6),
+        Color::Gray         => Some(7),
+        Color::DarkGray     => Some(8),
+        Color::LightRed     => Some(9),
+        Color::LightGreen   => Some(10),
+        Color::LightYellow  => Some(11),
+        Color::LightBlue    => Some(12),
+        Color::LightMagenta => Some(13),
+        Color::LightCyan    => Some(14),
+        Color::White        => Some(15),
+        Color::Indexed(i) if i < 16 => Some(i),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use ratatui::buffer::Buffer;
@@ -386,4 +626,68 @@ mod tests {
             ". . . . ",
         ]));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_render_shared_buffers() {
+        let content = Buffer::with_lines(["ab", "cd"]);
+        let expected = Buffer::with_lines(["    ", " ab ", " cd "]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Arc::new(Mutex::new(content.clone())).render_buffer(Offset { x: 1, y: 1 }, &mut buf);
+        assert_eq!(buf, expected);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Arc::new(RwLock::new(content)).render_buffer(Offset { x: 1, y: 1 }, &mut buf);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_render_buffer_region() {
+        let content = Buffer::with_lines(["abcd", "efgh", "ijkl"]);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        (&content, Rect::new(1, 1, 2, 2)).render_buffer(Offset { x: 2, y: 0 }, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  fg", "  jk"]));
+
+        // clipped by the source buffer and the destination buffer
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        (&content, Rect::new(2, 2, 9, 9)).render_buffer(Offset { x: -1, y: 1 }, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["    ", "l   "]));
+    }
+
+    #[test]
+    fn test_ansi_render_options() {
+        let mut buf = Buffer::with_lines(["ab", "cd"]);
+        buf.set_style(Rect::new(0, 0, 2, 2), Style::new().fg(Color::Rgb(255, 0, 0)));
+        buf[(1, 0)].set_bg(Color::Blue);
+
+        assert_eq!(
+            render_as_ansi_string(&buf),
+            "\x1b[0m\x1b[38;2;255;0;0ma\x1b[0m\x1b[38;2;255;0;0m\x1b[48;5;4mb\x1b[0m\n\
+             \x1b[0m\x1b[38;2;255;0;0mcd\x1b[0m\n"
+        );
+
+        let compact = AnsiRenderOptions {
+            palette: Some(ColorPalette::Ansi256),
+            skip_trailing_reset: true,
+            run_length_styles: true,
+            trailing_newline: false,
+        };
+        assert_eq!(
+            render_as_ansi_string_with(&buf, compact.clone()),
+            "\x1b[38;5;196ma\x1b[48;5;4mb\n\x1b[0m\x1b[38;5;196mcd\x1b[0m"
+        );
+
+        assert_eq!(AnsiRenderOptions::truecolor(true), AnsiRenderOptions::default());
+        assert_eq!(
+            AnsiRenderOptions::truecolor(false).palette,
+            Some(ColorPalette::Ansi256)
+        );
+
+        let ansi16 = AnsiRenderOptions { palette: Some(ColorPalette::Ansi16), ..compact };
+        assert_eq!(
+            render_as_ansi_string_with(&buf, ansi16),
+            "\x1b[91ma\x1b[44mb\n\x1b[0m\x1b[91mcd\x1b[0m"
+        );
+    }
+}
@@ -1,8 +1,12 @@
-use ratatui::buffer::Cell;
+use std::fmt;
+use std::sync::Arc;
+use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout;
 use ratatui::layout::{Margin, Position, Rect};
 use ratatui::prelude::Color;
 use crate::color_ext::ToRgbComponents;
+use crate::rect_ext::union_regions;
+use crate::selection_mask::{self, SelectionMask};
 use crate::{ref_count, RefCount, ThreadSafetyMarker};
 
 #[cfg(not(feature = "sendable"))]
@@ -15,6 +19,11 @@ type PositionFnType = RefCount<dyn Fn(Position) -> bool>;
 #[cfg(feature = "sendable")]
 type PositionFnType = RefCount<dyn Fn(Position) -> bool + Send>;
 
+#[cfg(not(feature = "sendable"))]
+type WordPredFn = RefCount<dyn Fn(usize) -> bool>;
+#[cfg(feature = "sendable")]
+type WordPredFn = RefCount<dyn Fn(usize) -> bool + Send>;
+
 /// A filter mode enables effects to operate on specific cells.
 #[derive(Clone, Default)]
 pub enum CellFilter {
@@ -41,10 +50,34 @@ pub enum CellFilter {
     Not(Box<CellFilter>),
     /// Selects cells within the specified layout, denoted by the index
     Layout(layout::Layout, u16),
+    /// Selects cells within the segments of nested layouts, denoted by the indices of each
+    /// layout. Each layout splits the segments selected by the preceding layout.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::{Constraint, Layout};
+    /// use tachyonfx::CellFilter;
+    ///
+    /// let columns = Layout::horizontal([Constraint::Fill(1); 3]);
+    /// let rows = Layout::vertical([Constraint::Length(1); 5]);
+    ///
+    /// // rows 2..4 of the second column
+    /// let filter = CellFilter::NestedLayout(vec![
+    ///     (columns, vec![1]),
+    ///     (rows, (2..4).collect()),
+    /// ]);
+    /// ```
+    NestedLayout(Vec<(layout::Layout, Vec<u16>)>),
     /// Selects cells by predicate function
     PositionFn(PositionFnType),
     /// Selects cells by predicate function
     EvalCell(CellPredFn),
+    /// Selects the cells of words, i.e. consecutive non-space cells on a row, for which
+    /// the predicate returns true. Words are indexed in reading order, starting from 0.
+    Word(WordPredFn),
+    /// Selects the cells of all regex matches in the text of each row.
+    #[cfg(feature = "regex")]
+    RowMatches(regex::Regex),
 }
 
 impl CellFilter {
@@ -60,6 +93,49 @@ impl CellFilter {
         CellFilter::EvalCell(ref_count(f))
     }
 
+    /// Selects the cells of all words whose index satisfies the predicate.
+    ///
+    /// # Example
+    /// ```
+    /// use tachyonfx::CellFilter;
+    ///
+    /// // every other word
+    /// let filter = CellFilter::word_fn(|idx| idx % 2 == 0);
+    /// ```
+    pub fn word_fn<F>(f: F) -> Self
+        where F: Fn(usize) -> bool + ThreadSafetyMarker + 'static
+    {
+        CellFilter::Word(ref_count(f))
+    }
+}
+
+impl fmt::Debug for CellFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CellFilter::All             => f.write_str("All"),
+            CellFilter::FgColor(color)  => f.debug_tuple("FgColor").field(color).finish(),
+            CellFilter::BgColor(color)  => f.debug_tuple("BgColor").field(color).finish(),
+            CellFilter::Inner(m)        => f.debug_tuple("Inner").field(m).finish(),
+            CellFilter::Outer(m)        => f.debug_tuple("Outer").field(m).finish(),
+            CellFilter::Text            => f.write_str("Text"),
+            CellFilter::AllOf(filters)  => f.debug_tuple("AllOf").field(filters).finish(),
+            CellFilter::AnyOf(filters)  => f.debug_tuple("AnyOf").field(filters).finish(),
+            CellFilter::NoneOf(filters) => f.debug_tuple("NoneOf").field(filters).finish(),
+            CellFilter::Not(filter)     => f.debug_tuple("Not").field(filter).finish(),
+            CellFilter::Layout(l, idx)  => f.debug_tuple("Layout").field(l).field(idx).finish(),
+            CellFilter::NestedLayout(steps) => f.debug_tuple("NestedLayout").field(steps).finish(),
+            // predicates can't be inspected
+            CellFilter::PositionFn(_)   => f.write_str("PositionFn(..)"),
+            CellFilter::EvalCell(_)     => f.write_str("EvalCell(..)"),
+            CellFilter::Word(_)         => f.write_str("Word(..)"),
+            #[cfg(feature = "regex")]
+            CellFilter::RowMatches(re)  => f.debug_tuple("RowMatches").field(re).finish(),
+        }
+    }
+}
+
+impl CellFilter {
+    #[allow(clippy::inherent_to_string)]
     pub fn to_string(&self) -> String {
         fn to_hex(c: &Color) -> String {
             let (r, g, b) = c.to_rgb();
@@ -89,22 +165,215 @@ impl CellFilter {
             CellFilter::NoneOf(filters) => format!("none_of({})", to_string(filters)),
             CellFilter::Not(filter)     => format!("!{}", filter.to_string()),
             CellFilter::Layout(_, idx)  => format!("layout({})", idx),
+            CellFilter::NestedLayout(steps) => {
+                let indices = steps.iter()
+                    .map(|(_, idx)| idx.iter().map(u16::to_string).collect::<Vec<_>>().join(","))
+                    .collect::<Vec<_>>();
+                format!("nested_layout({})", indices.join("; "))
+            },
             CellFilter::PositionFn(_)   => "position_fn".to_string(),
             CellFilter::EvalCell(_)     => "cell_fn".to_string(),
+            CellFilter::Word(_)         => "word".to_string(),
+            #[cfg(feature = "regex")]
+            CellFilter::RowMatches(re)  => format!("row_matches({})", re.as_str()),
         }
     }
 }
 
+/// Determines how a cell filter combines with the filters already set on an effect,
+/// or on its child effects. See [Effect::with_cell_selection_policy](crate::Effect::with_cell_selection_policy).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionPolicy {
+    /// Replaces any existing filters.
+    #[default]
+    Replace,
+    /// Selects the cells matching both the new and the existing filter.
+    Intersect,
+    /// Selects the cells matching either the new or the existing filter.
+    Union,
+}
+
+impl SelectionPolicy {
+    /// Combines `filter` with the `existing` filter, according to the policy.
+    pub fn combine(self, filter: CellFilter, existing: Option<CellFilter>) -> CellFilter {
+        match (self, existing) {
+            (SelectionPolicy::Replace, _)
+            | (_, None)                               => filter,
+            (SelectionPolicy::Intersect, Some(CellFilter::All)) => filter,
+            (SelectionPolicy::Intersect, Some(existing))        => match filter {
+                CellFilter::All => existing,
+                filter          => CellFilter::AllOf(vec![filter, existing]),
+            },
+            (SelectionPolicy::Union, Some(CellFilter::All))     => CellFilter::All,
+            (SelectionPolicy::Union, Some(existing))            => match filter {
+                CellFilter::All => CellFilter::All,
+                filter          => CellFilter::AnyOf(vec![filter, existing]),
+            },
+        }
+    }
+}
+
+/// A [CellFilter] resolved against an area, for testing which cells the filter
+/// selects. Created by [CellFilter::selector].
 pub struct CellSelector {
-    inner_area: Rect,
+    resolved: ResolvedArea,
     strategy: CellFilter,
+    mask: Option<Arc<SelectionMask>>,
+}
+
+/// The areas of a filter and its nested filters, resolved once per selector
+/// instead of for every cell; notably avoiding repeated `Layout` splits.
+struct ResolvedArea {
+    area: Rect,
+    nested: Vec<ResolvedArea>,
+    /// The selected layout segments, for [CellFilter::NestedLayout].
+    layout_segments: Vec<Rect>,
+    /// Index of the word or match of each cell in `area`, for [CellFilter::Word]
+    /// and `CellFilter::RowMatches`.
+    segments: Vec<Option<usize>>,
+}
+
+impl ResolvedArea {
+    fn new(area: Rect, mode: &CellFilter, buf: Option<&Buffer>) -> Self {
+        let inner_area = CellSelector::resolve_area(area, mode);
+        let nested = match mode {
+            CellFilter::AllOf(s)
+            | CellFilter::AnyOf(s)
+            | CellFilter::NoneOf(s) => s.iter().map(|m| Self::new(inner_area, m, buf)).collect(),
+            CellFilter::Not(m)      => vec![Self::new(area, m, buf)],
+            _                       => Vec::new(),
+        };
+
+        let segments = match (mode, buf) {
+            (CellFilter::Word(_), Some(buf))        => word_indices(buf, inner_area),
+            #[cfg(feature = "regex")]
+            (CellFilter::RowMatches(re), Some(buf)) => match_indices(buf, inner_area, re),
+            _                                       => Vec::new(),
+        };
+
+        let layout_segments = match mode {
+            CellFilter::NestedLayout(steps) => split_nested(area, steps),
+            _                               => Vec::new(),
+        };
+
+        Self { area: inner_area, nested, layout_segments, segments }
+    }
+
+    fn segment_at(&self, pos: Position) -> Option<usize> {
+        if !self.area.contains(pos) {
+            return None;
+        }
+
+        let idx = (pos.y - self.area.y) as usize * self.area.width as usize
+            + (pos.x - self.area.x) as usize;
+        self.segments.get(idx).copied().flatten()
+    }
+}
+
+/// Splits the area by each layout in turn, keeping the segments at the given indices.
+fn split_nested(area: Rect, steps: &[(layout::Layout, Vec<u16>)]) -> Vec<Rect> {
+    steps.iter().fold(vec![area], |areas, (layout, indices)| {
+        areas.iter()
+            .flat_map(|area| {
+                let segments = layout.split(*area);
+                indices.iter()
+                    .filter_map(|idx| segments.get(*idx as usize).copied())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    })
+}
+
+/// Assigns word indices, in reading order, to the cells of the area. Words are runs of
+/// non-space cells on a row; the trailing cells of wide characters belong to the word.
+fn word_indices(buf: &Buffer, area: Rect) -> Vec<Option<usize>> {
+    let mut words = Vec::with_capacity(area.area() as usize);
+    let mut next_word = 0;
+
+    for y in area.top()..area.bottom() {
+        let mut in_word = false;
+        for x in area.left()..area.right() {
+            let is_word_cell = match buf.cell(Position::new(x, y)).map(Cell::symbol) {
+                Some("")     => in_word, // continuation of a wide character
+                Some(symbol) => !symbol.chars().all(char::is_whitespace),
+                None         => false,
+            };
+
+            if in_word && !is_word_cell {
+                next_word += 1;
+            }
+
+            in_word = is_word_cell;
+            words.push(in_word.then_some(next_word));
+        }
+
+        if in_word {
+            next_word += 1;
+        }
+    }
+
+    words
+}
+
+/// Assigns match indices, in reading order, to the cells covered by the regex matches
+/// in the text of each row.
+#[cfg(feature = "regex")]
+fn match_indices(buf: &Buffer, area: Rect, re: &regex::Regex) -> Vec<Option<usize>> {
+    let mut segments = Vec::with_capacity(area.area() as usize);
+    let mut next_match = 0;
+
+    let mut text = String::new();
+    let mut offsets = Vec::with_capacity(area.width as usize);
+    for y in area.top()..area.bottom() {
+        text.clear();
+        offsets.clear();
+        for x in area.left()..area.right() {
+            let symbol = buf.cell(Position::new(x, y)).map_or(" ", Cell::symbol);
+            offsets.push(text.len());
+            text.push_str(symbol);
+        }
+
+        let row_start = segments.len();
+        segments.resize(row_start + offsets.len(), None);
+
+        for m in re.find_iter(&text).filter(|m| !m.is_empty()) {
+            let cell_end = |i: usize| offsets.get(i + 1).copied().unwrap_or(text.len());
+            offsets.iter()
+                .enumerate()
+                .filter(|&(i, &start)| start < m.end() && m.start() < cell_end(i))
+                .for_each(|(i, _)| segments[row_start + i] = Some(next_match));
+
+            next_match += 1;
+        }
+
+        // the trailing cells of wide characters belong to the preceding cell
+        for i in 1..offsets.len() {
+            if offsets[i] == offsets[i - 1] {
+                segments[row_start + i] = segments[row_start + i - 1];
+            }
+        }
+    }
+
+    segments
 }
 
 impl CellSelector {
-    fn new(area: Rect, strategy: CellFilter) -> Self {
-        let inner_area = Self::resolve_area(area, &strategy);
+    pub(crate) fn new(area: Rect, strategy: CellFilter) -> Self {
+        Self::with_buffer(area, strategy, None)
+    }
 
-        Self { inner_area, strategy }
+    /// Creates a selector for the area, using the buffer to resolve content-based
+    /// filters spanning multiple cells, such as [CellFilter::Word].
+    pub(crate) fn with_buffer(area: Rect, strategy: CellFilter, buf: Option<&Buffer>) -> Self {
+        let resolved = ResolvedArea::new(area, &strategy, buf);
+        let mask = (strategy.has_nested_positions()
+            && strategy.has_static_positions()
+            && !strategy.has_cell_criteria())
+            .then(|| selection_mask::cached(area, &strategy, |pos| {
+                Self::valid_position(&resolved, pos, &strategy)
+            }));
+
+        Self { resolved, strategy, mask }
     }
 
     fn resolve_area(area: Rect, mode: &CellFilter) -> Rect {
@@ -120,19 +389,43 @@ impl CellSelector {
             CellFilter::FgColor(_)           => area,
             CellFilter::BgColor(_)           => area,
             CellFilter::Layout(layout, idx)  => layout.split(area)[*idx as usize],
+            CellFilter::NestedLayout(steps)  => split_nested(area, steps).into_iter()
+                .fold(Rect::default(), union_regions),
             CellFilter::PositionFn(_)        => area,
             CellFilter::EvalCell(_)          => area,
+            CellFilter::Word(_)              => area,
+            #[cfg(feature = "regex")]
+            CellFilter::RowMatches(_)        => area,
         }
     }
 
+    /// Returns `true` if the cell at the position is selected by the filter.
     pub fn is_valid(&self, pos: Position, cell: &Cell) -> bool {
-        let mode = &self.strategy;
+        match self.mask.as_ref().and_then(|m| m.get(pos)) {
+            Some(valid) => valid,
+            None        => Self::is_selected(&self.resolved, pos, cell, &self.strategy),
+        }
+    }
+
+    /// Checks both the position and the cell against `mode`. Filters combining other
+    /// filters evaluate each of their filters separately.
+    fn is_selected(resolved: &ResolvedArea, pos: Position, cell: &Cell, mode: &CellFilter) -> bool {
+        let selected = |(resolved, mode): (&ResolvedArea, &CellFilter)| {
+            Self::is_selected(resolved, pos, cell, mode)
+        };
+        let nested = &resolved.nested;
 
-        self.valid_position(pos, mode)
-            && self.is_valid_cell(cell, mode)
+        match mode {
+            CellFilter::AllOf(s)  => nested.iter().zip(s).all(selected),
+            CellFilter::AnyOf(s)  => nested.iter().zip(s).any(selected),
+            CellFilter::NoneOf(s) => resolved.area.contains(pos) && !nested.iter().zip(s).any(selected),
+            _                     => Self::valid_position(resolved, pos, mode)
+                && Self::is_valid_cell(cell, mode),
+        }
     }
 
-    fn valid_position(&self, pos: Position, mode: &CellFilter) -> bool {
+    /// Checks the position against `mode`, using the areas resolved for `mode`.
+    fn valid_position(resolved: &ResolvedArea, pos: Position, mode: &CellFilter) -> bool {
         fn apply_position_fn(f: &PositionFnType, pos: Position) -> bool {
             #[cfg(not(feature = "sendable"))]
             return f.borrow()(pos);
@@ -140,27 +433,41 @@ impl CellSelector {
             f.lock().unwrap()(pos)
         }
 
+        fn apply_word_fn(f: &WordPredFn, word: usize) -> bool {
+            #[cfg(not(feature = "sendable"))]
+            return f.borrow()(word);
+            #[cfg(feature = "sendable")]
+            f.lock().unwrap()(word)
+        }
+
+        let inner_area = resolved.area;
+        let valid = |(resolved, mode): (&ResolvedArea, &CellFilter)| {
+            Self::valid_position(resolved, pos, mode)
+        };
+        let nested = &resolved.nested;
+
         match mode {
-            CellFilter::All           => self.inner_area.contains(pos),
-            CellFilter::Layout(_, _)  => self.inner_area.contains(pos),
-            CellFilter::Inner(_)      => self.inner_area.contains(pos),
-            CellFilter::Outer(_)      => !self.inner_area.contains(pos),
-            CellFilter::Text          => self.inner_area.contains(pos),
-            CellFilter::AllOf(s)      => s.iter()
-                .all(|mode| mode.selector(self.inner_area).valid_position(pos, mode)),
-            CellFilter::AnyOf(s)      => s.iter()
-                .any(|mode| mode.selector(self.inner_area).valid_position(pos, mode)),
-            CellFilter::NoneOf(s)     => s.iter()
-                .all(|mode| !mode.selector(self.inner_area).valid_position(pos, mode)),
-            CellFilter::Not(m)        => self.valid_position(pos, m.as_ref()),
-            CellFilter::FgColor(_)    => self.inner_area.contains(pos),
-            CellFilter::BgColor(_)    => self.inner_area.contains(pos),
+            CellFilter::All           => inner_area.contains(pos),
+            CellFilter::Layout(_, _)  => inner_area.contains(pos),
+            CellFilter::NestedLayout(_) => resolved.layout_segments.iter().any(|r| r.contains(pos)),
+            CellFilter::Inner(_)      => inner_area.contains(pos),
+            CellFilter::Outer(_)      => !inner_area.contains(pos),
+            CellFilter::Text          => inner_area.contains(pos),
+            CellFilter::AllOf(s)      => nested.iter().zip(s).all(valid),
+            CellFilter::AnyOf(s)      => nested.iter().zip(s).any(valid),
+            CellFilter::NoneOf(s)     => inner_area.contains(pos) && !nested.iter().zip(s).any(valid),
+            CellFilter::Not(m)        => valid((&nested[0], m.as_ref())),
+            CellFilter::FgColor(_)    => inner_area.contains(pos),
+            CellFilter::BgColor(_)    => inner_area.contains(pos),
             CellFilter::PositionFn(f) => apply_position_fn(f, pos),
-            CellFilter::EvalCell(_)   => self.inner_area.contains(pos),
+            CellFilter::EvalCell(_)   => inner_area.contains(pos),
+            CellFilter::Word(f)       => resolved.segment_at(pos).is_some_and(|w| apply_word_fn(f, w)),
+            #[cfg(feature = "regex")]
+            CellFilter::RowMatches(_) => resolved.segment_at(pos).is_some(),
         }
     }
 
-    fn is_valid_cell(&self, cell: &Cell, mode: &CellFilter) -> bool {
+    fn is_valid_cell(cell: &Cell, mode: &CellFilter) -> bool {
         fn apply_eval_fn(f: &CellPredFn, cell: &Cell) -> bool {
             #[cfg(not(feature = "sendable"))]
             return f.borrow()(cell);
@@ -178,15 +485,12 @@ impl CellSelector {
                 }
             },
 
-            CellFilter::AllOf(s) => {
-                s.iter()
-                    .all(|s| s.selector(self.inner_area).is_valid_cell(cell, s))
-            },
+            CellFilter::AllOf(s) => s.iter().all(|s| Self::is_valid_cell(cell, s)),
 
             CellFilter::FgColor(color) => cell.fg == *color,
             CellFilter::BgColor(color) => cell.bg == *color,
 
-            CellFilter::Not(m) => !self.is_valid_cell(cell, m.as_ref()),
+            CellFilter::Not(m) => !Self::is_valid_cell(cell, m.as_ref()),
 
             CellFilter::EvalCell(f) => apply_eval_fn(f, cell),
 
@@ -196,16 +500,123 @@ impl CellSelector {
 }
 
 impl CellFilter {
+    /// Creates a selector for the area.
+    ///
+    /// The selector has no access to the buffer, so it can't resolve filters based on
+    /// the content of multiple cells, such as [CellFilter::Word]; these never select
+    /// any cells. Use [CellFilter::try_selector] to reject such filters instead.
     pub fn selector(&self, area: Rect) -> CellSelector {
         CellSelector::new(area, self.clone())
     }
+
+    /// Creates a selector for the area, or returns `None` if the filter requires a
+    /// buffer to select cells, as is the case for [CellFilter::Word].
+    pub fn try_selector(&self, area: Rect) -> Option<CellSelector> {
+        (!self.has_buffer_positions()).then(|| self.selector(area))
+    }
+
+    /// Returns true if the positional part of the filter only depends on the
+    /// area, i.e. it doesn't contain any [CellFilter::PositionFn] or content-based
+    /// filters such as [CellFilter::Word].
+    fn has_static_positions(&self) -> bool {
+        match self {
+            CellFilter::AllOf(s)
+            | CellFilter::AnyOf(s)
+            | CellFilter::NoneOf(s)   => s.iter().all(CellFilter::has_static_positions),
+            CellFilter::Not(m)        => m.has_static_positions(),
+            CellFilter::PositionFn(_) => false,
+            CellFilter::Word(_)       => false,
+            #[cfg(feature = "regex")]
+            CellFilter::RowMatches(_) => false,
+            _                         => true,
+        }
+    }
+
+    /// Returns true if selecting a position involves more than a single area check,
+    /// i.e. the filter combines other filters or splits the area with a layout.
+    /// Masks are only worth computing for such filters.
+    fn has_nested_positions(&self) -> bool {
+        matches!(self,
+            CellFilter::AllOf(_)
+            | CellFilter::AnyOf(_)
+            | CellFilter::NoneOf(_)
+            | CellFilter::Not(_)
+            | CellFilter::Layout(_, _)
+            | CellFilter::NestedLayout(_)
+        )
+    }
+
+    /// Returns true if the filter selects positions by the content of multiple cells,
+    /// which can only be resolved with access to the buffer.
+    fn has_buffer_positions(&self) -> bool {
+        match self {
+            CellFilter::AllOf(s)
+            | CellFilter::AnyOf(s)
+            | CellFilter::NoneOf(s)   => s.iter().any(CellFilter::has_buffer_positions),
+            CellFilter::Not(m)        => m.has_buffer_positions(),
+            CellFilter::Word(_)       => true,
+            #[cfg(feature = "regex")]
+            CellFilter::RowMatches(_) => true,
+            _                         => false,
+        }
+    }
+
+    /// Returns true if the filter depends on the contents of the cell.
+    fn has_cell_criteria(&self) -> bool {
+        match self {
+            CellFilter::AllOf(s)
+            | CellFilter::AnyOf(s)
+            | CellFilter::NoneOf(s)   => s.iter().any(CellFilter::has_cell_criteria),
+            CellFilter::Not(_)
+            | CellFilter::Text
+            | CellFilter::FgColor(_)
+            | CellFilter::BgColor(_)
+            | CellFilter::EvalCell(_) => true,
+            _                         => false,
+        }
+    }
+
+    /// Returns true if both filters select the same positions, disregarding
+    /// any cell-based criteria.
+    pub(crate) fn selects_same_positions(&self, other: &CellFilter) -> bool {
+        use CellFilter::*;
+
+        fn all_same(a: &[CellFilter], b: &[CellFilter]) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.selects_same_positions(b))
+        }
+
+        let area_only = |f: &CellFilter| matches!(f, All | Text | FgColor(_) | BgColor(_) | EvalCell(_));
+
+        match (self, other) {
+            (a, b) if area_only(a) && area_only(b) => true,
+            (Inner(a), Inner(b))             => a == b,
+            (Outer(a), Outer(b))             => a == b,
+            (AllOf(a), AllOf(b))             => all_same(a, b),
+            (AnyOf(a), AnyOf(b))             => all_same(a, b),
+            (NoneOf(a), NoneOf(b))           => all_same(a, b),
+            (Not(a), Not(b))                 => a.selects_same_positions(b),
+            (Layout(a, ia), Layout(b, ib))   => a == b && ia == ib,
+            (NestedLayout(a), NestedLayout(b)) => a == b,
+            _                                => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use layout::Layout;
+    use layout::{Constraint, Layout};
     use super::*;
 
+    #[test]
+    fn test_cell_filter_debug() {
+        let filter = CellFilter::AllOf(vec![
+            CellFilter::Text,
+            CellFilter::Not(CellFilter::FgColor(Color::Red).into()),
+            CellFilter::apply_position_fn(|p| p.x > 2),
+        ]);
+        assert_eq!(format!("{:?}", filter), "AllOf([Text, Not(FgColor(Red)), PositionFn(..)])");
+    }
+
     #[test]
     fn test_cell_filter_to_string() {
         let filter = CellFilter::FgColor(Color::Red);
@@ -244,7 +655,7 @@ mod tests {
         let filter = CellFilter::Not(Box::new(CellFilter::FgColor(Color::Red)));
         assert_eq!(filter.to_string(), "!fg(#800000)");
 
-        let filter = CellFilter::Layout(Layout::horizontal(&[]), 0);
+        let filter = CellFilter::Layout(Layout::default(), 0);
         assert_eq!(filter.to_string(), "layout(0)");
 
         let filter = CellFilter::PositionFn(ref_count(|_| true));
@@ -253,4 +664,135 @@ mod tests {
         let filter = CellFilter::EvalCell(ref_count(|_| true));
         assert_eq!(filter.to_string(), "cell_fn");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_position_fn_is_not_masked() {
+        let filter = CellFilter::apply_position_fn(|pos| pos.x == pos.y);
+
+        let selector = filter.selector(Rect::new(0, 0, 4, 4));
+        assert!(selector.mask.is_none());
+        assert!(selector.is_valid(Position::new(2, 2), &Cell::default()));
+        assert!(!selector.is_valid(Position::new(2, 1), &Cell::default()));
+    }
+
+    #[test]
+    fn test_single_area_filters_are_not_masked() {
+        let area = Rect::new(0, 0, 4, 4);
+        assert!(CellFilter::All.selector(area).mask.is_none());
+        assert!(CellFilter::Inner(Margin::new(1, 1)).selector(area).mask.is_none());
+
+        let layout = Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]);
+        assert!(CellFilter::Layout(layout, 1).selector(area).mask.is_some());
+    }
+
+    #[test]
+    fn test_nested_layout_with_position_fn() {
+        let layout = Layout::horizontal([Constraint::Length(2), Constraint::Fill(1)]);
+        let filter = CellFilter::AllOf(vec![
+            CellFilter::Layout(layout, 1),
+            CellFilter::apply_position_fn(|pos| pos.y == 1),
+        ]);
+
+        let selector = filter.selector(Rect::new(0, 0, 5, 3));
+        let selected: Vec<Position> = Rect::new(0, 0, 5, 3).positions()
+            .filter(|pos| selector.is_valid(*pos, &Cell::default()))
+            .collect();
+
+        assert_eq!(selected, vec![Position::new(2, 1), Position::new(3, 1), Position::new(4, 1)]);
+    }
+
+    #[test]
+    fn test_word_filter() {
+        let mut buf = Buffer::with_lines(["ab  cd e", " fg  hij"]);
+        let area = buf.area;
+
+        let selector = CellSelector::with_buffer(area, CellFilter::word_fn(|w| w % 2 == 1), Some(&buf));
+        let selected: String = area.positions()
+            .map(|pos| if selector.is_valid(pos, &buf[pos]) { 'x' } else { '.' })
+            .collect();
+        assert_eq!(selected, concat!("....xx..", ".xx....."));
+
+        // words are re-evaluated for each selector
+        buf[(2, 0)].set_char('-');
+        let selector = CellSelector::with_buffer(area, CellFilter::word_fn(|w| w == 0), Some(&buf));
+        assert!(selector.is_valid(Position::new(2, 0), &buf[(2, 0)]));
+        assert!(!selector.is_valid(Position::new(4, 0), &buf[(4, 0)]));
+    }
+
+    #[test]
+    fn test_word_filter_selector_requires_buffer() {
+        let area = Rect::new(0, 0, 4, 1);
+        let filter = CellFilter::Not(Box::new(CellFilter::word_fn(|_| true)));
+        assert!(filter.try_selector(area).is_none());
+
+        // without a buffer, no words are resolved
+        let selector = filter.selector(area);
+        assert!(!selector.is_valid(Position::new(0, 0), &Cell::default()));
+
+        assert!(CellFilter::Text.try_selector(area).is_some());
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_row_matches_filter() {
+        let buf = Buffer::with_lines(["err: 404 ok", "200 or 5xx"]);
+        let area = buf.area;
+
+        let filter = CellFilter::RowMatches(regex::Regex::new(r"\d+").unwrap());
+        assert_eq!(filter.to_string(), r"row_matches(\d+)");
+
+        let selector = CellSelector::with_buffer(area, filter, Some(&buf));
+        let selected: String = area.positions()
+            .map(|pos| if selector.is_valid(pos, &buf[pos]) { 'x' } else { '.' })
+            .collect();
+        assert_eq!(selected, concat!(".....xxx...", "xxx....x..."));
+    }
+
+    #[test]
+    fn test_nested_layout() {
+        let columns = Layout::horizontal([Constraint::Length(2); 3]);
+        let rows = Layout::vertical([Constraint::Length(1); 4]);
+        let filter = CellFilter::NestedLayout(vec![(columns, vec![0, 2]), (rows, vec![1, 2])]);
+        assert_eq!(filter.to_string(), "nested_layout(0,2; 1,2)");
+
+        let area = Rect::new(0, 0, 6, 4);
+        let selector = filter.selector(area);
+        let selected: String = area.positions()
+            .map(|pos| if selector.is_valid(pos, &Cell::default()) { 'x' } else { '.' })
+            .collect();
+
+        assert_eq!(selected, concat!("......", "xx..xx", "xx..xx", "......"));
+    }
+
+    #[test]
+    fn test_selection_policy() {
+        use SelectionPolicy::*;
+
+        let combine = |policy: SelectionPolicy, existing| {
+            policy.combine(CellFilter::Text, existing).to_string()
+        };
+
+        assert_eq!(combine(Replace, Some(CellFilter::BgColor(Color::Red))), "text");
+        assert_eq!(combine(Intersect, Some(CellFilter::BgColor(Color::Red))), "all_of(text, bg(#800000))");
+        assert_eq!(combine(Intersect, Some(CellFilter::All)), "text");
+        assert_eq!(combine(Union, Some(CellFilter::BgColor(Color::Red))), "any_of(text, bg(#800000))");
+        assert_eq!(combine(Union, Some(CellFilter::All)), "all");
+        assert_eq!(combine(Union, None), "text");
+    }
+
+    #[test]
+    fn test_any_of_evaluates_each_filter() {
+        let filter = CellFilter::AnyOf(vec![
+            CellFilter::Inner(Margin::new(1, 1)),
+            CellFilter::FgColor(Color::Red),
+        ]);
+
+        let mut red = Cell::default();
+        red.set_fg(Color::Red);
+
+        let selector = filter.selector(Rect::new(0, 0, 3, 3));
+        assert!(selector.is_valid(Position::new(1, 1), &Cell::default()));
+        assert!(selector.is_valid(Position::new(0, 0), &red));
+        assert!(!selector.is_valid(Position::new(0, 0), &Cell::default()));
+    }
+}
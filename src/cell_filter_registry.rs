@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::CellFilter;
+
+/// A registry of named cell filters.
+///
+/// Filters are defined once and then referenced by name, e.g. by effect definitions
+/// which are loaded or serialized separately from the application's layout code.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Margin;
+/// use tachyonfx::{CellFilter, CellFilterRegistry};
+///
+/// let mut filters = CellFilterRegistry::default();
+/// filters.register("border", CellFilter::Outer(Margin::new(1, 1)));
+/// filters.register("border_text", CellFilter::AllOf(vec![
+///     filters.resolve("border").unwrap(),
+///     CellFilter::Text,
+/// ]));
+///
+/// assert_eq!(filters.resolve("border_text").unwrap().to_string(), "all_of(outer(1:1), text)");
+/// assert!(filters.resolve("sidebar").is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct CellFilterRegistry {
+    filters: HashMap<String, CellFilter>,
+}
+
+impl CellFilterRegistry {
+    /// Registers a filter under the given name, returning any filter previously
+    /// registered under the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        filter: CellFilter,
+    ) -> Option<CellFilter> {
+        self.filters.insert(name.into(), filter)
+    }
+
+    /// Removes the filter registered under the given name.
+    pub fn unregister(&mut self, name: &str) -> Option<CellFilter> {
+        self.filters.remove(name)
+    }
+
+    /// Returns the filter registered under the given name.
+    pub fn get(&self, name: &str) -> Option<&CellFilter> {
+        self.filters.get(name)
+    }
+
+    /// Returns a copy of the filter registered under the given name, ready to
+    /// be applied to an effect.
+    pub fn resolve(&self, name: &str) -> Option<CellFilter> {
+        self.get(name).cloned()
+    }
+
+    /// Returns true if a filter is registered under the given name.
+    pub fn contains(&self, name: &str) -> bool {
+        self.filters.contains_key(name)
+    }
+
+    /// Returns the names of all registered filters, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.filters.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    #[test]
+    fn test_register_and_resolve() {
+        let mut filters = CellFilterRegistry::default();
+        assert!(filters.register("red", CellFilter::FgColor(Color::Red)).is_none());
+        assert!(filters.contains("red"));
+
+        let previous = filters.register("red", CellFilter::BgColor(Color::Red));
+        assert_eq!(previous.unwrap().to_string(), "fg(#800000)");
+        assert_eq!(filters.resolve("red").unwrap().to_string(), "bg(#800000)");
+
+        assert_eq!(filters.names().collect::<Vec<_>>(), vec!["red"]);
+        assert!(filters.unregister("red").is_some());
+        assert!(filters.get("red").is_none());
+    }
+}
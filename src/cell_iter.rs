@@ -1,12 +1,13 @@
 use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::{Position, Rect};
+use crate::cell_filter::CellSelector;
 use crate::CellFilter;
 
 pub struct CellIterator<'a> {
     current: u32,
     area: Rect,
     buf: &'a mut Buffer,
-    filter: Option<CellFilter>,
+    selector: Option<CellSelector>,
 }
 
 impl<'a> CellIterator<'a> {
@@ -15,11 +16,14 @@ impl<'a> CellIterator<'a> {
         area: Rect,
         filter: Option<CellFilter>,
     ) -> Self {
+        let area = area.intersection(buf.area);
+        let selector = filter.map(|f| CellSelector::with_buffer(area, f, Some(buf)));
+
         Self {
             current: 0,
-            area: area.intersection(buf.area),
+            area,
             buf,
-            filter
+            selector,
         }
     }
 
@@ -37,7 +41,6 @@ impl<'a> Iterator for CellIterator<'a> {
     type Item = (Position, &'a mut Cell);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let selector = self.filter.as_ref().map(|f| f.selector(self.area));
         let area = self.area.area();
         while self.current < area {
             let (pos, cell) = self.cell_mut()?;
@@ -45,7 +48,7 @@ impl<'a> Iterator for CellIterator<'a> {
             let cell: &'a mut Cell = unsafe { std::mem::transmute(cell) };
             self.current += 1;
 
-            if let Some(filter) = &selector {
+            if let Some(filter) = &self.selector {
                 if filter.is_valid(pos, cell) {
                     return Some((pos, cell));
                 }
@@ -0,0 +1,191 @@
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::Interpolatable;
+
+/// Number of recently mapped colors remembered while processing a batch.
+const CACHE_SIZE: usize = 8;
+
+/// A small cache of recently mapped colors. Unlike [ColorMapper](crate::ColorMapper),
+/// which only remembers the last color, it copes with content alternating between a
+/// handful of colors, e.g. text on a background.
+struct BatchCache {
+    entries: [(Color, Color); CACHE_SIZE],
+    len: usize,
+    next: usize,
+}
+
+impl BatchCache {
+    fn new() -> Self {
+        Self { entries: [(Color::Reset, Color::Reset); CACHE_SIZE], len: 0, next: 0 }
+    }
+
+    fn map(&mut self, color: Color, f: &impl Fn(Color) -> Color) -> Color {
+        if let Some((_, mapped)) = self.entries[..self.len].iter().find(|(c, _)| *c == color) {
+            return *mapped;
+        }
+
+        let mapped = f(color);
+        self.entries[self.next] = (color, mapped);
+        self.next = (self.next + 1) % CACHE_SIZE;
+        self.len = (self.len + 1).min(CACHE_SIZE);
+        mapped
+    }
+}
+
+/// Maps the foreground colors of all cells with `f`, in one pass. Explicitly set
+/// underline colors follow the foreground and are mapped too. Each distinct color
+/// is only transformed once per batch, as long as the batch contains no more than
+/// a handful of distinct colors at a time.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::Color;
+/// use tachyonfx::{map_cells_fg, Interpolatable};
+///
+/// let mut buf = Buffer::with_lines(["hello"]);
+/// map_cells_fg(&mut buf.content, |c| c.lerp(&Color::Red, 0.5));
+/// ```
+pub fn map_cells_fg(cells: &mut [Cell], f: impl Fn(Color) -> Color) {
+    let mut cache = BatchCache::new();
+    for cell in cells {
+        cell.fg = cache.map(cell.fg, &f);
+        #[cfg(feature = "underline-color")]
+        if cell.underline_color != Color::Reset {
+            cell.underline_color = cache.map(cell.underline_color, &f);
+        }
+    }
+}
+
+/// Maps the background colors of all cells with `f`, in one pass.
+///
+/// See [map_cells_fg] for more information.
+pub fn map_cells_bg(cells: &mut [Cell], f: impl Fn(Color) -> Color) {
+    let mut cache = BatchCache::new();
+    for cell in cells {
+        cell.bg = cache.map(cell.bg, &f);
+    }
+}
+
+/// Interpolates the foreground and/or background colors of all cells toward the
+/// target colors, using a uniform `alpha`.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::Color;
+/// use tachyonfx::lerp_cells;
+///
+/// let mut buf = Buffer::with_lines(["hello", "world"]);
+/// lerp_cells(&mut buf.content, Some(Color::White), Some(Color::Black), 0.25);
+/// ```
+pub fn lerp_cells(
+    cells: &mut [Cell],
+    fg: Option<Color>,
+    bg: Option<Color>,
+    alpha: f32,
+) {
+    if let Some(fg) = fg {
+        map_cells_fg(cells, |c| c.lerp(&fg, alpha));
+    }
+    if let Some(bg) = bg {
+        map_cells_bg(cells, |c| c.lerp(&bg, alpha));
+    }
+}
+
+/// Minimum number of cells in an area before rows are processed in parallel.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 16 * 1024;
+
+/// Calls `f` with the position of the first cell and the contiguous slice of cells
+/// of each row of `area` within the buffer.
+///
+/// With the `parallel` feature, large areas are split into bands of rows which are
+/// processed on the rayon thread pool.
+pub(crate) fn for_each_row(
+    buf: &mut Buffer,
+    area: Rect,
+    f: impl Fn(Position, &mut [Cell]) + Sync,
+) {
+    let area = area.intersection(buf.area);
+    if area.is_empty() {
+        return;
+    }
+
+    let buf_width = buf.area.width as usize;
+    let start = buf.index_of(area.x, area.y);
+    let end = start + (area.height as usize - 1) * buf_width + area.width as usize;
+
+    let cells = &mut buf.content[start..end];
+    let for_each_row_of = |cells: &mut [Cell], y: u16| {
+        cells.chunks_mut(buf_width)
+            .zip(y..)
+            .for_each(|(row, y)| f(Position::new(area.x, y), &mut row[..area.width as usize]));
+    };
+
+    #[cfg(feature = "parallel")]
+    if area.area() as usize >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        let band_height = (area.height as usize).div_ceil(rayon::current_num_threads());
+        cells.par_chunks_mut(buf_width * band_height)
+            .enumerate()
+            .for_each(|(i, band)| for_each_row_of(band, area.y + (i * band_height) as u16));
+        return;
+    }
+
+    for_each_row_of(cells, area.y);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_cells() {
+        let mut buf = Buffer::with_lines(["ab", "cd"]);
+        buf.set_style(Rect::new(0, 0, 1, 2), (Color::Rgb(0, 0, 0), Color::Rgb(10, 10, 10)));
+        let mut expected = buf.clone();
+
+        lerp_cells(&mut buf.content, Some(Color::White), Some(Color::Black), 0.5);
+
+        expected.content.iter_mut().for_each(|cell| {
+            cell.fg = cell.fg.lerp(&Color::White, 0.5);
+            cell.bg = cell.bg.lerp(&Color::Black, 0.5);
+        });
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_for_each_row() {
+        let mut buf = Buffer::with_lines(["abcd", "efgh", "ijkl"]);
+        for_each_row(&mut buf, Rect::new(1, 1, 2, 10), |pos, cells| {
+            cells.iter_mut().for_each(|c| { c.set_char(if pos.y == 1 { 'x' } else { 'y' }); });
+            assert_eq!(pos.x, 1);
+        });
+
+        assert_eq!(buf, Buffer::with_lines(["abcd", "exxh", "iyyl"]));
+    }
+
+    #[test]
+    fn test_for_each_row_large_area() {
+        let area = Rect::new(0, 0, 300, 100);
+        let mut buf = Buffer::empty(area);
+        for_each_row(&mut buf, Rect::new(1, 3, 298, 97), |pos, cells| {
+            assert_eq!(pos.x, 1);
+            let ch = char::from(b'a' + (pos.y % 26) as u8);
+            cells.iter_mut().for_each(|c| { c.set_char(ch); });
+        });
+
+        for y in 3..area.height {
+            let ch = char::from(b'a' + (y % 26) as u8).to_string();
+            assert_eq!(buf[(0, y)].symbol(), " ");
+            assert_eq!(buf[(1, y)].symbol(), ch);
+            assert_eq!(buf[(298, y)].symbol(), ch);
+            assert_eq!(buf[(299, y)].symbol(), " ");
+        }
+        assert_eq!(buf[(1, 2)].symbol(), " ");
+    }
+}
@@ -0,0 +1,95 @@
+//! Memoization of HSL conversions.
+//!
+//! Converting between RGB and HSL is comparatively expensive, and full-screen color
+//! effects perform these conversions for every cell on every frame. The results are
+//! cached per thread, keyed by the packed RGB value or the bit pattern of the HSL
+//! components, respectively. Each cache is cleared once it grows beyond a fixed
+//! number of entries.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Maximum number of entries per cache before it is cleared.
+const MAX_ENTRIES: usize = 4096;
+
+type Hsl = (f32, f32, f32);
+
+thread_local! {
+    static TO_HSL: RefCell<HashMap<u32, Hsl>> = RefCell::new(HashMap::new());
+    static FROM_HSL: RefCell<HashMap<[u32; 3], (u8, u8, u8)>> = RefCell::new(HashMap::new());
+}
+
+/// Clears the HSL conversion caches of the current thread.
+pub fn clear() {
+    TO_HSL.with(|cache| cache.borrow_mut().clear());
+    FROM_HSL.with(|cache| cache.borrow_mut().clear());
+}
+
+pub(crate) fn to_hsl(rgb: (u8, u8, u8), convert: impl FnOnce() -> Hsl) -> Hsl {
+    let (r, g, b) = rgb;
+    let key = (r as u32) << 16 | (g as u32) << 8 | b as u32;
+
+    TO_HSL.with(|cache| cached(&mut cache.borrow_mut(), key, convert))
+}
+
+pub(crate) fn from_hsl(hsl: Hsl, convert: impl FnOnce() -> (u8, u8, u8)) -> (u8, u8, u8) {
+    let (h, s, l) = hsl;
+    let key = [h.to_bits(), s.to_bits(), l.to_bits()];
+
+    FROM_HSL.with(|cache| cached(&mut cache.borrow_mut(), key, convert))
+}
+
+fn cached<K, V>(
+    cache: &mut HashMap<K, V>,
+    key: K,
+    convert: impl FnOnce() -> V,
+) -> V
+where
+    K: std::hash::Hash + Eq,
+    V: Copy,
+{
+    if let Some(value) = cache.get(&key) {
+        return *value;
+    }
+
+    if cache.len() >= MAX_ENTRIES {
+        cache.clear();
+    }
+
+    let value = convert();
+    cache.insert(key, value);
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_conversion() {
+        clear();
+
+        assert_eq!(to_hsl((1, 2, 3), || (1.0, 2.0, 3.0)), (1.0, 2.0, 3.0));
+        assert_eq!(to_hsl((1, 2, 3), || panic!("expected cached value")), (1.0, 2.0, 3.0));
+
+        assert_eq!(from_hsl((1.0, 2.0, 3.0), || (1, 2, 3)), (1, 2, 3));
+        assert_eq!(from_hsl((1.0, 2.0, 3.0), || panic!("expected cached value")), (1, 2, 3));
+
+        clear();
+        assert_eq!(to_hsl((1, 2, 3), || (4.0, 5.0, 6.0)), (4.0, 5.0, 6.0));
+        assert_eq!(from_hsl((1.0, 2.0, 3.0), || (4, 5, 6)), (4, 5, 6));
+    }
+
+    #[test]
+    fn test_cache_is_bounded() {
+        clear();
+
+        (0..MAX_ENTRIES as u32 + 10).for_each(|i| {
+            to_hsl((i as u8, (i >> 8) as u8, 0), || (0.0, 0.0, 0.0));
+            from_hsl((i as f32, 0.0, 0.0), || (0, 0, 0));
+        });
+
+        TO_HSL.with(|cache| assert!(cache.borrow().len() <= MAX_ENTRIES));
+        FROM_HSL.with(|cache| assert!(cache.borrow().len() <= MAX_ENTRIES));
+    }
+}
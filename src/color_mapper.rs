@@ -1,12 +1,23 @@
 use ratatui::style::Color;
 
+use crate::Interpolatable;
+
 /// A utility struct for mapping and transforming colors based on
 /// a given alpha value. The `ColorMapper` caches the original color
 /// and alpha value to avoid redundant transformations.
-#[derive(Default)]
+#[derive(Clone, Debug, Default)]
 pub struct ColorMapper {
     original: (Color, f32),
-    transformed: Color
+    transformed: Color,
+    lut: Option<ColorLut>,
+}
+
+/// A precomputed gradient between two colors, see [ColorMapper::precompute].
+#[derive(Clone, Debug)]
+struct ColorLut {
+    from: Color,
+    to: Color,
+    colors: Vec<Color>,
 }
 
 impl ColorMapper {
@@ -47,4 +58,99 @@ impl ColorMapper {
 
         self.transformed
     }
-}
\ No newline at end of file
+
+    /// Maps the given color to its interpolation toward `to`, same as calling
+    /// [map](ColorMapper::map) with `|c| c.lerp(&to, alpha)`. If a lookup table has been
+    /// [precomputed](ColorMapper::precompute) from `from_color` to `to`, the color is
+    /// resolved from the lookup table instead.
+    ///
+    /// # Arguments
+    /// * `from_color` - The original color to be transformed.
+    /// * `to` - The target color, at alpha 1.0.
+    /// * `alpha` - The alpha value used for the interpolation.
+    ///
+    /// # Returns
+    /// * The interpolated color.
+    pub fn lerp(&mut self, from_color: Color, to: Color, alpha: f32) -> Color {
+        match self.lookup(from_color, to, alpha) {
+            Some(color) => color,
+            None => self.map(from_color, alpha, |c| c.lerp(&to, alpha)),
+        }
+    }
+
+    /// Returns the interpolated color from the lookup table, if one has been precomputed
+    /// from `from_color` to `to`.
+    pub(crate) fn lookup(&self, from_color: Color, to: Color, alpha: f32) -> Option<Color> {
+        self.lut.as_ref()
+            .filter(|lut| (lut.from, lut.to) == (from_color, to))
+            .map(|lut| lut.lookup(alpha))
+    }
+
+    /// Returns true if a lookup table has been precomputed.
+    pub(crate) fn is_precomputed(&self) -> bool {
+        self.lut.is_some()
+    }
+
+    /// Precomputes a lookup table of `steps` colors interpolated from `from` to `to`,
+    /// replacing any previous table. Subsequent calls to [lerp](ColorMapper::lerp) from
+    /// `from` to `to` resolve the color from the table, avoiding repeated HSL conversions
+    /// when the mapper is kept across frames.
+    ///
+    /// # Arguments
+    /// * `from` - The source color, at alpha 0.0.
+    /// * `to` - The target color, at alpha 1.0.
+    /// * `steps` - The number of colors in the lookup table; at least 2.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{ColorMapper, Interpolatable};
+    ///
+    /// let (from, to) = (Color::from_u32(0x1e1e2e), Color::from_u32(0xf5c2e7));
+    ///
+    /// let mut mapper = ColorMapper::default();
+    /// mapper.precompute(from, to, 64);
+    ///
+    /// let color = mapper.lerp(from, to, 0.5);
+    /// ```
+    pub fn precompute(&mut self, from: Color, to: Color, steps: usize) {
+        let steps = steps.max(2);
+        let colors = (0..steps)
+            .map(|i| from.lerp(&to, i as f32 / (steps - 1) as f32))
+            .collect();
+
+        self.lut = Some(ColorLut { from, to, colors });
+    }
+}
+
+impl ColorLut {
+    fn lookup(&self, alpha: f32) -> Color {
+        let last = self.colors.len() - 1;
+        let idx = (alpha.clamp(0.0, 1.0) * last as f32).round() as usize;
+        self.colors[idx]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_precomputed_lut() {
+        let (from, to) = (Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255));
+
+        let mut mapper = ColorMapper::default();
+        mapper.precompute(from, to, 3);
+
+        assert_eq!(mapper.lerp(from, to, 0.0), from);
+        assert_eq!(mapper.lerp(from, to, 0.4), from.lerp(&to, 0.5));
+        assert_eq!(mapper.lerp(from, to, 1.0), to);
+
+        // the lookup table is keyed on both colors
+        assert_eq!(mapper.lerp(from, Color::Rgb(255, 0, 0), 0.4), from.lerp(&Color::Rgb(255, 0, 0), 0.4));
+        assert_eq!(mapper.lerp(Color::Red, to, 0.4), Color::Red.lerp(&to, 0.4));
+
+        // other transforms are never resolved from the lookup table
+        assert_eq!(mapper.map(from, 0.4, |_| Color::Blue), Color::Blue);
+    }
+}
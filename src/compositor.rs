@@ -0,0 +1,478 @@
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Offset, Position};
+
+use crate::{Duration, Effect, Interpolatable, RefCount, Shader};
+
+/// How the cells of a [Layer] are combined with the cells beneath it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Layer cells replace the cells beneath them. With an alpha below 1.0, the colors
+    /// are interpolated toward the layer's colors, and the symbol is replaced once the
+    /// alpha reaches 0.5.
+    #[default]
+    Normal,
+    /// Like `Normal`, but blank layer cells are transparent.
+    Text,
+    /// Only the colors of the layer are blended, keeping the symbols beneath. Useful
+    /// for tinting or lighting layers.
+    Color,
+}
+
+/// A named layer of a [Compositor], holding the content of the layer and an optional
+/// effect applied to it.
+pub struct Layer {
+    name: String,
+    content: RefCount<Buffer>,
+    effect: Option<Effect>,
+    blend: BlendMode,
+    alpha: f32,
+    offset: Offset,
+    visible: bool,
+    back_buffer: Buffer,
+    #[cfg(feature = "graphics")]
+    image: Option<crate::graphics::Image>,
+    /// where the image was last written to the screen, if it's shown
+    #[cfg(feature = "graphics")]
+    image_placement: Option<ratatui::layout::Rect>,
+    /// a replaced image which is still shown, removed by the next `write_images`
+    #[cfg(feature = "graphics")]
+    replaced_image: Option<crate::graphics::Image>,
+}
+
+impl Layer {
+    /// Creates a layer with the given content. Applications render into the content
+    /// buffer, which is never modified by the compositor.
+    pub fn new(name: impl Into<String>, content: RefCount<Buffer>) -> Self {
+        Self {
+            name: name.into(),
+            content,
+            effect: None,
+            blend: BlendMode::Normal,
+            alpha: 1.0,
+            offset: Offset::default(),
+            visible: true,
+            back_buffer: Buffer::default(),
+            #[cfg(feature = "graphics")]
+            image: None,
+            #[cfg(feature = "graphics")]
+            image_placement: None,
+            #[cfg(feature = "graphics")]
+            replaced_image: None,
+        }
+    }
+
+    /// Sets the effect applied to the layer each frame.
+    pub fn with_effect(mut self, effect: Effect) -> Self {
+        self.effect = Some(effect);
+        self
+    }
+
+    /// Sets the blend mode of the layer.
+    pub fn with_blend(mut self, blend: BlendMode) -> Self {
+        self.blend = blend;
+        self
+    }
+
+    /// Sets the opacity of the layer, between 0.0 and 1.0.
+    pub fn with_alpha(mut self, alpha: f32) -> Self {
+        self.set_alpha(alpha);
+        self
+    }
+
+    /// Sets the position of the layer's content within the frame buffer.
+    pub fn with_offset(mut self, offset: Offset) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the image carried by the layer, displayed above the layer's cells by
+    /// [Compositor::write_images].
+    #[cfg(feature = "graphics")]
+    pub fn with_image(mut self, image: crate::graphics::Image) -> Self {
+        self.set_image(Some(image));
+        self
+    }
+
+    /// Returns the name of the layer.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the content buffer of the layer.
+    pub fn content(&self) -> &RefCount<Buffer> {
+        &self.content
+    }
+
+    /// Replaces the effect applied to the layer.
+    pub fn set_effect(&mut self, effect: Option<Effect>) {
+        self.effect = effect;
+    }
+
+    /// Sets the blend mode of the layer.
+    pub fn set_blend(&mut self, blend: BlendMode) {
+        self.blend = blend;
+    }
+
+    /// Sets the opacity of the layer, between 0.0 and 1.0.
+    pub fn set_alpha(&mut self, alpha: f32) {
+        self.alpha = alpha.clamp(0.0, 1.0);
+    }
+
+    /// Sets the position of the layer's content within the frame buffer.
+    pub fn set_offset(&mut self, offset: Offset) {
+        self.offset = offset;
+    }
+
+    /// Replaces the image carried by the layer.
+    #[cfg(feature = "graphics")]
+    pub fn set_image(&mut self, image: Option<crate::graphics::Image>) {
+        let replaced = core::mem::replace(&mut self.image, image);
+        if self.image_placement.take().is_some() {
+            self.replaced_image = replaced;
+        }
+    }
+
+    /// Returns the image carried by the layer.
+    #[cfg(feature = "graphics")]
+    pub fn image(&self) -> Option<&crate::graphics::Image> {
+        self.image.as_ref()
+    }
+
+    /// Shows or hides the layer. Effects of hidden layers aren't processed.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Returns true if the layer has an effect which is still running.
+    pub fn is_running(&self) -> bool {
+        #[cfg(feature = "graphics")]
+        if self.image.as_ref().is_some_and(crate::graphics::Image::is_running) {
+            return true;
+        }
+
+        self.effect.as_ref().is_some_and(Effect::running)
+    }
+
+    /// Copies the content into the back buffer, and applies the effect to it.
+    fn process(&mut self, duration: Duration) {
+        #[cfg(not(feature = "sendable"))]
+        let content = self.content.borrow();
+        #[cfg(feature = "sendable")]
+        let content = self.content.lock().unwrap();
+
+        if self.back_buffer.area != content.area {
+            self.back_buffer.resize(content.area);
+        }
+        self.back_buffer.content.clone_from_slice(&content.content);
+        drop(content);
+
+        if let Some(effect) = self.effect.as_mut() {
+            let area = effect.area().unwrap_or(self.back_buffer.area);
+            effect.process(duration, &mut self.back_buffer, area);
+        }
+
+        #[cfg(feature = "graphics")]
+        if let Some(image) = self.image.as_mut() {
+            image.process(duration);
+        }
+    }
+
+    /// Marks the cells beneath the layer's image as skipped, so that the terminal
+    /// backend doesn't draw over the image.
+    #[cfg(feature = "graphics")]
+    fn reserve_image_area(&self, buf: &mut Buffer) {
+        let Some(area) = self.image.as_ref().and_then(|i| i.area(self.offset, buf.area)) else {
+            return;
+        };
+
+        for pos in area.positions() {
+            buf[pos].skip = true;
+        }
+    }
+
+    /// Blends the back buffer onto the frame buffer.
+    fn blend_onto(&self, buf: &mut Buffer) {
+        if self.alpha == 0.0 {
+            return;
+        }
+
+        let src = &self.back_buffer;
+        for pos in src.area.positions() {
+            let cell = &src[pos];
+            if cell.skip || (self.blend == BlendMode::Text && cell.symbol() == " ") {
+                continue;
+            }
+
+            let x = pos.x as i32 - src.area.x as i32 + self.offset.x;
+            let y = pos.y as i32 - src.area.y as i32 + self.offset.y;
+            let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) else { continue };
+            let Some(target) = buf.cell_mut(Position::new(x, y)) else { continue };
+
+            blend_cell(target, cell, self.blend, self.alpha);
+        }
+    }
+}
+
+/// Composites an ordered stack of named layers onto the frame buffer.
+///
+/// Each layer holds a content buffer, which the application renders into, and an
+/// optional effect. Every frame, the compositor copies each layer's content into a
+/// back buffer owned by the layer, applies the layer's effect to the back buffer and
+/// blends the result onto the frame buffer, bottom layer first. Since effects operate
+/// on the back buffers, the content only needs to be rendered when it changes.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Offset, Rect};
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, ref_count, BlendMode, Compositor, Duration, Interpolation, Layer};
+///
+/// let screen = Rect::new(0, 0, 40, 10);
+/// let popup = ref_count(Buffer::with_lines(["hello", "world"]));
+///
+/// let mut compositor = Compositor::default();
+/// compositor.push(Layer::new("popup", popup)
+///     .with_effect(fx::fade_from_fg(Color::Black, (500, Interpolation::Linear)))
+///     .with_offset(Offset { x: 10, y: 4 })
+///     .with_blend(BlendMode::Text));
+///
+/// let mut buf = Buffer::empty(screen);
+/// compositor.composite(Duration::from_millis(16), &mut buf);
+/// assert_eq!(buf[(10, 4)].symbol(), "h");
+///
+/// compositor.layer_mut("popup").unwrap().set_alpha(0.25);
+/// ```
+#[derive(Default)]
+pub struct Compositor {
+    layers: Vec<Layer>,
+}
+
+impl Compositor {
+    /// Adds a layer on top of all other layers. Any existing layer with the same
+    /// name is replaced.
+    pub fn push(&mut self, layer: Layer) {
+        self.remove(&layer.name);
+        self.layers.push(layer);
+    }
+
+    /// Inserts a layer at the given position in the stack, where 0 is the bottom layer.
+    /// Any existing layer with the same name is replaced.
+    pub fn insert(&mut self, index: usize, layer: Layer) {
+        self.remove(&layer.name);
+        self.layers.insert(index.min(self.layers.len()), layer);
+    }
+
+    /// Removes the layer with the given name.
+    pub fn remove(&mut self, name: &str) -> Option<Layer> {
+        let idx = self.layers.iter().position(|l| l.name == name)?;
+        Some(self.layers.remove(idx))
+    }
+
+    /// Returns the layer with the given name.
+    pub fn layer(&self, name: &str) -> Option<&Layer> {
+        self.layers.iter().find(|l| l.name == name)
+    }
+
+    /// Returns the layer with the given name, for updating its effect or blending.
+    pub fn layer_mut(&mut self, name: &str) -> Option<&mut Layer> {
+        self.layers.iter_mut().find(|l| l.name == name)
+    }
+
+    /// Returns the names of all layers, from bottom to top.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.layers.iter().map(Layer::name)
+    }
+
+    /// Returns true if any layer has an effect which is still running.
+    pub fn is_running(&self) -> bool {
+        self.layers.iter().any(Layer::is_running)
+    }
+
+    /// Processes the effects of all visible layers, and composites the layers onto
+    /// the buffer, bottom layer first.
+    pub fn composite(&mut self, duration: Duration, buf: &mut Buffer) {
+        for layer in self.layers.iter_mut().filter(|l| l.visible) {
+            layer.process(duration);
+            layer.blend_onto(buf);
+            #[cfg(feature = "graphics")]
+            layer.reserve_image_area(buf);
+        }
+    }
+
+    /// Writes the escape sequences displaying the images of all visible layers onto the
+    /// screen, bottom layer first, and removing the images of hidden layers. Call after
+    /// the frame has been drawn to the terminal, writing to the terminal's output.
+    ///
+    /// Images are only written when they, or their positions, have changed since the last
+    /// call; see [invalidate_images](Compositor::invalidate_images). Images which don't fit
+    /// entirely on the screen are hidden, as they can't be cropped to the screen.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::io::Write;
+    /// use ratatui::layout::Rect;
+    /// use tachyonfx::Compositor;
+    ///
+    /// let mut compositor = Compositor::default();
+    /// // ... terminal.draw(|f| compositor.composite(elapsed, f.buffer_mut()))
+    /// let screen = Rect::new(0, 0, 80, 24);
+    /// let mut stdout = std::io::stdout();
+    /// compositor.write_images(&mut stdout, screen).unwrap();
+    /// stdout.flush().unwrap();
+    /// ```
+    #[cfg(feature = "graphics")]
+    pub fn write_images<W: std::io::Write>(&mut self, w: &mut W, screen: ratatui::layout::Rect) -> std::io::Result<()> {
+        for layer in self.layers.iter_mut() {
+            if let Some(replaced) = layer.replaced_image.take() {
+                replaced.write_hide(w)?;
+            }
+
+            let offset = layer.offset;
+            let visible = layer.visible;
+            let Some(image) = layer.image.as_mut() else { continue };
+
+            match image.area(offset, screen).filter(|_| visible) {
+                Some(area) if layer.image_placement == Some(area) => {}
+                Some(area) => {
+                    image.write_to(w, area.x, area.y)?;
+                    layer.image_placement = Some(area);
+                }
+                None if layer.image_placement.is_some() => {
+                    image.write_hide(w)?;
+                    layer.image_placement = None;
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Makes the next call to [write_images](Compositor::write_images) write all images
+    /// again. Call after the terminal has been cleared or resized, which erases them.
+    #[cfg(feature = "graphics")]
+    pub fn invalidate_images(&mut self) {
+        self.layers.iter_mut().for_each(|l| l.image_placement = None);
+    }
+}
+
+fn blend_cell(target: &mut Cell, cell: &Cell, blend: BlendMode, alpha: f32) {
+    let replace_symbol = blend != BlendMode::Color && alpha >= 0.5;
+
+    if alpha == 1.0 && blend != BlendMode::Color {
+        *target = cell.clone();
+        return;
+    }
+
+    target.fg = target.fg.lerp(&cell.fg, alpha);
+    target.bg = target.bg.lerp(&cell.bg, alpha);
+    if replace_symbol {
+        target.set_symbol(cell.symbol());
+        target.modifier = cell.modifier;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Rect;
+    use ratatui::style::{Color, Style};
+
+    use crate::{fx, ref_count, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_composite_layers_in_order() {
+        let mut compositor = Compositor::default();
+        compositor.push(Layer::new("bottom", ref_count(Buffer::with_lines(["aaaa", "aaaa"]))));
+        compositor.push(Layer::new("top", ref_count(Buffer::with_lines(["b b"])))
+            .with_offset(Offset { x: 1, y: 1 })
+            .with_blend(BlendMode::Text));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        compositor.composite(Duration::from_millis(10), &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["aaaa ", "abab "]));
+
+        compositor.insert(0, Layer::new("top", ref_count(Buffer::with_lines(["ccccc"]))));
+        assert_eq!(compositor.names().collect::<Vec<_>>(), vec!["top", "bottom"]);
+
+        // the replaced "top" layer is now beneath the "bottom" layer
+        compositor.composite(Duration::from_millis(10), &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["aaaac", "aaaa "]));
+    }
+
+    #[test]
+    fn test_effects_apply_to_back_buffer() {
+        let content = ref_count(Buffer::with_lines(["ab"]));
+        let timer = (100, Interpolation::Linear);
+
+        let mut compositor = Compositor::default();
+        compositor.push(Layer::new("fx", content.clone())
+            .with_effect(fx::fade_to_fg(Color::Red, timer)));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        compositor.composite(Duration::from_millis(100), &mut buf);
+        assert!(!compositor.is_running());
+
+        let mut expected = Buffer::with_lines(["ab"]);
+        expected.set_style(expected.area, Style::default().fg(Color::Red));
+        assert_eq!(buf, expected);
+
+        // the content is left untouched
+        #[cfg(not(feature = "sendable"))]
+        assert_eq!(*content.borrow(), Buffer::with_lines(["ab"]));
+        #[cfg(feature = "sendable")]
+        assert_eq!(*content.lock().unwrap(), Buffer::with_lines(["ab"]));
+    }
+
+    #[cfg(feature = "graphics")]
+    #[test]
+    fn test_image_layers() {
+        use ratatui::layout::Size;
+        use crate::graphics::{Image, ImagePayload};
+
+        let sixel = "\x1bPq#0~\x1b\\".to_string();
+        let mut compositor = Compositor::default();
+        compositor.push(Layer::new("image", ref_count(Buffer::default()))
+            .with_offset(Offset { x: 1, y: 0 })
+            .with_image(Image::new(ImagePayload::Sixel(sixel.clone()), Size::new(2, 1))));
+
+        let screen = Rect::new(0, 0, 4, 2);
+        let mut buf = Buffer::empty(screen);
+        compositor.composite(Duration::from_millis(10), &mut buf);
+        let skipped: Vec<_> = screen.positions().filter(|&p| buf[p].skip).collect();
+        assert_eq!(skipped, vec![Position::new(1, 0), Position::new(2, 0)]);
+
+        let mut out = Vec::new();
+        compositor.write_images(&mut out, screen).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), format!("\x1b7\x1b[1;2H{sixel}\x1b8"));
+
+        // unchanged images aren't written again, unless invalidated
+        let mut out = Vec::new();
+        compositor.write_images(&mut out, screen).unwrap();
+        assert!(out.is_empty());
+
+        compositor.invalidate_images();
+        compositor.write_images(&mut out, screen).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), format!("\x1b7\x1b[1;2H{sixel}\x1b8"));
+    }
+
+    #[test]
+    fn test_color_blend_keeps_symbols() {
+        let mut tint = Buffer::with_lines(["  "]);
+        tint.set_style(tint.area, Style::default().bg(Color::Rgb(200, 0, 0)));
+
+        let mut compositor = Compositor::default();
+        compositor.push(Layer::new("tint", ref_count(tint))
+            .with_blend(BlendMode::Color)
+            .with_alpha(0.5));
+
+        let mut buf = Buffer::with_lines(["xy"]);
+        buf.set_style(buf.area, Style::default().bg(Color::Rgb(0, 0, 0)));
+        compositor.composite(Duration::from_millis(10), &mut buf);
+
+        assert_eq!(buf[(0, 0)].symbol(), "x");
+        assert_eq!(buf[(0, 0)].bg, Color::Rgb(0, 0, 0).lerp(&Color::Rgb(200, 0, 0), 0.5));
+    }
+}
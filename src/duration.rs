@@ -1,60 +1,168 @@
 #[cfg(feature = "std-duration")]
-pub type Duration = std::time::Duration;
+pub type Duration = core::time::Duration;
 
 #[cfg(not(feature = "std-duration"))]
 pub type Duration = duration::Duration;
 
 #[cfg(not(feature = "std-duration"))]
+#[allow(clippy::module_inception)]
+// casts to and from `Ticks` are only conversions with the "micros-duration" feature
+#[allow(clippy::unnecessary_cast)]
 pub mod duration {
-    // Your custom Duration implementation goes here
-
-    use std::iter::Sum;
-    use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
-
+    use core::iter::Sum;
+    use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+    /// The unit of the custom [Duration]: whole milliseconds, or microseconds with the
+    /// "micros-duration" feature.
+    #[cfg(not(feature = "micros-duration"))]
+    type Ticks = u32;
+    #[cfg(feature = "micros-duration")]
+    type Ticks = u64;
+
+    #[cfg(not(feature = "micros-duration"))]
+    const TICKS_PER_MS: Ticks = 1;
+    #[cfg(feature = "micros-duration")]
+    const TICKS_PER_MS: Ticks = 1000;
+
+    /// A duration used unless the "std-duration" feature is enabled.
+    ///
+    /// Durations are stored as whole milliseconds in 4 bytes, spanning up to ~49.7 days.
+    /// With the "micros-duration" feature, they're stored as microseconds in 8 bytes, so
+    /// that frame times at high frame rates aren't truncated to whole milliseconds.
+    ///
+    /// Effects add up and scale durations with the saturating methods, so processing
+    /// never panics on overflow.
     #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
     pub struct Duration {
+        /// Not available with the "micros-duration" feature; prefer
+        /// [from_millis](Duration::from_millis) and [as_millis](Duration::as_millis).
+        #[cfg(not(feature = "micros-duration"))]
         pub milliseconds: u32,
+        #[cfg(feature = "micros-duration")]
+        micros: u64,
     }
 
     impl Duration {
-        pub const ZERO: Self = Self { milliseconds: 0 };
+        pub const ZERO: Self = Self::from_ticks(0);
 
-        pub const fn from_millis(milliseconds: u32) -> Self {
+        #[cfg(not(feature = "micros-duration"))]
+        const fn from_ticks(milliseconds: Ticks) -> Self {
             Self { milliseconds }
         }
 
+        #[cfg(feature = "micros-duration")]
+        const fn from_ticks(micros: Ticks) -> Self {
+            Self { micros }
+        }
+
+        #[cfg(not(feature = "micros-duration"))]
+        const fn ticks(self) -> Ticks {
+            self.milliseconds
+        }
+
+        #[cfg(feature = "micros-duration")]
+        const fn ticks(self) -> Ticks {
+            self.micros
+        }
+
+        pub const fn from_millis(milliseconds: u32) -> Self {
+            Self::from_ticks(milliseconds as Ticks * TICKS_PER_MS)
+        }
+
+        /// Creates a duration from microseconds, truncated to whole milliseconds without
+        /// the "micros-duration" feature.
+        pub const fn from_micros(micros: u64) -> Self {
+            let ticks = micros / (1000 / TICKS_PER_MS as u64);
+            if ticks > Ticks::MAX as u64 {
+                Self::from_ticks(Ticks::MAX)
+            } else {
+                Self::from_ticks(ticks as Ticks)
+            }
+        }
+
+        /// Creates a duration from whole seconds, saturating at `u32::MAX` milliseconds.
         pub const fn from_secs(seconds: u32) -> Self {
-            Self { milliseconds: seconds * 1000 }
+            Self::from_millis(seconds.saturating_mul(1000))
         }
 
         pub fn from_secs_f32(seconds: f32) -> Self {
-            Self { milliseconds: (seconds * 1000.0) as u32 }
+            Self::from_ticks((seconds * 1000.0 * TICKS_PER_MS as f32) as Ticks)
         }
 
+        /// Returns the number of whole milliseconds, saturating at `u32::MAX`.
         pub fn as_millis(&self) -> u32 {
-            self.milliseconds
+            (self.ticks() / TICKS_PER_MS).min(u32::MAX as Ticks) as u32
+        }
+
+        /// Returns the number of whole microseconds.
+        pub fn as_micros(&self) -> u64 {
+            self.ticks() as u64 * (1000 / TICKS_PER_MS as u64)
         }
 
         pub const fn is_zero(&self) -> bool {
-            self.milliseconds == 0
+            self.ticks() == 0
         }
 
         pub fn as_secs_f32(&self) -> f32 {
-            self.milliseconds as f32 / 1000.0
+            self.ticks() as f32 / (1000.0 * TICKS_PER_MS as f32)
         }
 
-        pub fn checked_sub(&self, other: Self) -> Option<Self> {
-            self.milliseconds
-                .checked_sub(other.milliseconds)
-                .map(Self::from_millis)
+        /// Returns `self + rhs`, or `None` on overflow.
+        pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+            match self.ticks().checked_add(rhs.ticks()) {
+                Some(ticks) => Some(Self::from_ticks(ticks)),
+                None        => None,
+            }
+        }
+
+        /// Returns `self - rhs`, or `None` if `rhs` is greater than `self`.
+        pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+            match self.ticks().checked_sub(rhs.ticks()) {
+                Some(ticks) => Some(Self::from_ticks(ticks)),
+                None        => None,
+            }
+        }
+
+        /// Returns `self * rhs`, or `None` on overflow.
+        pub const fn checked_mul(self, rhs: u32) -> Option<Self> {
+            match self.ticks().checked_mul(rhs as Ticks) {
+                Some(ticks) => Some(Self::from_ticks(ticks)),
+                None        => None,
+            }
+        }
+
+        /// Returns `self / rhs`, or `None` if `rhs` is zero.
+        pub const fn checked_div(self, rhs: u32) -> Option<Self> {
+            match self.ticks().checked_div(rhs as Ticks) {
+                Some(ticks) => Some(Self::from_ticks(ticks)),
+                None        => None,
+            }
+        }
+
+        /// Returns `self + rhs`, saturating at the maximum duration.
+        pub const fn saturating_add(self, rhs: Self) -> Self {
+            Self::from_ticks(self.ticks().saturating_add(rhs.ticks()))
+        }
+
+        /// Returns `self - rhs`, or [Duration::ZERO] if `rhs` is greater than `self`.
+        pub const fn saturating_sub(self, rhs: Self) -> Self {
+            Self::from_ticks(self.ticks().saturating_sub(rhs.ticks()))
+        }
+
+        /// Returns `self * rhs`, saturating at the maximum duration.
+        pub const fn saturating_mul(self, rhs: u32) -> Self {
+            Self::from_ticks(self.ticks().saturating_mul(rhs as Ticks))
         }
     }
 
+    // like std::time::Duration, the operators panic on overflow; use the
+    // checked or saturating methods where durations aren't bounded.
+
     impl Mul<u32> for Duration {
         type Output = Self;
 
         fn mul(self, rhs: u32) -> Self {
-            Self { milliseconds: self.milliseconds * rhs }
+            self.checked_mul(rhs).expect("overflow when multiplying duration by scalar")
         }
     }
 
@@ -62,7 +170,7 @@ pub mod duration {
         type Output = Self;
 
         fn add(self, rhs: Self) -> Self {
-            Self { milliseconds: self.milliseconds + rhs.milliseconds }
+            self.checked_add(rhs).expect("overflow when adding durations")
         }
     }
 
@@ -70,19 +178,19 @@ pub mod duration {
         type Output = Self;
 
         fn add(self, rhs: u32) -> Self {
-            Self { milliseconds: self.milliseconds + rhs }
+            self + Self::from_millis(rhs)
         }
     }
 
     impl AddAssign<Duration> for Duration {
         fn add_assign(&mut self, rhs: Self) {
-            self.milliseconds += rhs.milliseconds;
+            *self = *self + rhs;
         }
     }
 
     impl AddAssign<u32> for Duration {
         fn add_assign(&mut self, rhs: u32) {
-            self.milliseconds += rhs;
+            *self = *self + rhs;
         }
     }
 
@@ -90,7 +198,7 @@ pub mod duration {
         type Output = Self;
 
         fn sub(self, rhs: Self) -> Self {
-            Self { milliseconds: self.milliseconds - rhs.milliseconds }
+            self.checked_sub(rhs).expect("overflow when subtracting durations")
         }
     }
 
@@ -98,19 +206,19 @@ pub mod duration {
         type Output = Self;
 
         fn sub(self, rhs: u32) -> Self {
-            Self { milliseconds: self.milliseconds - rhs }
+            self - Self::from_millis(rhs)
         }
     }
 
     impl SubAssign<Duration> for Duration {
         fn sub_assign(&mut self, rhs: Self) {
-            self.milliseconds -= rhs.milliseconds;
+            *self = *self - rhs;
         }
     }
 
     impl SubAssign<u32> for Duration {
         fn sub_assign(&mut self, rhs: u32) {
-            self.milliseconds -= rhs;
+            *self = *self - rhs;
         }
     }
 
@@ -118,7 +226,7 @@ pub mod duration {
         type Output = Duration;
 
         fn mul(self, rhs: Duration) -> Self::Output {
-            Duration { milliseconds: self * rhs.milliseconds }
+            rhs * self
         }
     }
 
@@ -126,25 +234,25 @@ pub mod duration {
         type Output = Duration;
 
         fn mul(self, rhs: f32) -> Duration {
-            Duration { milliseconds: (self.milliseconds as f32 * rhs) as u32 }
+            Duration::from_ticks((self.ticks() as f32 * rhs) as Ticks)
         }
     }
 
     impl Sum for Duration {
         fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-            iter.fold(Self::ZERO, Add::add)
+            iter.fold(Self::ZERO, Self::saturating_add)
         }
     }
 
-    impl From<std::time::Duration> for Duration {
-        fn from(d: std::time::Duration) -> Self {
-            Self { milliseconds: d.as_millis() as u32 }
+    impl From<core::time::Duration> for Duration {
+        fn from(d: core::time::Duration) -> Self {
+            Self::from_ticks((d.as_micros() / (1000 / TICKS_PER_MS as u128)).min(Ticks::MAX as u128) as Ticks)
         }
     }
 
-    impl From<Duration> for std::time::Duration {
+    impl From<Duration> for core::time::Duration {
         fn from(d: Duration) -> Self {
-            std::time::Duration::from_millis(d.milliseconds as u64)
+            core::time::Duration::from_micros(d.as_micros())
         }
     }
 
@@ -161,7 +269,8 @@ pub mod duration {
 
         #[test]
         fn verify_size_of_duration() {
-            assert_eq!(size_of::<Duration>(), 4);
+            let expected = if cfg!(feature = "micros-duration") { 8 } else { 4 };
+            assert_eq!(size_of::<Duration>(), expected);
         }
 
         #[test]
@@ -179,7 +288,7 @@ pub mod duration {
 
         #[test]
         fn test_duration_sum() {
-            let durations = vec![
+            let durations = [
                 Duration::from_millis(100),
                 Duration::from_millis(200),
                 Duration::from_millis(300),
@@ -206,5 +315,49 @@ pub mod duration {
             assert_eq!(d1.checked_sub(d2), Some(Duration::from_millis(100)));
             assert_eq!(d2.checked_sub(d1), None);
         }
+
+        #[test]
+        #[cfg(not(feature = "micros-duration"))]
+        fn test_duration_checked_and_saturating() {
+            let max = Duration::from_millis(u32::MAX);
+            let d = Duration::from_millis(100);
+
+            assert_eq!(max.checked_add(d), None);
+            assert_eq!(d.checked_mul(u32::MAX), None);
+            assert_eq!(d.checked_div(0), None);
+            assert_eq!(d.checked_div(4), Some(Duration::from_millis(25)));
+
+            assert_eq!(max.saturating_add(d), max);
+            assert_eq!(d.saturating_sub(max), Duration::ZERO);
+            assert_eq!(d.saturating_mul(u32::MAX), max);
+            assert_eq!(Duration::from_secs(u32::MAX), max);
+        }
+
+        #[test]
+        #[should_panic(expected = "overflow when subtracting durations")]
+        fn test_duration_sub_overflow() {
+            let _ = Duration::from_millis(100) - Duration::from_millis(200);
+        }
+
+        #[test]
+        #[cfg(not(feature = "micros-duration"))]
+        fn test_duration_from_std_saturates() {
+            let d: Duration = std::time::Duration::from_secs(60 * 60 * 24 * 100).into();
+            assert_eq!(d, Duration::from_millis(u32::MAX));
+        }
+
+        #[test]
+        fn test_duration_micros() {
+            let tick = Duration::from_micros(8_333);
+            let elapsed: Duration = std::iter::repeat(tick).take(120).sum();
+
+            if cfg!(feature = "micros-duration") {
+                assert_eq!(elapsed.as_micros(), 999_960);
+                assert_eq!(elapsed.as_millis(), 999);
+            } else {
+                assert_eq!(elapsed, Duration::from_millis(960));
+            }
+            assert_eq!(Duration::from_millis(3).as_micros(), 3_000);
+        }
     }
 }
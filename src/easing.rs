@@ -0,0 +1,228 @@
+//! Easing functions, as visualised on [easings.net](https://easings.net/). Ported from
+//! the `simple-easing` crate, using the float functions in [crate::math] to remain
+//! available without `std`.
+
+use core::f32::consts::PI;
+use crate::math::{cos, exp2, mul_add, powi, sin, sqrt};
+
+const C1: f32 = 1.70158;
+const C2: f32 = C1 * 1.525;
+const C3: f32 = C1 + 1.0;
+const C4: f32 = (2.0 * PI) / 3.0;
+const C5: f32 = (2.0 * PI) / 4.5;
+
+pub(crate) fn back_in(t: f32) -> f32 {
+    mul_add(C3 * t * t, t, -(C1 * t * t))
+}
+
+pub(crate) fn back_out(t: f32) -> f32 {
+    mul_add(C1, powi(t - 1.0, 2), mul_add(C3, powi(t - 1.0, 3), 1.0))
+}
+
+pub(crate) fn back_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        (powi(2.0 * t, 2) * mul_add((C2 + 1.0) * 2.0, t, -C2)) / 2.0
+    } else {
+        let a = powi(mul_add(2.0, t, -2.0), 2) * mul_add(C2 + 1.0, mul_add(t, 2.0, -2.0), C2);
+        (a + 2.0) / 2.0
+    }
+}
+
+pub(crate) fn bounce_in(t: f32) -> f32 {
+    1.0 - bounce_out(1.0 - t)
+}
+
+pub(crate) fn bounce_out(t: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    if t < 1.0 / D1 {
+        N1 * t * t
+    } else if t < 2.0 / D1 {
+        mul_add(N1, powi(t - 1.5 / D1, 2), 0.75)
+    } else if t < 2.5 / D1 {
+        mul_add(N1, powi(t - 2.25 / D1, 2), 0.937_5)
+    } else {
+        mul_add(N1, powi(t - 2.625 / D1, 2), 0.984_375)
+    }
+}
+
+pub(crate) fn bounce_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        (1.0 - bounce_out(mul_add(2.0, -t, 1.0))) / 2.0
+    } else {
+        (1.0 + bounce_out(mul_add(2.0, t, -1.0))) / 2.0
+    }
+}
+
+pub(crate) fn circ_in(t: f32) -> f32 {
+    1.0 - sqrt(mul_add(t, -t, 1.0))
+}
+
+pub(crate) fn circ_out(t: f32) -> f32 {
+    sqrt(mul_add(t - 1.0, -(t - 1.0), 1.0))
+}
+
+pub(crate) fn circ_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        (1.0 - sqrt(mul_add(2.0 * t, -(2.0 * t), 1.0))) / 2.0
+    } else {
+        let u = mul_add(-2.0, t, 2.0);
+        (sqrt(mul_add(u, -u, 1.0)) + 1.0) / 2.0
+    }
+}
+
+pub(crate) fn cubic_in(t: f32) -> f32 {
+    t * t * t
+}
+
+pub(crate) fn elastic_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if 1.0 <= t {
+        1.0
+    } else {
+        -exp2(mul_add(10.0, t, -10.0)) * sin(mul_add(t, 10.0, -10.75) * C4)
+    }
+}
+
+pub(crate) fn elastic_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if 1.0 <= t {
+        1.0
+    } else {
+        mul_add(exp2(-10.0 * t), sin(mul_add(t, 10.0, -0.75) * C4), 1.0)
+    }
+}
+
+pub(crate) fn elastic_in_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if 1.0 <= t {
+        1.0
+    } else if t < 0.5 {
+        -(exp2(mul_add(20.0, t, -10.0)) * sin(mul_add(20.0, t, -11.125) * C5)) / 2.0
+    } else {
+        (exp2(mul_add(-20.0, t, 10.0)) * sin(mul_add(20.0, t, -11.125) * C5)) / 2.0 + 1.0
+    }
+}
+
+pub(crate) fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else {
+        exp2(mul_add(10.0, t, -10.0))
+    }
+}
+
+pub(crate) fn expo_out(t: f32) -> f32 {
+    if 1.0 <= t {
+        1.0
+    } else {
+        1.0 - exp2(-10.0 * t)
+    }
+}
+
+pub(crate) fn expo_in_out(t: f32) -> f32 {
+    if t <= 0.0 {
+        0.0
+    } else if 1.0 <= t {
+        1.0
+    } else if t < 0.5 {
+        exp2(mul_add(20.0, t, -10.0)) / 2.0
+    } else {
+        (2.0 - exp2(mul_add(-20.0, t, 10.0))) / 2.0
+    }
+}
+
+pub(crate) fn quad_in(t: f32) -> f32 {
+    t * t
+}
+
+pub(crate) fn quad_out(t: f32) -> f32 {
+    mul_add(1.0 - t, -(1.0 - t), 1.0)
+}
+
+pub(crate) fn quad_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - powi(mul_add(-2.0, t, 2.0), 2) / 2.0
+    }
+}
+
+pub(crate) fn quart_in(t: f32) -> f32 {
+    t * t * t * t
+}
+
+pub(crate) fn quart_out(t: f32) -> f32 {
+    1.0 - powi(1.0 - t, 4)
+}
+
+pub(crate) fn quart_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        8.0 * t * t * t * t
+    } else {
+        1.0 - powi(mul_add(-2.0, t, 2.0), 4) / 2.0
+    }
+}
+
+pub(crate) fn quint_in(t: f32) -> f32 {
+    t * t * t * t * t
+}
+
+pub(crate) fn quint_out(t: f32) -> f32 {
+    1.0 - powi(1.0 - t, 5)
+}
+
+pub(crate) fn quint_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        16.0 * t * t * t * t * t
+    } else {
+        1.0 - powi(mul_add(-2.0, t, 2.0), 5) / 2.0
+    }
+}
+
+pub(crate) fn reverse(t: f32) -> f32 {
+    1.0 - t
+}
+
+pub(crate) fn sine_in(t: f32) -> f32 {
+    1.0 - cos(t * PI / 2.0)
+}
+
+pub(crate) fn sine_out(t: f32) -> f32 {
+    sin(t * PI / 2.0)
+}
+
+pub(crate) fn sine_in_out(t: f32) -> f32 {
+    -(cos(PI * t) - 1.0) / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints() {
+        let fns: [fn(f32) -> f32; 27] = [
+            back_in, back_out, back_in_out,
+            bounce_in, bounce_out, bounce_in_out,
+            circ_in, circ_out, circ_in_out,
+            cubic_in,
+            elastic_in, elastic_out, elastic_in_out,
+            expo_in, expo_out, expo_in_out,
+            quad_in, quad_out, quad_in_out,
+            quart_in, quart_out, quart_in_out,
+            quint_in, quint_out, quint_in_out,
+            sine_in, sine_out,
+        ];
+
+        for (i, f) in fns.iter().enumerate() {
+            assert!(f(0.0).abs() < 1e-5, "easing {i} at 0.0: {}", f(0.0));
+            assert!((f(1.0) - 1.0).abs() < 1e-5, "easing {i} at 1.0: {}", f(1.0));
+        }
+        assert!((sine_in_out(0.5) - 0.5).abs() < 1e-5);
+    }
+}
@@ -1,9 +1,12 @@
 
-use crate::widget::EffectSpan;
+use crate::widget::{EffectSpan, StableHasher};
+use crate::fx_observer::Observation;
 use crate::shader::Shader;
-use crate::{CellFilter, CellIterator, Duration, EffectTimer};
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, InputState, RefCount, SelectionPolicy};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
+use std::fmt;
+use core::hash::Hasher;
 
 /// Represents an effect that can be applied to terminal cells.
 /// The `Effect` struct wraps a shader, allowing it to be configured
@@ -71,6 +74,45 @@ impl Effect {
         cloned
     }
 
+    /// Creates a new `Effect` with the specified cell selection, combined with the
+    /// existing cell selection according to the policy. For effects hosting other
+    /// effects, such as [fx::parallel](crate::fx::parallel), the policy is applied
+    /// to the cell selection of each child effect.
+    ///
+    /// # Arguments
+    /// * `mode` - The cell selection strategy to apply.
+    /// * `policy` - How `mode` combines with existing cell selections.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::Margin;
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, CellFilter, SelectionPolicy};
+    ///
+    /// let fx = fx::parallel(&[
+    ///     fx::fade_to_fg(Color::Red, 500).with_cell_selection(CellFilter::Text),
+    ///     fx::dissolve(500).with_cell_selection(CellFilter::FgColor(Color::Gray)),
+    /// ]).with_cell_selection_policy(CellFilter::Inner(Margin::new(1, 1)), SelectionPolicy::Intersect);
+    /// ```
+    pub fn with_cell_selection_policy(&self, mode: CellFilter, policy: SelectionPolicy) -> Self {
+        let mut cloned = self.clone();
+        cloned.propagate_cell_selection(mode, policy);
+        cloned
+    }
+
+    /// Creates a new `Effect` reacting to the shared input state, for effects such as
+    /// [fx::spotlight](crate::fx::spotlight) or custom effects reading
+    /// [ShaderFnContext::input](crate::fx::ShaderFnContext::input). For effects hosting
+    /// other effects, the input state is attached to each child effect.
+    ///
+    /// # Arguments
+    /// * `input` - The input state, updated by the application.
+    pub fn with_input(&self, input: RefCount<InputState>) -> Self {
+        let mut cloned = self.clone();
+        cloned.set_input(input);
+        cloned
+    }
+
     /// Creates a new `Effect` with the shader's reverse flag toggled.
     ///
     /// # Returns
@@ -80,9 +122,91 @@ impl Effect {
         cloned.reverse();
         cloned
     }
+
+    /// Describes the effect and its children as an indented tree, listing the name,
+    /// duration, cell filter and area of each effect. Filters are omitted when all
+    /// cells are selected, and areas when none has been set.
+    ///
+    /// A lightweight alternative to [EffectTimeline](crate::widget::EffectTimeline)
+    /// for logs, tests and headless environments.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tachyonfx::{fx, CellFilter, Interpolation};
+    ///
+    /// let effect = fx::sequence(&[
+    ///     fx::sleep(200),
+    ///     fx::dissolve((500, Interpolation::Linear))
+    ///         .with_cell_selection(CellFilter::Text)
+    ///         .with_area(Rect::new(0, 0, 10, 5)),
+    /// ]);
+    ///
+    /// assert_eq!(effect.describe(), "\
+    /// sequential 700ms
+    /// ├ sleep 200ms
+    /// └ dissolve 500ms filter=text area=10x5+0+0
+    /// ");
+    /// ```
+    pub fn describe(&self) -> String {
+        let mut out = String::new();
+        self.as_effect_span(Duration::default()).describe(&mut out, "", "");
+        out
+    }
+
+    /// Returns true if both effects share the same structure: the name, duration,
+    /// interpolation, cell filter and area of each effect in the tree, as listed by
+    /// [Effect::describe].
+    ///
+    /// The progress of the effects is not compared, so a running effect equals a
+    /// freshly built copy of itself. This lets applications rebuilding effects on
+    /// state changes keep the running effect, rather than restarting an identical
+    /// animation. Note that effect-specific parameters, such as colors, and the
+    /// closures of filters like [CellFilter::PositionFn] can't be inspected.
+    ///
+    /// # Example
+    /// ```
+    /// use tachyonfx::{fx, CellFilter, Duration, Interpolation, Shader};
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    ///
+    /// let build = || fx::dissolve((500, Interpolation::Linear))
+    ///     .with_cell_selection(CellFilter::Text);
+    ///
+    /// let mut running = build();
+    /// let area = Rect::new(0, 0, 10, 1);
+    /// running.process(Duration::from_millis(100), &mut Buffer::empty(area), area);
+    ///
+    /// assert!(running.structural_eq(&build()));
+    /// assert!(!running.structural_eq(&fx::dissolve(500)));
+    /// ```
+    pub fn structural_eq(&self, other: &Effect) -> bool {
+        let offset = Duration::default();
+        self.as_effect_span(offset).structural_eq(&other.as_effect_span(offset))
+    }
+
+    /// Returns a hash of the structure of the effect, consistent with
+    /// [Effect::structural_eq]. The hash is stable across runs and platforms, and
+    /// can be stored to detect changes to effects between sessions.
+    pub fn structural_hash(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+        self.as_effect_span(Duration::default()).structural_hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 
+impl fmt::Debug for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Effect")
+            .field("name", &self.name())
+            .field("timer", &self.timer())
+            .field("cell_selection", &self.cell_selection())
+            .field("area", &self.area())
+            .field("done", &self.done())
+            .finish()
+    }
+}
+
 impl Clone for Effect {
     fn clone(&self) -> Self {
         Self { shader: self.shader.clone_box() }
@@ -95,8 +219,26 @@ impl Shader for Effect {
     }
 
     fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
-        let area = self.shader.area().unwrap_or(area);
-        self.shader.process(duration, buf, area)
+        // clipped to the buffer, as the area may no longer fit after a terminal resize;
+        // the timer keeps advancing even if nothing of the area remains
+        let area = self.shader.area().unwrap_or(area).intersection(buf.area);
+
+        #[cfg(all(feature = "alloc-audit", debug_assertions))]
+        let allocations = crate::alloc_audit::allocations();
+
+        let observation = Observation::start();
+        let overflow = self.shader.process(duration, buf, area);
+
+        #[cfg(all(feature = "alloc-audit", debug_assertions))]
+        crate::alloc_audit::check_process(self.shader.name(), allocations);
+
+        if let Some(observation) = observation {
+            let processed = duration.saturating_sub(overflow.unwrap_or_default());
+            let cells_touched = self.shader.dirty_region().unwrap_or(area).area();
+            observation.finish(self.shader.name(), processed, cells_touched);
+        }
+
+        overflow
     }
 
     fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator){
@@ -135,6 +277,14 @@ impl Shader for Effect {
         self.shader.timer_mut()
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.shader.propagate_cell_selection(filter, policy)
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.shader.set_input(input)
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         self.shader.cell_selection()
     }
@@ -143,6 +293,14 @@ impl Shader for Effect {
         self.shader.reset()
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        self.shader.dirty_region()
+    }
+
+    fn panic_message(&self) -> Option<&str> {
+        self.shader.panic_message()
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan
     where
         Self: Sized + Clone,
@@ -163,3 +321,56 @@ impl<S> IntoEffect for S
         Effect::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use crate::fx::Direction;
+    use crate::{fx, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_structural_hash() {
+        let build = |ms: u32, interpolation: Interpolation| fx::sequence(&[
+            fx::sleep(200),
+            fx::fade_to_fg(Color::Red, (ms, interpolation))
+                .with_cell_selection(CellFilter::Text),
+        ]);
+
+        let fx = build(500, Interpolation::QuadOut);
+        assert!(fx.structural_eq(&build(500, Interpolation::QuadOut)));
+        assert!(!fx.structural_eq(&build(500, Interpolation::Linear)));
+
+        assert_eq!(fx.structural_hash(), build(500, Interpolation::QuadOut).structural_hash());
+        assert_ne!(fx.structural_hash(), build(600, Interpolation::QuadOut).structural_hash());
+        assert_ne!(fx.structural_hash(), fx.with_area(Rect::new(0, 0, 5, 5)).structural_hash());
+    }
+
+    #[test]
+    fn test_area_clipped_to_buffer() {
+        let effects = [
+            fx::sweep_in(Direction::UpToDown, 5, 2, Color::Black, 500),
+            fx::slide_in(Direction::LeftToRight, 5, 2, Color::Black, 500),
+            fx::Glitch::builder()
+                .cell_glitch_ratio(1.0)
+                .action_ms(100..200)
+                .action_start_delay_ms(0..50)
+                .build()
+                .into_effect(),
+            fx::dissolve(500),
+        ];
+
+        // the terminal shrank after the effects were set up for a larger area
+        let buf_area = Rect::new(0, 0, 4, 2);
+        for effect in effects {
+            for area in [Rect::new(2, 1, 10, 10), Rect::new(8, 8, 4, 4)] {
+                let mut fx = effect.with_area(area);
+                let mut buf = Buffer::empty(buf_area);
+                (0..5).for_each(|_| { fx.process(Duration::from_millis(150), &mut buf, buf_area); });
+                assert!(fx.done() || fx.name() == "glitch", "{}", fx.name());
+            }
+        }
+    }
+}
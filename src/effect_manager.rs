@@ -0,0 +1,648 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::fx::Crossfade;
+use crate::rect_ext::union_regions;
+use crate::fx_observer::observing;
+use crate::{fx, Duration, Effect, EffectTimer, FxObserver, IntoEffect, Shader};
+
+/// Manages a collection of active effects, processing them each frame and
+/// discarding them once they are done.
+///
+/// Effects are processed layer by layer, from the lowest [EffectLayer] to the highest,
+/// and in insertion order within each layer. Placing e.g. post-processing effects on
+/// the [overlay](EffectLayer::OVERLAY) layer ensures they run after the content
+/// effects, regardless of when they were added. Layers can be
+/// [disabled](EffectManager::set_layer_enabled), pausing their effects.
+///
+/// The manager also aggregates the regions modified by the effects during the
+/// last frame, see [dirty_region](EffectManager::dirty_region). When instrumented,
+/// the time spent processing each effect is measured too, see
+/// [effect_costs](EffectManager::effect_costs). For profiling nested effects, an
+/// [FxObserver] can be [plugged in](EffectManager::set_observer).
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{fx, Duration, EffectManager, Interpolation};
+///
+/// let area = Rect::new(0, 0, 20, 5);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut effects = EffectManager::default();
+/// effects.add_effect(fx::coalesce((500, Interpolation::Linear)));
+///
+/// effects.process_effects(Duration::from_millis(16), &mut buf, area);
+/// assert_eq!(effects.dirty_region(), Some(area));
+/// ```
+#[derive(Default)]
+pub struct EffectManager {
+    /// sorted by layer, in insertion order within each layer
+    effects: Vec<ManagedEffect>,
+    disabled_layers: Vec<EffectLayer>,
+    dirty: Option<Rect>,
+    observer: Option<Box<dyn FxObserver>>,
+    instrumented: bool,
+    costs: Vec<EffectCost>,
+    budget: Option<std::time::Duration>,
+    low_priority_layers: Vec<EffectLayer>,
+    /// the time by which the effects of the last frame exceeded the budget
+    overrun: std::time::Duration,
+    throttled: bool,
+}
+
+/// The layer of an effect in an [EffectManager], determining the order in which
+/// effects are processed: lower layers first. Any `i16` is a valid layer; the
+/// predefined layers leave room for layers in between.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, EffectLayer, EffectManager, Interpolation};
+///
+/// let mut effects = EffectManager::default();
+/// // processed last, despite being added first
+/// effects.add_effect_to_layer(EffectLayer::OVERLAY, fx::term256_colors());
+/// effects.add_effect(fx::fade_from_fg(Color::Black, (500, Interpolation::Linear)));
+/// effects.add_effect_to_layer(EffectLayer(-10), fx::fade_from(Color::Black, Color::Black, 300));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EffectLayer(pub i16);
+
+impl EffectLayer {
+    /// For effects drawing backgrounds, processed before the content effects.
+    pub const BACKGROUND: EffectLayer = EffectLayer(-100);
+    /// The default layer.
+    pub const CONTENT: EffectLayer = EffectLayer(0);
+    /// For post-processing effects, processed after the content effects.
+    pub const OVERLAY: EffectLayer = EffectLayer(100);
+}
+
+impl From<i16> for EffectLayer {
+    fn from(z: i16) -> Self {
+        EffectLayer(z)
+    }
+}
+
+/// How a unique effect takes over from a running effect with the same key, see
+/// [add_unique_effect](EffectManager::add_unique_effect).
+#[derive(Clone, Copy, Debug, Default)]
+pub enum HandoffPolicy {
+    /// Cancels the running effect, starting the new effect immediately.
+    #[default]
+    Replace,
+    /// Starts the new effect once the running effect has completed.
+    Queue,
+    /// Runs both effects side by side, crossfading from the output of the running
+    /// effect to the output of the new effect over the duration of the timer.
+    Crossfade(EffectTimer),
+}
+
+struct ManagedEffect {
+    layer: EffectLayer,
+    key: Option<String>,
+    effect: Effect,
+    /// time withheld from a low-priority effect by the frame budget
+    deferred: Duration,
+    /// skipped during the current frame, due to the frame budget
+    throttled: bool,
+    /// the processing time of the last frame the effect ran, when measured
+    cost: std::time::Duration,
+    /// the cells drawn by a low-priority effect, re-applied while it is throttled
+    last_output: Option<Buffer>,
+}
+
+/// The time spent processing a single effect during a frame, as measured by an
+/// instrumented [EffectManager].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EffectCost {
+    /// The name of the effect.
+    pub name: &'static str,
+    /// The wall-clock time spent in [process](Shader::process).
+    pub process_time: std::time::Duration,
+}
+
+impl EffectManager {
+    /// Adds an effect to be processed by the manager, on the
+    /// [content](EffectLayer::CONTENT) layer.
+    pub fn add_effect(&mut self, effect: Effect) {
+        self.add_effect_to_layer(EffectLayer::CONTENT, effect);
+    }
+
+    /// Adds an effect to be processed by the manager, on the given layer. The effect
+    /// is processed after the effects already added to the same layer.
+    pub fn add_effect_to_layer(&mut self, layer: impl Into<EffectLayer>, effect: Effect) {
+        self.insert(layer.into(), None, effect);
+    }
+
+    /// Adds an effect identified by a key, on the [content](EffectLayer::CONTENT) layer.
+    /// If an effect with the same key is still running, the new effect takes over
+    /// according to the policy - e.g. for restarting the animation of a panel.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tachyonfx::{fx, EffectManager, HandoffPolicy, Interpolation};
+    ///
+    /// let mut effects = EffectManager::default();
+    /// let highlight = || fx::fade_from_fg(Color::Yellow, (400, Interpolation::QuadOut));
+    ///
+    /// effects.add_unique_effect("sidebar", HandoffPolicy::Replace, highlight());
+    /// // replaces the running effect, rather than stacking another highlight on top
+    /// effects.add_unique_effect("sidebar", HandoffPolicy::Crossfade(150.into()), highlight());
+    /// assert_eq!(effects.effect_count(), 1);
+    /// ```
+    pub fn add_unique_effect(&mut self, key: impl Into<String>, policy: HandoffPolicy, effect: Effect) {
+        self.add_unique_effect_to_layer(EffectLayer::CONTENT, key, policy, effect);
+    }
+
+    /// Adds an effect identified by a key, on the given layer. See
+    /// [add_unique_effect](EffectManager::add_unique_effect).
+    pub fn add_unique_effect_to_layer(
+        &mut self,
+        layer: impl Into<EffectLayer>,
+        key: impl Into<String>,
+        policy: HandoffPolicy,
+        effect: Effect,
+    ) {
+        let key = key.into();
+        let running = self.effects.iter()
+            .position(|e| e.key.as_ref() == Some(&key))
+            .map(|idx| self.effects.remove(idx).effect);
+
+        let effect = match (running, policy) {
+            (None, _)                                  => effect,
+            (Some(_), HandoffPolicy::Replace)          => effect,
+            (Some(running), HandoffPolicy::Queue)      => fx::sequence(&[running, effect]),
+            (Some(running), HandoffPolicy::Crossfade(timer)) =>
+                Crossfade::new(running, effect, timer).into_effect(),
+        };
+
+        self.insert(layer.into(), Some(key), effect);
+    }
+
+    /// Cancels the effect with the given key. Returns `true` if the effect was running.
+    pub fn cancel_unique_effect(&mut self, key: &str) -> bool {
+        let len = self.effects.len();
+        self.effects.retain(|e| e.key.as_deref() != Some(key));
+        self.effects.len() != len
+    }
+
+    fn insert(&mut self, layer: EffectLayer, key: Option<String>, effect: Effect) {
+        let idx = self.effects.partition_point(|e| e.layer <= layer);
+        self.effects.insert(idx, ManagedEffect {
+            layer,
+            key,
+            effect,
+            deferred: Duration::ZERO,
+            throttled: false,
+            cost: std::time::Duration::ZERO,
+            last_output: None,
+        });
+    }
+
+    /// Enables or disables processing the effects on a layer. The effects of disabled
+    /// layers are paused, neither advancing nor modifying the buffer, until the layer
+    /// is enabled again. All layers are enabled by default.
+    pub fn set_layer_enabled(&mut self, layer: impl Into<EffectLayer>, enabled: bool) {
+        let layer = layer.into();
+        self.disabled_layers.retain(|l| *l != layer);
+        if !enabled {
+            self.disabled_layers.push(layer);
+        }
+    }
+
+    /// Returns `true` unless the layer has been disabled.
+    pub fn is_layer_enabled(&self, layer: impl Into<EffectLayer>) -> bool {
+        !self.disabled_layers.contains(&layer.into())
+    }
+
+    /// Processes all active effects for the given duration, then removes
+    /// any effects that have completed.
+    ///
+    /// # Arguments
+    /// * `duration` - The time elapsed since the last frame.
+    /// * `buf` - The buffer to apply the effects to.
+    /// * `area` - The area to apply effects to, unless an effect has its own area.
+    pub fn process_effects(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) {
+        let mut observer = self.observer.take();
+        observing(&mut observer, || self.process_active_effects(duration, buf, area));
+        self.observer = observer;
+    }
+
+    fn process_active_effects(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) {
+        let mut dirty = Rect::default();
+        let mut total_cost = std::time::Duration::ZERO;
+        self.costs.clear();
+        self.select_throttled_effects();
+
+        let measured = self.instrumented || self.budget.is_some();
+        let disabled = &self.disabled_layers;
+        let active = self.effects.iter_mut()
+            .filter(|e| !disabled.contains(&e.layer))
+            .filter(|e| e.effect.running());
+
+        for managed in active {
+            if managed.throttled {
+                managed.deferred = managed.deferred.saturating_add(duration);
+                if let Some(output) = managed.last_output.as_ref()
+                    .filter(|output| buf.area.union(output.area) == buf.area)
+                {
+                    buf.merge(output);
+                    dirty = union_regions(dirty, output.area);
+                }
+                continue;
+            }
+
+            let duration = duration.saturating_add(core::mem::take(&mut managed.deferred));
+            let effect = &mut managed.effect;
+            let effect_area = effect.area().unwrap_or(area);
+
+            let started = measured.then(crate::features::Instant::now);
+            effect.process(duration, buf, effect_area);
+
+            let effect_dirty = effect.dirty_region().unwrap_or(effect_area);
+            if let Some(started) = started {
+                managed.cost = started.elapsed();
+                total_cost += managed.cost;
+            }
+            if self.instrumented {
+                self.costs.push(EffectCost { name: effect.name(), process_time: managed.cost });
+            }
+            if self.budget.is_some() && self.low_priority_layers.contains(&managed.layer) {
+                capture_output(buf, effect_dirty, &mut managed.last_output);
+            }
+            dirty = union_regions(dirty, effect_dirty);
+        }
+
+        self.dirty = (!dirty.is_empty()).then_some(dirty);
+        self.effects.retain(|e| e.effect.running());
+        self.overrun = self.budget
+            .and_then(|budget| total_cost.checked_sub(budget))
+            .unwrap_or_default();
+    }
+
+    /// Picks the low-priority effects to skip during this frame, if the last frame
+    /// exceeded the budget: the costliest first, until their combined cost covers the
+    /// overrun. Effects skipped during the last frame are never skipped again.
+    fn select_throttled_effects(&mut self) {
+        let mut overrun = core::mem::take(&mut self.overrun);
+        let mut candidates: Vec<&mut ManagedEffect> = self.effects.iter_mut()
+            .filter_map(|e| {
+                let skipped_last_frame = core::mem::take(&mut e.throttled);
+                let eligible = !skipped_last_frame
+                    && self.low_priority_layers.contains(&e.layer)
+                    && !self.disabled_layers.contains(&e.layer)
+                    && e.effect.running();
+
+                eligible.then_some(e)
+            })
+            .collect();
+
+        candidates.sort_by_key(|e| core::cmp::Reverse(e.cost));
+        self.throttled = false;
+        for managed in candidates {
+            if overrun.is_zero() {
+                break;
+            }
+            overrun = overrun.saturating_sub(managed.cost);
+            managed.throttled = true;
+            self.throttled = true;
+        }
+    }
+
+    /// Returns `true` if any effects are still running.
+    pub fn is_running(&self) -> bool {
+        !self.effects.is_empty()
+    }
+
+    /// Returns the number of active effects.
+    pub fn effect_count(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Sets an observer receiving [ProcessStats](crate::ProcessStats) for every effect
+    /// processed, including nested effects, replacing any previous observer.
+    pub fn set_observer(&mut self, observer: impl FxObserver + 'static) {
+        self.observer = Some(Box::new(observer));
+    }
+
+    /// Removes and returns the observer, if any.
+    pub fn remove_observer(&mut self) -> Option<Box<dyn FxObserver>> {
+        self.observer.take()
+    }
+
+    /// Enables or disables measuring the processing time of each effect. Disabled
+    /// by default, as it adds a clock read per effect and frame.
+    pub fn set_instrumented(&mut self, instrumented: bool) {
+        self.instrumented = instrumented;
+        self.costs.clear();
+    }
+
+    /// Returns `true` if the processing time of effects is being measured.
+    pub fn is_instrumented(&self) -> bool {
+        self.instrumented
+    }
+
+    /// Returns the processing time of each effect during the last call to
+    /// [process_effects](EffectManager::process_effects), in processing order. Empty unless the manager is [instrumented](EffectManager::set_instrumented).
+    pub fn effect_costs(&self) -> &[EffectCost] {
+        &self.costs
+    }
+
+    /// Sets the time budget for processing all effects in a frame, or `None` to
+    /// disable the budget (the default).
+    ///
+    /// The processing time of each effect is measured while a budget is set. When the
+    /// effects of a frame exceed the budget, effects on
+    /// [low-priority](EffectManager::set_layer_low_priority) layers are skipped during
+    /// the next frame - the costliest first, until the overrun is covered - while all
+    /// other effects are processed as usual. Skipped effects re-apply the cells they drew
+    /// during their last frame, so they don't flicker, and are paused rather than dropped,
+    /// catching up on the withheld time once they run again; they are never skipped two
+    /// frames in a row.
+    pub fn set_frame_budget(&mut self, budget: Option<std::time::Duration>) {
+        self.budget = budget;
+        self.overrun = std::time::Duration::ZERO;
+    }
+
+    /// Returns the frame budget, if any.
+    pub fn frame_budget(&self) -> Option<std::time::Duration> {
+        self.budget
+    }
+
+    /// Marks the effects on a layer as low priority, making them the first to be
+    /// skipped when processing exceeds the [frame budget](EffectManager::set_frame_budget).
+    /// Suitable for ambient effects, such as backgrounds. No layer is low priority by
+    /// default.
+    pub fn set_layer_low_priority(&mut self, layer: impl Into<EffectLayer>, low_priority: bool) {
+        let layer = layer.into();
+        self.low_priority_layers.retain(|l| *l != layer);
+        if low_priority {
+            self.low_priority_layers.push(layer);
+        }
+    }
+
+    /// Returns `true` if the layer has been marked as low priority.
+    pub fn is_layer_low_priority(&self, layer: impl Into<EffectLayer>) -> bool {
+        self.low_priority_layers.contains(&layer.into())
+    }
+
+    /// Returns `true` if any low-priority effects were skipped during the last call to
+    /// [process_effects](EffectManager::process_effects), due to the previous frame
+    /// exceeding the [frame budget](EffectManager::set_frame_budget).
+    pub fn is_throttled(&self) -> bool {
+        self.throttled
+    }
+
+    /// Returns the bounding area of all cells modified by the effects during the
+    /// last call to [process_effects](EffectManager::process_effects), or `None`
+    /// if no cells were modified.
+    ///
+    /// Shaders that don't report a [dirty region](Shader::dirty_region) are assumed
+    /// to have modified their entire area.
+    pub fn dirty_region(&self) -> Option<Rect> {
+        self.dirty
+    }
+}
+
+/// Copies the cells of `region` into `output`, reusing its allocation.
+fn capture_output(buf: &Buffer, region: Rect, output: &mut Option<Buffer>) {
+    let region = region.intersection(buf.area);
+    let output = output.get_or_insert_with(|| Buffer::empty(region));
+    output.resize(region);
+    for pos in region.positions() {
+        output[pos] = buf[pos].clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use crate::{fx, Interpolatable, Interpolation, ProcessStats};
+
+    use super::*;
+
+    #[test]
+    fn test_dirty_region_aggregation() {
+        let area = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(area);
+
+        let mut effects = EffectManager::default();
+        effects.add_effect(fx::fade_to_fg(Color::Red, (100, Interpolation::Linear))
+            .with_area(Rect::new(1, 1, 2, 2)));
+        effects.add_effect(fx::sequence(&[
+            fx::sleep(50),
+            fx::fade_to_fg(Color::Red, (100, Interpolation::Linear))
+                .with_area(Rect::new(5, 5, 3, 3)),
+        ]));
+
+        effects.process_effects(Duration::from_millis(20), &mut buf, area);
+        assert_eq!(effects.dirty_region(), Some(Rect::new(1, 1, 2, 2)));
+
+        effects.process_effects(Duration::from_millis(40), &mut buf, area);
+        assert_eq!(effects.dirty_region(), Some(Rect::new(1, 1, 7, 7)));
+
+        // the first fade completes and is removed
+        effects.process_effects(Duration::from_millis(60), &mut buf, area);
+        effects.process_effects(Duration::from_millis(10), &mut buf, area);
+        assert!(effects.is_running());
+        assert_eq!(effects.dirty_region(), Some(Rect::new(5, 5, 3, 3)));
+
+        effects.process_effects(Duration::from_millis(100), &mut buf, area);
+        assert!(!effects.is_running());
+    }
+
+    #[test]
+    fn test_instrumented_effect_costs() {
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+
+        let mut effects = EffectManager::default();
+        effects.add_effect(fx::dissolve((100, Interpolation::Linear)));
+        effects.add_effect(fx::sleep(100));
+
+        effects.process_effects(Duration::from_millis(20), &mut buf, area);
+        assert!(effects.effect_costs().is_empty());
+
+        effects.set_instrumented(true);
+        effects.process_effects(Duration::from_millis(20), &mut buf, area);
+        let names: Vec<_> = effects.effect_costs().iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["dissolve", "sleep"]);
+        assert_eq!(effects.effect_count(), 2);
+    }
+
+    #[test]
+    fn test_sleeping_effects_are_not_dirty() {
+        let area = Rect::new(0, 0, 10, 10);
+        let mut buf = Buffer::empty(area);
+
+        let mut effects = EffectManager::default();
+        effects.add_effect(fx::sleep(100));
+
+        effects.process_effects(Duration::from_millis(20), &mut buf, area);
+        assert_eq!(effects.dirty_region(), None);
+    }
+
+    #[test]
+    fn test_layers_control_processing_order() {
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        let ms = Duration::from_millis;
+
+        // the last effect to process determines the color
+        let mut effects = EffectManager::default();
+        effects.add_effect_to_layer(EffectLayer::OVERLAY, fx::fade_to_fg(Color::Red, 10));
+        effects.add_effect(fx::fade_to_fg(Color::Green, 10));
+        effects.add_effect_to_layer(-1, fx::fade_to_fg(Color::Blue, 10));
+        effects.add_effect(fx::never_complete(fx::fade_to_fg(Color::Yellow, 10)));
+
+        effects.process_effects(ms(10), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Red);
+
+        effects.add_effect_to_layer(EffectLayer::OVERLAY, fx::fade_to_fg(Color::Red, 100));
+        effects.set_layer_enabled(EffectLayer::OVERLAY, false);
+        assert!(!effects.is_layer_enabled(EffectLayer::OVERLAY));
+
+        effects.process_effects(ms(10), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Yellow);
+        assert_eq!(effects.effect_count(), 2);
+
+        // the paused overlay fade continues where it left off
+        effects.set_layer_enabled(EffectLayer::OVERLAY, true);
+        effects.process_effects(ms(100), &mut buf, area);
+        assert_eq!(effects.effect_count(), 1);
+    }
+
+    #[test]
+    fn test_unique_effect_handoff() {
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        let ms = Duration::from_millis;
+        let fade = |color, duration: u32| fx::fade_to_fg(color, (duration, Interpolation::Linear));
+
+        let mut effects = EffectManager::default();
+        effects.add_unique_effect("a", HandoffPolicy::Replace, fade(Color::Red, 100));
+        effects.add_unique_effect("a", HandoffPolicy::Replace, fade(Color::Blue, 100));
+        effects.add_unique_effect("b", HandoffPolicy::Replace, fx::sleep(200));
+        assert_eq!(effects.effect_count(), 2);
+
+        effects.process_effects(ms(100), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Blue);
+
+        // queued effects start once the running effect completes
+        effects.add_unique_effect("b", HandoffPolicy::Queue, fade(Color::Green, 50));
+        effects.process_effects(ms(50), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Blue);
+        effects.process_effects(ms(100), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Green);
+        assert!(!effects.is_running());
+
+        effects.add_unique_effect("c", HandoffPolicy::Replace, fx::never_complete(fade(Color::Red, 10)));
+        assert!(effects.cancel_unique_effect("c"));
+        assert!(!effects.cancel_unique_effect("c"));
+    }
+
+    #[test]
+    fn test_unique_effect_crossfade() {
+        let area = Rect::new(0, 0, 1, 1);
+        let ms = Duration::from_millis;
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+
+        let mut effects = EffectManager::default();
+        effects.add_unique_effect("a", HandoffPolicy::Replace, fx::never_complete(fx::fade_to_fg(black, 10)));
+        effects.add_unique_effect("a", HandoffPolicy::Crossfade((100, Interpolation::Linear).into()),
+            fx::fade_to_fg(white, 10));
+
+        let mut buf = Buffer::empty(area);
+        effects.process_effects(ms(50), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, black.lerp(&white, 0.5));
+
+        let mut buf = Buffer::empty(area);
+        effects.process_effects(ms(50), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, white);
+        assert!(!effects.is_running());
+    }
+
+    #[test]
+    fn test_frame_budget_skips_low_priority_layers() {
+        let area = Rect::new(0, 0, 2, 1);
+        let ms = Duration::from_millis;
+        let fade = |x| fx::fade_to_fg(Color::Red, (100, Interpolation::Linear))
+            .with_area(Rect::new(x, 0, 1, 1));
+
+        let mut effects = EffectManager::default();
+        effects.set_layer_low_priority(EffectLayer::BACKGROUND, true);
+        effects.set_frame_budget(Some(std::time::Duration::ZERO));
+        effects.add_effect_to_layer(EffectLayer::BACKGROUND, fade(0));
+        effects.add_effect(fade(1));
+
+        // the expected output of a fade after the given time
+        let faded = |t| {
+            let mut buf = Buffer::empty(area);
+            fade(0).process(ms(t), &mut buf, area);
+            buf[(0, 0)].fg
+        };
+
+        let mut buf = Buffer::empty(area);
+        effects.process_effects(ms(20), &mut buf, area);
+        assert!(!effects.is_throttled());
+        assert_eq!(buf[(0, 0)].fg, faded(20));
+
+        // every frame exceeds a zero budget; background effects sit out the next one,
+        // re-applying their last output
+        let mut buf = Buffer::empty(area);
+        effects.process_effects(ms(20), &mut buf, area);
+        assert!(effects.is_throttled());
+        assert_eq!(buf[(0, 0)].fg, faded(20));
+        assert_eq!(buf[(1, 0)].fg, faded(40));
+
+        // ...catching up on the skipped time, never skipping two frames in a row
+        let mut buf = Buffer::empty(area);
+        effects.process_effects(ms(20), &mut buf, area);
+        assert!(!effects.is_throttled());
+        assert_eq!(buf[(0, 0)].fg, faded(60));
+        assert_eq!(buf[(1, 0)].fg, faded(60));
+    }
+
+    #[test]
+    fn test_observer_receives_process_stats() {
+        let area = Rect::new(0, 0, 10, 4);
+        let mut buf = Buffer::empty(area);
+
+        let stats = crate::ref_count(Vec::new());
+        let observed = stats.clone();
+
+        let mut effects = EffectManager::default();
+        effects.set_observer(move |s: &ProcessStats| {
+            #[cfg(not(feature = "sendable"))]
+            observed.borrow_mut().push(*s);
+            #[cfg(feature = "sendable")]
+            observed.lock().unwrap().push(*s);
+        });
+        effects.add_effect(fx::dissolve(100).with_area(Rect::new(0, 0, 2, 3)));
+        effects.add_effect(fx::sequence(&[fx::sleep(10), fx::fade_to_fg(Color::Red, 100)]));
+        effects.process_effects(Duration::from_millis(16), &mut buf, area);
+
+        #[cfg(not(feature = "sendable"))]
+        let stats = stats.borrow();
+        #[cfg(feature = "sendable")]
+        let stats = stats.lock().unwrap();
+
+        let summary: Vec<_> = stats.iter()
+            .map(|s| (s.name, s.duration, s.cells_touched, s.depth))
+            .collect();
+        assert_eq!(summary, [
+            ("dissolve", Duration::from_millis(16), 6, 0),
+            ("sleep", Duration::from_millis(10), 0, 1),
+            ("fade_to", Duration::from_millis(6), 40, 1),
+            ("sequential", Duration::from_millis(16), 40, 0),
+        ]);
+
+        drop(stats);
+        assert!(effects.remove_observer().is_some());
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::Mul;
+use core::ops::Mul;
 use crate::Duration;
 use crate::interpolation::Interpolation;
 
@@ -22,7 +22,7 @@ use crate::interpolation::Interpolation;
 /// let c: EffectTimer = 500.into();
 /// let d: EffectTimer = (500, Interpolation::Linear).into();
 /// ```
-#[derive(Clone, Copy, Default)]
+#[derive(Clone, Copy, Debug, Default)]
 pub struct EffectTimer {
     remaining: Duration,
     total: Duration,
@@ -136,12 +136,12 @@ impl EffectTimer {
     /// let alpha = timer.alpha();
     /// ```
     pub fn alpha(&self) -> f32 {
-        let total = self.total.as_millis() as f32;
+        let total = self.total.as_secs_f32();
         if total == 0.0 {
             return if self.reverse { 0.0 } else { 1.0 };
         }
 
-        let remaining = self.remaining.as_millis() as f32;
+        let remaining = self.remaining.as_secs_f32();
         let inv_alpha = remaining / total;
 
         let a = if self.reverse { inv_alpha } else { 1.0 - inv_alpha };
@@ -152,6 +152,11 @@ impl EffectTimer {
         self.total
     }
 
+    /// Returns the interpolation method applied to the alpha value.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
     /// Processes the timer by reducing the remaining duration by the specified amount.
     ///
     /// # Arguments
@@ -222,6 +227,6 @@ impl Mul<u32> for EffectTimer {
     type Output = EffectTimer;
 
     fn mul(self, rhs: u32) -> Self::Output {
-        EffectTimer::new(self.duration() * rhs, self.interpolation)
+        EffectTimer::new(self.duration().saturating_mul(rhs), self.interpolation)
     }
 }
\ No newline at end of file
@@ -1,34 +1,51 @@
 #[cfg(feature = "sendable")]
 mod sendable {
-    use std::sync::{Arc, Mutex};
+    use std::sync::{Arc, Mutex, Weak};
 
     pub trait ThreadSafetyMarker : Send {}
     impl<T: Send> ThreadSafetyMarker for T {}
 
     pub type RefCount<T> = Arc<Mutex<T>>;
+    pub type WeakRefCount<T> = Weak<Mutex<T>>;
 
     pub fn ref_count<T>(value: T) -> RefCount<T> {
         Arc::new(Mutex::new(value))
     }
+
+    pub fn ref_count_weak<T>(value: &RefCount<T>) -> WeakRefCount<T> {
+        Arc::downgrade(value)
+    }
 }
 
 #[cfg(not(feature = "sendable"))]
 mod sendable {
+    use std::rc::{Rc, Weak};
     use std::cell::RefCell;
-    use std::rc::Rc;
 
     pub trait ThreadSafetyMarker {}
     impl<T> ThreadSafetyMarker for T {}
 
     pub type RefCount<T> = Rc<RefCell<T>>;
+    pub type WeakRefCount<T> = Weak<RefCell<T>>;
 
     pub fn ref_count<T>(value: T) -> RefCount<T> {
         Rc::new(RefCell::new(value))
     }
+
+    pub fn ref_count_weak<T>(value: &RefCount<T>) -> WeakRefCount<T> {
+        Rc::downgrade(value)
+    }
 }
 
 pub use sendable::ThreadSafetyMarker;
-pub use sendable::RefCount;
+pub use sendable::{RefCount, WeakRefCount};
+
+/// The wall clock, backed by `web-time` with the "web" feature, as `std::time` panics
+/// on `wasm32-unknown-unknown`. `web-time` re-exports `std::time` on other targets.
+#[cfg(feature = "web")]
+pub(crate) use web_time::{Instant, SystemTime};
+#[cfg(not(feature = "web"))]
+pub(crate) use std::time::{Instant, SystemTime};
 
 /// Wraps a value in a reference-counted smart pointer.
 ///
@@ -63,3 +80,90 @@ pub use sendable::RefCount;
 pub fn ref_count<T>(value: T) -> RefCount<T> {
     sendable::ref_count(value)
 }
+
+/// Creates a weak reference to a reference-counted value, which doesn't keep the
+/// value alive.
+///
+/// The weak reference is either a `Weak<Mutex<T>>` or a `Weak<RefCell<T>>`, depending
+/// on the "sendable" feature flag, and is upgraded back to a [RefCount] with `upgrade()`.
+///
+/// # Examples
+///
+/// ```
+/// use tachyonfx::{ref_count, ref_count_weak};
+///
+/// let wrapped = ref_count(42);
+/// let weak = ref_count_weak(&wrapped);
+/// assert!(weak.upgrade().is_some());
+///
+/// drop(wrapped);
+/// assert!(weak.upgrade().is_none());
+/// ```
+pub fn ref_count_weak<T>(value: &RefCount<T>) -> WeakRefCount<T> {
+    sendable::ref_count_weak(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use static_assertions::assert_impl_all;
+
+    use crate::fx::*;
+    use crate::*;
+
+    // plain values are thread-safe regardless of the "sendable" feature
+    assert_impl_all!(EffectTimer: Send, Sync);
+    assert_impl_all!(Interpolation: Send, Sync);
+    assert_impl_all!(Duration: Send, Sync);
+    assert_impl_all!(SimpleRng: Send, Sync);
+    assert_impl_all!(Direction: Send, Sync);
+    assert_impl_all!(RepeatMode: Send, Sync);
+    assert_impl_all!(GlitchType: Send, Sync);
+    assert_impl_all!(ColorPalette: Send, Sync);
+    assert_impl_all!(GradientSpec: Send, Sync);
+    assert_impl_all!(Sprite: Send, Sync);
+    assert_impl_all!(SpritePath: Send, Sync);
+    assert_impl_all!(ColorMapper: Send, Sync);
+    assert_impl_all!(BoundingBox: Send, Sync);
+    assert_impl_all!(EffectLayer: Send, Sync);
+    assert_impl_all!(HandoffPolicy: Send, Sync);
+    assert_impl_all!(InputState: Send, Sync);
+    assert_impl_all!(crate::pixel_canvas::PixelCanvas: Send, Sync);
+
+    #[cfg(feature = "sendable")]
+    mod sendable {
+        use ratatui::buffer::Buffer;
+        use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+        use crate::fx::*;
+        use crate::widget::{AreaOverlay, CellFilterPreview, EffectSpan, FxLifecycleState, EffectTimeline, EffectTimelineRects, FxStats};
+        use crate::*;
+
+        // effects, and everything owning them, can be built on one thread and moved
+        // to another, but aren't shared: shaders are only required to be Send
+        assert_impl_all!(Effect: Send);
+        assert_impl_all!(EffectManager: Send);
+        assert_impl_all!(Compositor: Send);
+        assert_impl_all!(Layer: Send);
+        assert_impl_all!(FxLifecycleState: Send);
+        assert_impl_all!(Glitch: Send);
+        assert_impl_all!(FadeModifiers: Send);
+        assert_impl_all!(ShaderFn<()>: Send);
+        assert_not_impl_any!(Effect: Sync);
+
+        // effect parameters and shared buffers
+        assert_impl_all!(CellFilter: Send, Sync);
+        assert_impl_all!(CellFilterRegistry: Send, Sync);
+        assert_impl_all!(ShaderFnSignature<()>: Send, Sync);
+        assert_impl_all!(RefCount<Buffer>: Send, Sync);
+        assert_impl_all!(BufferRef: Send, Sync);
+        assert_impl_all!(BufferPool: Send, Sync);
+
+        // widgets describing effects
+        assert_impl_all!(EffectTimeline: Send, Sync);
+        assert_impl_all!(EffectTimelineRects: Send, Sync);
+        assert_impl_all!(EffectSpan: Send, Sync);
+        assert_impl_all!(AreaOverlay: Send, Sync);
+        assert_impl_all!(CellFilterPreview: Send, Sync);
+        assert_impl_all!(FxStats: Send, Sync);
+    }
+}
@@ -0,0 +1,104 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Shader};
+
+/// Hides the selected cells every other interval, with the `HIDDEN` modifier.
+#[derive(Clone, Debug)]
+pub struct Blink {
+    interval: Duration,
+    /// time into the current on/off cycle, in seconds
+    elapsed: f32,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Blink {
+    pub fn new(interval: Duration, cell_filter: CellFilter) -> Self {
+        Self { interval, elapsed: 0.0, area: None, cell_filter }
+    }
+
+    fn is_hidden(&self) -> bool {
+        self.elapsed >= self.interval.as_secs_f32()
+    }
+}
+
+impl Shader for Blink {
+    fn name(&self) -> &'static str {
+        "blink"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let cycle = 2.0 * self.interval.as_secs_f32();
+        if cycle > 0.0 {
+            self.elapsed = (self.elapsed + duration.as_secs_f32()).rem_euclid(cycle);
+        }
+
+        if self.is_hidden() {
+            let cells = self.cell_iter(buf, area);
+            self.execute(1.0, area, cells);
+        }
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        cell_iter.for_each(|(_, cell)| {
+            cell.modifier.insert(Modifier::HIDDEN);
+        });
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+    use crate::testing::effect_frames;
+
+    use super::*;
+
+    #[test]
+    fn test_blink() {
+        let mut fx = fx::blink(Duration::from_millis(500), CellFilter::Text);
+        let tick = Duration::from_millis(250);
+        let frames = effect_frames(&mut fx, Buffer::with_lines(["a─"]), [tick; 5]);
+
+        let hidden: Vec<bool> = frames.iter()
+            .map(|buf| buf[(0, 0)].modifier.contains(Modifier::HIDDEN))
+            .collect();
+        assert_eq!(hidden, [false, true, true, false, false]);
+        assert!(frames.iter().all(|buf| !buf[(1, 0)].modifier.contains(Modifier::HIDDEN)));
+    }
+}
@@ -0,0 +1,120 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{CellFilter, CellIterator, Duration, RefCount, Shader};
+
+/// Copies the area of the main buffer into an auxiliary buffer, then completes.
+#[derive(Clone, Debug)]
+pub struct CaptureInto {
+    target: RefCount<Buffer>,
+    area: Option<Rect>,
+    captured: bool,
+}
+
+impl CaptureInto {
+    pub fn new(target: RefCount<Buffer>) -> Self {
+        Self { target, area: None, captured: false }
+    }
+}
+
+impl Shader for CaptureInto {
+    fn name(&self) -> &'static str {
+        "capture_into"
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if !self.captured {
+            #[cfg(not(feature = "sendable"))]
+            capture(buf, area, &mut self.target.borrow_mut());
+            #[cfg(feature = "sendable")]
+            capture(buf, area, &mut self.target.lock().unwrap());
+
+            self.captured = true;
+        }
+
+        // capturing takes no time; the full duration is passed on
+        Some(duration)
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.captured
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn dirty_region(&self) -> Option<Rect> {
+        Some(Rect::default())
+    }
+
+    fn reset(&mut self) {
+        self.captured = false;
+    }
+}
+
+/// Resizes `target` to match `src`, and copies the cells within `area`. Cells outside
+/// of the area are marked as skipped, so that they are omitted when the captured
+/// buffer is rendered with [blit_buffer](crate::blit_buffer).
+fn capture(src: &Buffer, area: Rect, target: &mut Buffer) {
+    target.resize(src.area);
+    target.reset();
+
+    let area = area.intersection(src.area);
+    for pos in src.area.positions() {
+        if area.contains(pos) {
+            target[pos] = src[pos].clone();
+        } else {
+            target[pos].skip = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ref_count;
+
+    use super::*;
+
+    #[test]
+    fn test_capture_area() {
+        let target = ref_count(Buffer::empty(Rect::new(0, 0, 1, 1)));
+        let mut fx = CaptureInto::new(target.clone());
+
+        let mut buf = Buffer::with_lines(["abc", "def"]);
+        let overflow = fx.process(Duration::from_millis(16), &mut buf, Rect::new(1, 0, 2, 2));
+        assert_eq!(overflow, Some(Duration::from_millis(16)));
+        assert!(fx.done());
+
+        // later changes to the main buffer aren't captured
+        buf[(1, 0)].set_char('x');
+        fx.process(Duration::from_millis(16), &mut buf, Rect::new(1, 0, 2, 2));
+
+        #[cfg(not(feature = "sendable"))]
+        let captured = target.borrow().clone();
+        #[cfg(feature = "sendable")]
+        let captured = target.lock().unwrap().clone();
+
+        let mut expected = Buffer::with_lines([" bc", " ef"]);
+        expected[(0, 0)].skip = true;
+        expected[(0, 1)].skip = true;
+        assert_eq!(captured, expected);
+    }
+}
@@ -0,0 +1,241 @@
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::color_ext::ToRgbComponents;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator, ColorMapper};
+
+/// A color vision deficiency, simulated by
+/// [simulate_color_vision](crate::fx::simulate_color_vision).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// Absence of green-sensitive cones; the most common form of color blindness.
+    Deuteranopia,
+    /// Absence of red-sensitive cones.
+    Protanopia,
+    /// Absence of blue-sensitive cones.
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// Simulation matrices from Machado, Oliveira and Fernandes (2009), at full severity.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Deficiency::Deuteranopia => [
+                [ 0.367322, 0.860646, -0.227968],
+                [ 0.280085, 0.672501,  0.047413],
+                [-0.011820, 0.042940,  0.968881],
+            ],
+            Deficiency::Protanopia => [
+                [ 0.152286,  1.052583, -0.204868],
+                [ 0.114503,  0.786281,  0.099216],
+                [-0.003882, -0.048116,  1.051998],
+            ],
+            Deficiency::Tritanopia => [
+                [ 1.255528, -0.076749, -0.178779],
+                [-0.078411,  0.930809,  0.147602],
+                [ 0.004733,  0.691367,  0.303900],
+            ],
+        }
+    }
+}
+
+/// A per-color transformation applied by [ColorFilter].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum ColorOp {
+    #[default]
+    Grayscale,
+    Sepia,
+    Invert,
+    ColorVision(Deficiency),
+}
+
+impl ColorOp {
+    fn apply(self, color: Color) -> (u8, u8, u8) {
+        let (r, g, b) = color.to_rgb();
+
+        match self {
+            ColorOp::Grayscale => transform((r, g, b), [
+                [0.299, 0.587, 0.114],
+                [0.299, 0.587, 0.114],
+                [0.299, 0.587, 0.114],
+            ]),
+            ColorOp::Sepia => transform((r, g, b), [
+                [0.393, 0.769, 0.189],
+                [0.349, 0.686, 0.168],
+                [0.272, 0.534, 0.131],
+            ]),
+            ColorOp::Invert => (255 - r, 255 - g, 255 - b),
+            ColorOp::ColorVision(deficiency) => transform((r, g, b), deficiency.matrix()),
+        }
+    }
+}
+
+fn transform((r, g, b): (u8, u8, u8), m: [[f32; 3]; 3]) -> (u8, u8, u8) {
+    let (r, g, b) = (r as f32, g as f32, b as f32);
+    let row = |i: usize| (m[i][0] * r + m[i][1] * g + m[i][2] * b)
+        .round()
+        .clamp(0.0, 255.0) as u8;
+
+    (row(0), row(1), row(2))
+}
+
+/// Interpolates the foreground and background colors toward the result of
+/// a [ColorOp], in RGB space.
+#[derive(Clone, Default, Debug)]
+pub struct ColorFilter {
+    timer: EffectTimer,
+    op: ColorOp,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl ColorFilter {
+    pub(crate) fn new(op: ColorOp, timer: EffectTimer) -> Self {
+        Self { op, timer, ..Self::default() }
+    }
+}
+
+fn lerp_rgb(from: Color, to: (u8, u8, u8), alpha: f32) -> Color {
+    let (r, g, b) = from.to_rgb();
+    let lerp = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * alpha).round() as u8;
+
+    Color::Rgb(lerp(r, to.0), lerp(g, to.1), lerp(b, to.2))
+}
+
+impl Shader for ColorFilter {
+    fn name(&self) -> &'static str {
+        match self.op {
+            ColorOp::Grayscale => "grayscale",
+            ColorOp::Sepia     => "sepia",
+            ColorOp::Invert    => "invert",
+            ColorOp::ColorVision(_) => "simulate_color_vision",
+        }
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+        #[cfg(feature = "underline-color")]
+        let mut underline_mapper = ColorMapper::default();
+
+        let op = self.op;
+        let transform = |c: Color| lerp_rgb(c, op.apply(c), alpha);
+
+        for (_, cell) in cell_iter {
+            let fg = fg_mapper.map(cell.fg, alpha, transform);
+            let bg = bg_mapper.map(cell.bg, alpha, transform);
+
+            cell.set_fg(fg);
+            cell.set_bg(bg);
+
+            #[cfg(feature = "underline-color")]
+            if cell.underline_color != Color::Reset {
+                cell.underline_color = underline_mapper.map(cell.underline_color, alpha, transform);
+            }
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> { self.area }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+
+    use crate::{Duration, Interpolation};
+
+    use super::*;
+
+    fn apply(op: ColorOp, elapsed: Duration, fg: Color) -> Color {
+        let mut buf = Buffer::with_lines(["x"]);
+        buf.set_style(buf.area, fg);
+
+        let area = buf.area;
+        let mut fx = ColorFilter::new(op, EffectTimer::from_ms(100, Interpolation::Linear));
+        fx.process(elapsed, &mut buf, area);
+        buf.content[0].fg
+    }
+
+    #[test]
+    fn test_grayscale() {
+        assert_eq!(apply(ColorOp::Grayscale, Duration::from_millis(100), Color::Rgb(255, 0, 0)), Color::Rgb(76, 76, 76));
+        assert_eq!(apply(ColorOp::Grayscale, Duration::from_millis(50), Color::Rgb(255, 0, 0)), Color::Rgb(166, 38, 38));
+        assert_eq!(apply(ColorOp::Grayscale, Duration::from_millis(0), Color::Rgb(255, 0, 0)), Color::Rgb(255, 0, 0));
+    }
+
+    #[test]
+    fn test_sepia() {
+        assert_eq!(apply(ColorOp::Sepia, Duration::from_millis(100), Color::Rgb(100, 100, 100)), Color::Rgb(135, 120, 94));
+        assert_eq!(apply(ColorOp::Sepia, Duration::from_millis(100), Color::Rgb(255, 255, 255)), Color::Rgb(255, 255, 239));
+    }
+
+    #[test]
+    fn test_invert() {
+        assert_eq!(apply(ColorOp::Invert, Duration::from_millis(100), Color::Rgb(10, 20, 30)), Color::Rgb(245, 235, 225));
+        assert_eq!(apply(ColorOp::Invert, Duration::from_millis(100), Color::White), Color::Rgb(63, 63, 63));
+    }
+
+    #[cfg(feature = "underline-color")]
+    #[test]
+    fn test_underline_color() {
+        let mut buf = Buffer::with_lines(["xy"]);
+        buf[(0, 0)].underline_color = Color::Rgb(255, 0, 0);
+
+        let area = buf.area;
+        let mut fx = ColorFilter::new(ColorOp::Grayscale, EffectTimer::from_ms(100, Interpolation::Linear));
+        fx.process(Duration::from_millis(100), &mut buf, area);
+
+        assert_eq!(buf[(0, 0)].underline_color, Color::Rgb(76, 76, 76));
+        // unset underline colors follow the foreground color and are left untouched
+        assert_eq!(buf[(1, 0)].underline_color, Color::Reset);
+    }
+
+    #[test]
+    fn test_color_vision() {
+        let red = Color::Rgb(255, 0, 0);
+        let green = Color::Rgb(0, 255, 0);
+        let deuteranopia = ColorOp::ColorVision(Deficiency::Deuteranopia);
+
+        // red and green become hard to tell apart
+        let (r1, g1, _) = apply(deuteranopia, Duration::from_millis(100), red).to_rgb();
+        let (r2, g2, _) = apply(deuteranopia, Duration::from_millis(100), green).to_rgb();
+        assert!(r1 < 255 && g2 < 255);
+        assert!(g1 > 0 && r2 > 0);
+
+        // grays are preserved
+        let gray = Color::Rgb(128, 128, 128);
+        for d in [Deficiency::Deuteranopia, Deficiency::Protanopia, Deficiency::Tritanopia] {
+            assert_eq!(apply(ColorOp::ColorVision(d), Duration::from_millis(100), gray), gray);
+        }
+    }
+}
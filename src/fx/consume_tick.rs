@@ -6,7 +6,7 @@ use crate::CellFilter;
 use crate::shader::Shader;
 
 /// consumes any remaining duration for a single tick.
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 pub struct ConsumeTick {
     has_consumed_tick: bool,
 }
@@ -37,6 +37,7 @@ impl Shader for ConsumeTick {
     fn area(&self) -> Option<Rect> { None }
     fn set_area(&mut self, _area: Rect) {}
     fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+    fn dirty_region(&self) -> Option<Rect> { Some(Rect::default()) }
 
     fn reset(&mut self) {
         self.has_consumed_tick = false;
@@ -1,45 +1,80 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Rect};
-use crate::{CellFilter, CellIterator, Duration, EffectTimer};
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, InputState, RefCount, SelectionPolicy};
+use crate::rect_ext::union_regions;
 use crate::effect::Effect;
 use crate::widget::EffectSpan;
 use crate::Interpolation::Linear;
 use crate::shader::Shader;
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 pub struct SequentialEffect {
     effects: Vec<Effect>,
     current: usize,
+    dirty: Rect,
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 pub struct ParallelEffect {
     effects: Vec<Effect>,
+    dirty: Rect,
+    /// completes with the first child to finish, cancelling the others
+    first_wins: bool,
 }
 
 impl SequentialEffect {
     pub fn new(effects: Vec<Effect>) -> Self {
-        Self { effects, current: 0 }
+        Self { effects, current: 0, dirty: Rect::default() }
     }
 }
 
 impl ParallelEffect {
     pub fn new(effects: Vec<Effect>) -> Self {
-        Self { effects }
+        Self { effects, dirty: Rect::default(), first_wins: false }
+    }
+
+    /// Creates a parallel effect which completes as soon as any of its effects
+    /// completes, cancelling the remaining effects.
+    pub fn first_wins(effects: Vec<Effect>) -> Self {
+        Self { effects, dirty: Rect::default(), first_wins: true }
+    }
+
+    fn process_first_wins(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let mut overflow: Option<Duration> = None;
+        self.dirty = Rect::default();
+
+        for effect in self.effects.iter_mut() {
+            let effect_area = effect.area().unwrap_or(area);
+            // the largest overflow belongs to the effect finishing first
+            if let Some(d) = effect.process(duration, buf, effect_area) {
+                overflow = Some(overflow.map_or(d, |o| o.max(d)));
+            }
+            self.dirty = union_regions(self.dirty, effect.dirty_region().unwrap_or(effect_area));
+        }
+
+        overflow
     }
 }
 
 impl Shader for ParallelEffect {
     fn name(&self) -> &'static str {
-        "parallel"
+        if self.first_wins { "parallel_any" } else { "parallel" }
     }
 
     fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        if self.first_wins {
+            return self.process_first_wins(duration, buf, area);
+        }
+
         let mut remaining = Some(duration);
+        self.dirty = Rect::default();
 
         for effect in self.effects.iter_mut().filter(|e| e.running()) {
             let effect_area = effect.area().unwrap_or(area);
-            match effect.process(duration, buf, effect_area) {
+            let overflow = effect.process(duration, buf, effect_area);
+            self.dirty = union_regions(self.dirty, effect.dirty_region().unwrap_or(effect_area));
+
+            match overflow {
                 None => remaining = None,
                 Some(d) if remaining.is_some() => {
                     remaining = Some(d.min(remaining.unwrap()));
@@ -54,7 +89,10 @@ impl Shader for ParallelEffect {
     fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
 
     fn done(&self) -> bool {
-        self.effects.iter().all(Effect::done)
+        match self.first_wins {
+            true  => self.effects.is_empty() || self.effects.iter().any(Effect::done),
+            false => self.effects.iter().all(Effect::done),
+        }
     }
 
     fn clone_box(&self) -> Box<dyn Shader> {
@@ -73,6 +111,14 @@ impl Shader for ParallelEffect {
         self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.effects.iter_mut().for_each(|e| e.propagate_cell_selection(filter.clone(), policy));
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.effects.iter_mut().for_each(|e| e.set_input(input.clone()));
+    }
+
     fn reverse(&mut self) {
         self.effects.iter_mut().for_each(Effect::reverse)
     }
@@ -82,11 +128,12 @@ impl Shader for ParallelEffect {
     }
 
     fn timer(&self) -> Option<EffectTimer> {
-        self.effects.iter()
+        let durations = self.effects.iter()
             .filter_map(|fx| fx.timer())
-            .map(|t| t.duration())
-            .max()
-            .map(|d| EffectTimer::new(d, Linear))
+            .map(|t| t.duration());
+
+        let duration = if self.first_wins { durations.min() } else { durations.max() };
+        duration.map(|d| EffectTimer::new(d, Linear))
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
@@ -97,6 +144,10 @@ impl Shader for ParallelEffect {
         self.effects.iter_mut().for_each(Effect::reset)
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        Some(self.dirty)
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         let children = self.effects.iter()
             .map(|e| e.as_effect_span(offset))
@@ -119,10 +170,13 @@ impl Shader for SequentialEffect {
     ) -> Option<Duration> {
 
         let mut remaining = Some(duration);
+        self.dirty = Rect::default();
+
         while remaining.is_some() && !self.done() {
             let effect = &mut self.effects[self.current];
             let effect_area = effect.area().unwrap_or(area);
             remaining = effect.process(remaining.unwrap(), buf, effect_area);
+            self.dirty = union_regions(self.dirty, effect.dirty_region().unwrap_or(effect_area));
 
             if effect.done() {
                 self.current += 1;
@@ -154,6 +208,14 @@ impl Shader for SequentialEffect {
         self.effects.iter_mut().for_each(|e| e.set_cell_selection(strategy.clone()));
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.effects.iter_mut().for_each(|e| e.propagate_cell_selection(filter.clone(), policy));
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.effects.iter_mut().for_each(|e| e.set_input(input.clone()));
+    }
+
     fn reverse(&mut self) {
         self.effects.iter_mut().for_each(Effect::reverse)
     }
@@ -165,7 +227,7 @@ impl Shader for SequentialEffect {
             .map(|fx| fx.timer())
             .filter(|t| t.is_some())
             .map(|t| t.unwrap().duration())
-            .sum();
+            .fold(Duration::ZERO, Duration::saturating_add);
 
         if duration.is_zero() {
             None
@@ -181,12 +243,16 @@ impl Shader for SequentialEffect {
         self.effects.iter_mut().for_each(Effect::reset)
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        Some(self.dirty)
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         let mut acc = Duration::ZERO;
         let children = self.effects.iter()
             .map(|e| {
-                let span = e.as_effect_span(offset + acc);
-                acc += e.timer().map(|t| t.duration()).unwrap_or_default();
+                let span = e.as_effect_span(offset.saturating_add(acc));
+                acc = acc.saturating_add(e.timer().map(|t| t.duration()).unwrap_or_default());
                 span
             })
             .collect();
@@ -195,3 +261,33 @@ impl Shader for SequentialEffect {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use crate::{fx, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_parallel_any() {
+        let mut fx = fx::parallel_any(&[
+            fx::fade_to_fg(Color::Red, (100, Interpolation::Linear)),
+            fx::fade_to_fg(Color::Blue, (300, Interpolation::Linear)),
+            fx::never_complete(fx::consume_tick()),
+        ]);
+        assert_eq!(fx.name(), "parallel_any");
+        assert_eq!(fx.timer().unwrap().duration(), Duration::from_millis(100));
+
+        let mut buf = Buffer::with_lines(["x"]);
+        let area = buf.area;
+        assert_eq!(fx.process(Duration::from_millis(60), &mut buf, area), None);
+        assert!(fx.running());
+
+        assert_eq!(fx.process(Duration::from_millis(60), &mut buf, area), Some(Duration::from_millis(20)));
+        assert!(fx.done());
+
+        fx.reset();
+        assert!(fx.running());
+    }
+}
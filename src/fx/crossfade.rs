@@ -0,0 +1,122 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::fx::masked::blend_cell;
+use crate::rect_ext::union_regions;
+use crate::widget::EffectSpan;
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, RefCount, SelectionPolicy, Shader};
+
+/// Crossfades from the output of one effect to the output of another. Both effects
+/// run side by side until the crossfade completes, after which only the second
+/// effect remains.
+#[derive(Clone, Debug)]
+pub struct Crossfade {
+    from: Effect,
+    to: Effect,
+    timer: EffectTimer,
+    /// the output of the `from` effect
+    scratch: Buffer,
+}
+
+impl Crossfade {
+    pub fn new(from: Effect, to: Effect, timer: EffectTimer) -> Self {
+        Self { from, to, timer, scratch: Buffer::empty(Rect::default()) }
+    }
+}
+
+impl Shader for Crossfade {
+    fn name(&self) -> &'static str {
+        "crossfade"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        if self.timer.done() {
+            return self.to.process(duration, buf, self.to.area().unwrap_or(area));
+        }
+
+        self.timer.process(duration);
+        let alpha = self.timer.alpha();
+
+        // the from-effect renders onto a copy of the content
+        if self.scratch.area == buf.area {
+            self.scratch.content.clone_from(&buf.content);
+        } else {
+            self.scratch = buf.clone();
+        }
+
+        let from_area = self.from.area().unwrap_or(area);
+        if self.from.running() {
+            self.from.process(duration, &mut self.scratch, from_area);
+        }
+
+        let to_area = self.to.area().unwrap_or(area);
+        let overflow = self.to.process(duration, buf, to_area);
+
+        let blended = union_regions(from_area, to_area).intersection(buf.area);
+        for y in blended.top()..blended.bottom() {
+            for x in blended.left()..blended.right() {
+                blend_cell(&self.scratch[(x, y)], &mut buf[(x, y)], alpha);
+            }
+        }
+
+        if self.timer.done() { overflow } else { None }
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.timer.done() && self.to.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.to.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.from.set_area(area);
+        self.to.set_area(area);
+    }
+
+    fn set_cell_selection(&mut self, filter: CellFilter) {
+        self.from.set_cell_selection(filter.clone());
+        self.to.set_cell_selection(filter);
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        let duration = self.to.timer().unwrap_or_default().duration();
+        Some(EffectTimer::new(duration.max(self.timer.duration()), crate::Interpolation::Linear))
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.from.propagate_cell_selection(filter.clone(), policy);
+        self.to.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.from.set_input(input.clone());
+        self.to.set_input(input);
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.to.cell_selection()
+    }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        self.from.reset();
+        self.to.reset();
+    }
+
+    fn as_effect_span(&self, offset: Duration) -> EffectSpan {
+        let children = vec![self.from.as_effect_span(offset), self.to.as_effect_span(offset)];
+        EffectSpan::new(self, offset, children)
+    }
+}
@@ -0,0 +1,203 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::cell_filter::CellSelector;
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Interpolatable, RefCount, Shader};
+
+#[cfg(not(feature = "sendable"))]
+type CursorPositionFn = RefCount<dyn Fn() -> Option<Position>>;
+#[cfg(feature = "sendable")]
+type CursorPositionFn = RefCount<dyn Fn() -> Option<Position> + Send>;
+
+/// Tints the background of the cells recently passed by the cursor, as reported by a
+/// closure each frame, fading each cell over the decay time.
+#[derive(Clone)]
+pub struct CursorTrail {
+    cursor_position: CursorPositionFn,
+    color: Color,
+    decay: Duration,
+    cursor: Option<Position>,
+    /// cells left behind by the cursor, each with the time since the cursor last left it
+    trail: Vec<(Position, Duration)>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl CursorTrail {
+    pub fn new(cursor_position: CursorPositionFn, color: Color, decay: Duration) -> Self {
+        Self {
+            cursor_position,
+            color,
+            decay,
+            cursor: None,
+            trail: Vec::new(),
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    fn cursor_position(&self) -> Option<Position> {
+        #[cfg(not(feature = "sendable"))]
+        let f = self.cursor_position.borrow();
+        #[cfg(feature = "sendable")]
+        let f = self.cursor_position.lock().unwrap();
+        f()
+    }
+
+    /// Ages the trail, then extends it with the cells between the previous and the
+    /// current cursor position.
+    fn update(&mut self, duration: Duration) {
+        let decay = self.decay;
+        self.trail.iter_mut().for_each(|(_, age)| *age = age.saturating_add(duration));
+        self.trail.retain(|(_, age)| *age < decay);
+
+        let cursor = self.cursor_position();
+        if let (Some(from), Some(to)) = (self.cursor, cursor) {
+            if from != to {
+                let steps = from.x.abs_diff(to.x).max(from.y.abs_diff(to.y));
+                let trail: Vec<Position> = (0..steps).map(|i| {
+                    let a = i as f32 / steps as f32;
+                    Position::new(from.x.lerp(&to.x, a), from.y.lerp(&to.y, a))
+                }).collect();
+
+                // cells passed again restart their decay
+                self.trail.retain(|(pos, _)| *pos != to && !trail.contains(pos));
+                self.trail.extend(trail.into_iter().map(|pos| (pos, Duration::ZERO)));
+            }
+        }
+
+        self.cursor = cursor;
+    }
+}
+
+impl Shader for CursorTrail {
+    fn name(&self) -> &'static str {
+        "cursor_trail"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        self.update(duration);
+        if self.trail.is_empty() {
+            return None;
+        }
+
+        let area = area.intersection(buf.area);
+        let selector = CellSelector::with_buffer(area, self.cell_filter.clone(), Some(buf));
+
+        let decay = self.decay.as_secs_f32();
+        for &(pos, age) in &self.trail {
+            if !area.contains(pos) {
+                continue;
+            }
+
+            let cell = &mut buf[pos];
+            if selector.is_valid(pos, cell) {
+                let strength = 1.0 - age.as_secs_f32() / decay;
+                cell.set_bg(cell.bg.lerp(&self.color, strength));
+            }
+        }
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.cursor = None;
+        self.trail.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::fx;
+
+    use super::*;
+
+    #[test]
+    fn test_trail_fades_behind_cursor() {
+        let area = Rect::new(0, 0, 5, 1);
+        let white = Color::Rgb(255, 255, 255);
+        let black = Color::Rgb(0, 0, 0);
+
+        let cursor = Arc::new(Mutex::new(Position::new(0, 0)));
+        let position = cursor.clone();
+        let mut trail = fx::cursor_trail(
+            move || Some(*position.lock().unwrap()),
+            white,
+            Duration::from_millis(100),
+        );
+
+        let mut process = |ms| {
+            let mut buf = Buffer::empty(area);
+            buf.set_style(area, ratatui::style::Style::new().bg(black));
+            trail.process(Duration::from_millis(ms), &mut buf, area);
+            (0..5).map(|x| buf[(x, 0)].bg).collect::<Vec<_>>()
+        };
+
+        assert_eq!(process(16), [black; 5]);
+
+        // jumping ahead leaves a trail through the skipped cells
+        *cursor.lock().unwrap() = Position::new(3, 0);
+        assert_eq!(process(16), [white, white, white, black, black]);
+
+        let half = black.lerp(&white, 0.5);
+        assert_eq!(process(50), [half, half, half, black, black]);
+        assert_eq!(process(50), [black; 5]);
+    }
+
+    #[test]
+    fn test_revisited_cells_restart_decay() {
+        let cursor = Arc::new(Mutex::new(Position::new(0, 0)));
+        let position = cursor.clone();
+        let cursor_position = crate::ref_count(move || Some(*position.lock().unwrap()));
+        let mut trail = CursorTrail::new(cursor_position, Color::White, Duration::from_millis(100));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let area = buf.area;
+        let mut move_to = |x: u16| {
+            *cursor.lock().unwrap() = Position::new(x, 0);
+            trail.process(Duration::from_millis(10), &mut buf, area);
+        };
+
+        move_to(0);
+        move_to(3);
+        move_to(0);
+
+        let mut positions: Vec<u16> = trail.trail.iter().map(|(pos, _)| pos.x).collect();
+        positions.sort_unstable();
+        assert_eq!(positions, [1, 2, 3]);
+        assert!(trail.trail.iter().all(|(_, age)| *age == Duration::ZERO));
+    }
+}
@@ -0,0 +1,288 @@
+use std::sync::Arc;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+
+use crate::widget::EffectSpan;
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, RefCount, SelectionPolicy, Shader};
+
+#[cfg(not(feature = "sendable"))]
+type DelayFn = RefCount<dyn Fn(Position) -> Duration>;
+#[cfg(feature = "sendable")]
+type DelayFn = RefCount<dyn Fn(Position) -> Duration + Send>;
+
+/// The maximum number of distinct delays, and thereby instances of the wrapped effect.
+const MAX_DELAY_STEPS: usize = 32;
+
+/// Offsets the timeline of the wrapped effect per cell, by the delay returned for
+/// each cell position.
+///
+/// Cells sharing the same delay are processed by the same instance of the wrapped
+/// effect, restricted to those cells. The delays are evaluated once for the area,
+/// and quantized to at most [MAX_DELAY_STEPS] distinct delays.
+#[derive(Clone)]
+pub struct DelayMap {
+    fx: Effect,
+    delay_fn: DelayFn,
+    /// an instance of the wrapped effect for each distinct delay, in ascending order
+    delayed: Vec<(Duration, Effect)>,
+    /// the area the delays were evaluated for
+    delay_area: Rect,
+    /// set when the instances were created, so that they catch up with the elapsed time
+    distributed: bool,
+    elapsed: Duration,
+}
+
+impl DelayMap {
+    pub fn new(fx: Effect, delay_fn: DelayFn) -> Self {
+        Self {
+            fx,
+            delay_fn,
+            delayed: Vec::new(),
+            delay_area: Rect::default(),
+            distributed: false,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    fn delay(&self, pos: Position) -> Duration {
+        #[cfg(not(feature = "sendable"))]
+        let f = self.delay_fn.borrow();
+        #[cfg(feature = "sendable")]
+        let f = self.delay_fn.lock().unwrap();
+        f(pos)
+    }
+
+    /// Evaluates the delay of each cell in the area, creating an instance of the
+    /// wrapped effect for each distinct delay.
+    fn distribute(&mut self, area: Rect) {
+        let mut delays: Vec<Duration> = area.positions().map(|pos| self.delay(pos)).collect();
+
+        let mut distinct = delays.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        if distinct.len() > MAX_DELAY_STEPS {
+            let (min, max) = (distinct[0], distinct[distinct.len() - 1]);
+            let quantize = quantizer(min, max);
+            delays.iter_mut().for_each(|delay| *delay = quantize(*delay));
+
+            distinct.iter_mut().for_each(|delay| *delay = quantize(*delay));
+            distinct.dedup();
+        }
+
+        let delays: Arc<[Duration]> = delays.into();
+
+        self.delayed = distinct.into_iter()
+            .map(|delay| {
+                let delays = delays.clone();
+                let selection = CellFilter::apply_position_fn(move |pos| {
+                    if !area.contains(pos) {
+                        return false;
+                    }
+
+                    let idx = (pos.y - area.y) as usize * area.width as usize + (pos.x - area.x) as usize;
+                    delays[idx] == delay
+                });
+
+                let fx = self.fx.with_cell_selection_policy(selection, SelectionPolicy::Intersect);
+                (delay, fx)
+            })
+            .collect();
+        self.delay_area = area;
+        self.distributed = true;
+    }
+}
+
+/// Rounds delays between `min` and `max` to the nearest of [MAX_DELAY_STEPS] evenly
+/// spaced steps.
+fn quantizer(min: Duration, max: Duration) -> impl Fn(Duration) -> Duration {
+    let steps = (MAX_DELAY_STEPS - 1) as f32;
+    let span = max.saturating_sub(min).as_secs_f32();
+
+    move |delay| {
+        let step = (delay.saturating_sub(min).as_secs_f32() / span * steps).round();
+        min.saturating_add(Duration::from_secs_f32(span * step / steps))
+    }
+}
+
+impl Shader for DelayMap {
+    fn name(&self) -> &'static str {
+        "delay_map"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        if self.delayed.is_empty() || self.delay_area != area {
+            self.distribute(area);
+        }
+
+        self.elapsed = self.elapsed.saturating_add(duration);
+        // instances created mid-effect, e.g. after a resize, catch up with the
+        // elapsed time rather than starting over
+        let catch_up = core::mem::take(&mut self.distributed);
+
+        // the overflow of the instance completing last
+        let mut overflow: Option<Duration> = None;
+        for (delay, fx) in self.delayed.iter_mut() {
+            // cells wait at the start of the effect until their delay has passed, and
+            // hold the end state once completed
+            let running = fx.running();
+            let local = match (running, catch_up) {
+                (false, _)    => Duration::ZERO,
+                (true, true)  => self.elapsed.saturating_sub(*delay),
+                (true, false) => self.elapsed.saturating_sub(*delay).min(duration),
+            };
+
+            match fx.process(local, buf, area) {
+                Some(o) if running => overflow = Some(overflow.map_or(o, |min| min.min(o))),
+                _ => {},
+            }
+        }
+
+        overflow.filter(|_| self.done())
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        !self.delayed.is_empty() && self.delayed.iter().all(|(_, fx)| fx.done())
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area);
+        self.delayed.clear();
+    }
+
+    fn set_cell_selection(&mut self, filter: CellFilter) {
+        self.fx.set_cell_selection(filter);
+        self.delayed.clear();
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse();
+        self.delayed.iter_mut().for_each(|(_, fx)| fx.reverse());
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        let max_delay = self.delayed.last().map_or(Duration::ZERO, |(delay, _)| *delay);
+        self.fx.timer().map(|t| EffectTimer::new(t.duration().saturating_add(max_delay), t.interpolation()))
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.fx.propagate_cell_selection(filter, policy);
+        self.delayed.clear();
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.fx.set_input(input.clone());
+        self.delayed.iter_mut().for_each(|(_, fx)| fx.set_input(input.clone()));
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.delayed.iter_mut().for_each(|(_, fx)| fx.reset());
+    }
+
+    fn as_effect_span(&self, offset: Duration) -> EffectSpan {
+        EffectSpan::new(self, offset, vec![self.fx.as_effect_span(offset)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Style};
+
+    use crate::{fx, Interpolatable, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_delays_are_quantized() {
+        let area = Rect::new(0, 0, 100, 10);
+        let mut fx = DelayMap::new(
+            fx::fade_to_fg(Color::Red, 100),
+            crate::ref_count(|pos: Position| Duration::from_millis(1) * (pos.x as u32 * 7 + pos.y as u32)),
+        );
+
+        let mut buf = Buffer::empty(area);
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        assert_eq!(fx.delayed.len(), MAX_DELAY_STEPS);
+        assert_eq!(fx.delayed.first().map(|(d, _)| *d), Some(Duration::ZERO));
+        // quantized in f32; std-duration keeps the sub-millisecond rounding error
+        assert_eq!(fx.delayed.last().map(|(d, _)| d.as_millis()), Some(702));
+    }
+
+    #[test]
+    fn test_resize_keeps_progress() {
+        let area = Rect::new(0, 0, 2, 1);
+        let white = Color::Rgb(255, 255, 255);
+
+        let fade = fx::fade_to_fg(white, (100, Interpolation::Linear));
+        let mut fx = fx::delay_map(fade, |pos| Duration::from_millis(50) * pos.x as u32);
+
+        let mut buf = Buffer::empty(area);
+        fx.process(Duration::from_millis(100), &mut buf, area);
+
+        let resized = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(resized);
+        fx.process(Duration::from_millis(50), &mut buf, resized);
+        assert_eq!(buf[(0, 0)].fg, white);
+        assert_eq!(buf[(1, 0)].fg, white);
+        assert!(!fx.done());
+    }
+
+    #[test]
+    fn test_positions_outside_delay_area() {
+        let white = Color::Rgb(255, 255, 255);
+        let area = Rect::new(1, 1, 2, 1);
+
+        // the child effect covers more than the area the delays are distributed over
+        let fade = fx::fade_to_fg(white, 100).with_area(Rect::new(0, 0, 4, 2));
+        let mut fx = fx::delay_map(fx::parallel(&[fade]), |_| Duration::ZERO);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        fx.process(Duration::from_millis(100), &mut buf, area);
+        assert_eq!(buf[(1, 1)].fg, white);
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn test_delay_per_column() {
+        let area = Rect::new(0, 0, 3, 1);
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+
+        let fade = fx::fade_to_fg(white, (100, Interpolation::Linear));
+        let mut fx = fx::delay_map(fade, |pos| Duration::from_millis(50) * pos.x as u32);
+
+        let fg = |fx: &mut Effect| {
+            let mut buf = Buffer::empty(area);
+            buf.set_style(area, Style::new().fg(black));
+            fx.process(Duration::from_millis(50), &mut buf, area);
+            (0..3).map(|x| buf[(x, 0)].fg).collect::<Vec<_>>()
+        };
+
+        let half = black.lerp(&white, 0.5);
+        assert_eq!(fg(&mut fx), [half, black, black]);
+        assert_eq!(fg(&mut fx), [white, half, black]);
+        assert_eq!(fg(&mut fx), [white, white, half]);
+        assert!(!fx.done());
+        assert_eq!(fg(&mut fx), [white, white, white]);
+        assert!(fx.done());
+    }
+}
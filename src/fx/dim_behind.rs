@@ -0,0 +1,143 @@
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator, ColorMapper, HslConvertable, Interpolatable};
+
+const DIMMED: Color = Color::Rgb(0, 0, 0);
+
+/// Darkens, and optionally desaturates, the cells outside of a modal area.
+///
+/// Once the timer has run its course, the cells remain dimmed until the effect is
+/// discarded; when reversed, the dimming fades out and the effect completes.
+#[derive(Clone, Debug)]
+pub struct DimBehind {
+    modal_area: Rect,
+    amount: f32,
+    desaturation: f32,
+    timer: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl DimBehind {
+    pub fn new(modal_area: Rect, amount: f32, desaturation: f32, timer: EffectTimer) -> Self {
+        Self {
+            modal_area,
+            amount: amount.clamp(0.0, 1.0),
+            desaturation: desaturation.clamp(0.0, 1.0),
+            timer,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    fn dim(&self, color: Color, alpha: f32) -> Color {
+        let color = if self.desaturation > 0.0 {
+            let (h, s, l) = color.to_hsl();
+            Color::from_hsl(h, s * (1.0 - self.desaturation * alpha), l)
+        } else {
+            color
+        };
+
+        color.lerp(&DIMMED, self.amount * alpha)
+    }
+}
+
+impl Shader for DimBehind {
+    fn name(&self) -> &'static str {
+        "dim_behind"
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        if alpha == 0.0 {
+            return;
+        }
+
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+
+        cell_iter
+            .filter(|(pos, _)| !self.modal_area.contains(*pos))
+            .for_each(|(_, cell)| {
+                let fg = fg_mapper.map(cell.fg, alpha, |c| self.dim(c, alpha));
+                let bg = bg_mapper.map(cell.bg, alpha, |c| self.dim(c, alpha));
+                cell.set_fg(fg);
+                cell.set_bg(bg);
+            });
+    }
+
+    fn done(&self) -> bool {
+        self.timer.is_reversed() && self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::style::Style;
+
+    use crate::{fx, Duration, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_dims_outside_modal_until_reversed() {
+        let area = Rect::new(0, 0, 3, 1);
+        let gray = Color::Rgb(200, 200, 200);
+        let modal = Rect::new(1, 0, 1, 1);
+
+        let buf = || {
+            let mut buf = Buffer::empty(area);
+            buf.set_style(area, Style::new().fg(gray).bg(gray));
+            buf
+        };
+        let dimmed = gray.lerp(&DIMMED, 0.5);
+
+        let mut dim = fx::dim_behind(modal, 0.5, (100, Interpolation::Linear));
+        let mut frame = buf();
+        dim.process(Duration::from_millis(200), &mut frame, area);
+        assert_eq!(frame[(0, 0)].bg, dimmed);
+        assert_eq!(frame[(1, 0)].bg, gray);
+        assert_eq!(frame[(2, 0)].fg, dimmed);
+
+        // stays dimmed while the modal is open
+        assert!(dim.running());
+
+        let mut undim = dim.reversed();
+        undim.reset();
+        let mut frame = buf();
+        undim.process(Duration::from_millis(100), &mut frame, area);
+        assert_eq!(frame[(0, 0)].bg, gray);
+        assert!(undim.done());
+    }
+}
@@ -1,3 +1,5 @@
+use core::f32::consts::TAU;
+
 use ratatui::layout::Rect;
 use crate::{RangeSampler, SimpleRng};
 
@@ -24,24 +26,187 @@ impl Direction {
     }
 }
 
+/// The length of the gradient at the leading edge of
+/// [sweep](crate::fx::sweep_in) and [slide](crate::fx::slide_in) effects.
+///
+/// A `u16` converts into a length in cells, while an `f32` converts into a fraction
+/// of the area, letting the same effect scale across both small and large areas.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GradientLength {
+    /// A fixed number of cells.
+    Cells(u16),
+    /// A fraction of the area's width or height, along the direction of the effect.
+    Fraction(f32),
+}
+
+impl GradientLength {
+    /// Returns the length in cells, possibly fractional, for the given area.
+    pub(crate) fn resolve(&self, area: Rect, direction: Direction) -> f32 {
+        match *self {
+            GradientLength::Cells(cells) => cells as f32,
+            GradientLength::Fraction(fraction) => {
+                let extent = match direction {
+                    Direction::LeftToRight | Direction::RightToLeft => area.width,
+                    Direction::UpToDown    | Direction::DownToUp    => area.height,
+                };
+                fraction.max(0.0) * extent as f32
+            },
+        }
+    }
+}
+
+impl From<u16> for GradientLength {
+    fn from(cells: u16) -> Self {
+        GradientLength::Cells(cells)
+    }
+}
+
+impl From<f32> for GradientLength {
+    fn from(fraction: f32) -> Self {
+        GradientLength::Fraction(fraction)
+    }
+}
+
+/// The distribution of the random offsets applied per row or column by
+/// [sweep](crate::fx::sweep_in) and [slide](crate::fx::slide_in) effects.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+pub enum SweepJitterShape {
+    /// Offsets are evenly distributed across the extent.
+    #[default]
+    Uniform,
+    /// Offsets cluster around the middle of the extent, approximating a bell curve.
+    Gaussian,
+    /// Offsets follow a sine wave with a random phase, repeating every `wavelength`
+    /// rows or columns.
+    SineRipple { wavelength: u16 },
+}
+
+/// Configures the random offsets applied per row or column by sweeping and
+/// sliding effects.
+///
+/// A plain `u16` converts into a uniform jitter of that extent, seeded by the
+/// dimensions of the effect area.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx::{self, Direction, SweepJitter, SweepJitterShape};
+///
+/// let jitter = SweepJitter::new(10)
+///     .with_shape(SweepJitterShape::SineRipple { wavelength: 12 })
+///     .with_seed(42)
+///     .with_reroll_on_reset(true);
+///
+/// let sweep = fx::repeating(fx::sweep_in(Direction::UpToDown, 5, jitter, Color::Black, 1000));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct SweepJitter {
+    extent: u16,
+    shape: SweepJitterShape,
+    seed: Option<u32>,
+    reroll_on_reset: bool,
+    /// the fraction of the effect's duration by which rows or columns may finish early
+    duration_spread: f32,
+}
+
+impl SweepJitter {
+    /// Creates a uniform jitter with the maximum offset of `extent` cells.
+    pub fn new(extent: u16) -> Self {
+        Self { extent, ..Self::default() }
+    }
+
+    /// Sets the distribution of the offsets.
+    pub fn with_shape(mut self, shape: SweepJitterShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Seeds the offsets, instead of deriving the seed from the effect area.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// When enabled, each reset of the effect, e.g. under [fx::repeat](crate::fx::repeat),
+    /// rolls new offsets. By default, every run reuses the same offsets.
+    pub fn with_reroll_on_reset(mut self, reroll: bool) -> Self {
+        self.reroll_on_reset = reroll;
+        self
+    }
+
+    /// Gives each row or column a slightly different duration, for an organic "venetian
+    /// blind" feel instead of a rigid front. Each row or column finishes up to `spread`
+    /// (0.0 to 1.0) of the effect's duration early.
+    pub fn with_duration_spread(mut self, spread: f32) -> Self {
+        self.duration_spread = spread.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Returns the maximum offset, in cells.
+    pub fn extent(&self) -> u16 {
+        self.extent
+    }
+
+    /// Returns the distribution of the offsets.
+    pub fn shape(&self) -> SweepJitterShape {
+        self.shape
+    }
+
+    /// Returns the configured seed, if any.
+    pub fn seed(&self) -> Option<u32> {
+        self.seed
+    }
+
+    /// Returns whether each reset rolls new offsets.
+    pub fn rerolls_on_reset(&self) -> bool {
+        self.reroll_on_reset
+    }
+
+    /// Returns the fraction of the duration by which rows or columns may finish early.
+    pub fn duration_spread(&self) -> f32 {
+        self.duration_spread
+    }
+
+    /// Returns true if all rows or columns progress in lockstep.
+    pub(crate) fn is_rigid(&self) -> bool {
+        self.extent == 0 && self.duration_spread == 0.0
+    }
+}
+
+impl From<u16> for SweepJitter {
+    fn from(extent: u16) -> Self {
+        Self::new(extent)
+    }
+}
+
 /// Generates random variances for directional effects.
 pub(crate) struct DirectionalVariance {
     rng: SimpleRng,
     direction: Direction,
     max: i16,
+    shape: SweepJitterShape,
+    /// the phase of [SweepJitterShape::SineRipple], in radians
+    phase: f32,
+    /// the number of variances generated so far
+    index: u16,
+    duration_spread: f32,
+    /// separate from `rng`, so that the spread doesn't affect the offsets
+    spread_rng: SimpleRng,
 }
 
 impl DirectionalVariance {
     /// Creates a new `DirectionalVariance` instance.
     ///
-    /// This method initializes a `DirectionalVariance` with a seed based on the given area's dimensions,
-    /// the specified direction for the sliding effect, and the maximum variance allowed.
+    /// Unless the jitter has a seed, the RNG is seeded with the given area's dimensions.
+    /// The seed is further mixed with `run`, allowing for different variances on each
+    /// run of an effect.
     ///
     /// # Arguments
     ///
-    /// * `area` - The `Rect` representing the area of the effect. Used to seed the RNG.
+    /// * `area` - The `Rect` representing the area of the effect.
     /// * `direction` - The `Direction` of the sliding effect.
-    /// * `max` - The maximum variance that can be generated.
+    /// * `jitter` - The extent, distribution and seed of the variances.
+    /// * `run` - The number of times the effect has been rerolled.
     ///
     /// # Returns
     ///
@@ -49,13 +214,40 @@ impl DirectionalVariance {
     pub(super) fn from(
         area: Rect,
         direction: Direction,
-        max: u16
+        jitter: SweepJitter,
+        run: u32,
     ) -> Self {
+        let seed = jitter.seed.unwrap_or((area.width as u32) << 16 | area.height as u32);
+        let seed = seed ^ run.wrapping_mul(0x9e37_79b9);
+        let mut rng = SimpleRng::new(seed);
+
+        let phase = match jitter.shape {
+            SweepJitterShape::SineRipple { .. } => rng.gen_f32() * TAU,
+            _ => 0.0,
+        };
+
         Self {
-            rng: SimpleRng::new((area.width as u32) << 16 | area.height as u32),
+            rng,
             direction,
-            max: max as i16,
+            max: jitter.extent as i16,
+            shape: jitter.shape,
+            phase,
+            index: 0,
+            duration_spread: jitter.duration_spread,
+            spread_rng: SimpleRng::new(seed.rotate_left(16)),
+        }
+    }
+
+    /// Returns the progress of the next row or column, given the progress of the
+    /// effect. With a duration spread, each row or column finishes early by a random
+    /// fraction of the duration, up to the spread.
+    pub(crate) fn line_progress(&mut self, progress: f32) -> f32 {
+        if self.duration_spread == 0.0 {
+            return progress;
         }
+
+        let duration = 1.0 - self.duration_spread * self.spread_rng.gen_f32();
+        (progress / duration).min(1.0)
     }
 
     /// Generates the next variance value.
@@ -73,7 +265,19 @@ impl DirectionalVariance {
             return (0, 0);
         }
 
-        let variance = self.rng.gen_range(0..self.max);
+        let variance = match self.shape {
+            SweepJitterShape::Uniform => self.rng.gen_range(0..self.max),
+            SweepJitterShape::Gaussian => {
+                let sum: f32 = (0..3).map(|_| self.rng.gen_f32()).sum();
+                ((sum / 3.0) * self.max as f32) as i16
+            },
+            SweepJitterShape::SineRipple { wavelength } => {
+                let angle = self.index as f32 * TAU / wavelength.max(1) as f32 + self.phase;
+                ((0.5 + 0.5 * angle.sin()) * (self.max - 1) as f32).round() as i16
+            },
+        };
+        self.index = self.index.wrapping_add(1);
+
         match self.direction {
             Direction::LeftToRight => (variance, 0),
             Direction::RightToLeft => (-variance, 0),
@@ -81,4 +285,60 @@ impl DirectionalVariance {
             Direction::DownToUp    => (0, -variance),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variances(jitter: SweepJitter, run: u32) -> Vec<i16> {
+        let area = Rect::new(0, 0, 40, 20);
+        let mut variance = DirectionalVariance::from(area, Direction::LeftToRight, jitter, run);
+        (0..40).map(|_| variance.next().0).collect()
+    }
+
+    #[test]
+    fn test_variances_within_extent() {
+        let shapes = [SweepJitterShape::Uniform, SweepJitterShape::Gaussian, SweepJitterShape::SineRipple { wavelength: 8 }];
+        for shape in shapes {
+            let v = variances(SweepJitter::new(6).with_shape(shape), 0);
+            assert!(v.iter().all(|v| (0..6).contains(v)), "{shape:?}: {v:?}");
+        }
+    }
+
+    #[test]
+    fn test_seed_and_run() {
+        let jitter = SweepJitter::new(10);
+        assert_eq!(variances(jitter, 0), variances(jitter, 0));
+        assert_ne!(variances(jitter, 0), variances(jitter, 1));
+        assert_ne!(variances(jitter, 0), variances(jitter.with_seed(7), 0));
+    }
+
+    #[test]
+    fn test_gradient_length() {
+        let area = Rect::new(5, 5, 40, 10);
+        assert_eq!(GradientLength::from(8).resolve(area, Direction::UpToDown), 8.0);
+        assert_eq!(GradientLength::from(0.25).resolve(area, Direction::LeftToRight), 10.0);
+        assert_eq!(GradientLength::from(0.25).resolve(area, Direction::DownToUp), 2.5);
+    }
+
+    #[test]
+    fn test_duration_spread() {
+        let area = Rect::new(0, 0, 40, 20);
+        let jitter = SweepJitter::new(0).with_duration_spread(0.5);
+        let mut variance = DirectionalVariance::from(area, Direction::UpToDown, jitter, 0);
+
+        let progress: Vec<f32> = (0..40).map(|_| variance.line_progress(0.5)).collect();
+        assert!(progress.iter().all(|p| (0.5..=1.0).contains(p)), "{progress:?}");
+        assert!(progress.iter().any(|p| *p != progress[0]));
+
+        let mut variance = DirectionalVariance::from(area, Direction::UpToDown, jitter, 0);
+        assert!((0..40).all(|_| variance.line_progress(1.0) == 1.0));
+    }
+
+    #[test]
+    fn test_sine_ripple_repeats() {
+        let v = variances(SweepJitter::new(10).with_shape(SweepJitterShape::SineRipple { wavelength: 8 }), 0);
+        assert_eq!(v[..8], v[8..16]);
+    }
+}
@@ -1,17 +1,54 @@
-use ratatui::layout::Rect;
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
 
+use crate::color_batch::for_each_row;
+use crate::color_ext::ToRgbComponents;
 use crate::effect_timer::EffectTimer;
 use crate::shader::Shader;
 use crate::simple_rng::SimpleRng;
+use crate::wide_cells::WideCells;
 use crate::CellFilter;
 use crate::CellIterator;
+use crate::Duration;
 
-#[derive(Clone)]
+/// Keeps the thresholds of ordered cells below 1.0, so that every cell is dissolved
+/// by the end of the effect.
+const MAX_THRESHOLD: f32 = 1.0 - f32::EPSILON;
+
+/// The share of the timer each cell spends as a partial glyph, when building up.
+const BUILD_UP_SHARE: f32 = 0.2;
+
+/// The partial glyphs shown while building up, from nearly resolved to nearly gone.
+const BUILD_UP_GLYPHS: [char; 4] = ['█', '▓', '▒', '░'];
+
+/// The order in which cells are dissolved. When coalescing, cells are restored in
+/// the reverse order.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DissolveOrder {
+    /// Cells are dissolved in random order.
+    #[default]
+    Random,
+    /// Cells are dissolved in a random order determined by the seed; the same seed
+    /// always produces the same order.
+    Seeded(u32),
+    /// Cells are dissolved from the center of the area outwards.
+    CenterOut,
+    /// Cells are dissolved from the edges of the area inwards.
+    EdgesIn,
+    /// Cells with the brightest foreground colors are dissolved first.
+    Brightness,
+    /// Cells are dissolved row by row, from left to right.
+    ReadingOrder,
+}
+
+#[derive(Clone, Debug)]
 pub struct Dissolve {
     timer: EffectTimer,
     area: Option<Rect>,
     cell_filter: CellFilter,
     lcg: SimpleRng,
+    order: DissolveOrder,
+    build_up: bool,
 }
 
 impl Dissolve {
@@ -23,6 +60,120 @@ impl Dissolve {
             area: None,
             cell_filter: CellFilter::All,
             lcg: SimpleRng::default(),
+            order: DissolveOrder::Random,
+            build_up: false,
+        }
+    }
+
+    /// Passes the cells through partial block glyphs on their way between the real
+    /// symbol and a blank cell.
+    pub fn with_build_up(mut self) -> Self {
+        self.build_up = true;
+        self
+    }
+
+    pub fn with_order(mut self, order: DissolveOrder) -> Self {
+        if let DissolveOrder::Seeded(seed) = order {
+            self.lcg = SimpleRng::new(seed);
+        }
+        self.order = order;
+        self
+    }
+}
+
+/// Returns the threshold of an ordered cell, in the range 0.0 to 1.0; the cell is
+/// dissolved once alpha exceeds it.
+fn ordered_threshold(order: DissolveOrder, area: Rect, pos: Position, cell: &Cell) -> f32 {
+    // distance from the center, with cells being roughly twice as tall as they are wide
+    let center_distance = || {
+        let cx = (area.width.saturating_sub(1)) as f32 / 2.0;
+        let cy = (area.height.saturating_sub(1)) as f32 / 2.0;
+        let dx = (pos.x - area.x) as f32 - cx;
+        let dy = ((pos.y - area.y) as f32 - cy) * 2.0;
+
+        let max = cx.hypot(cy * 2.0);
+        if max == 0.0 { 0.0 } else { dx.hypot(dy) / max }
+    };
+
+    let t = match order {
+        DissolveOrder::CenterOut    => center_distance(),
+        DissolveOrder::EdgesIn      => 1.0 - center_distance(),
+        DissolveOrder::Brightness   => {
+            let (r, g, b) = cell.fg.to_rgb();
+            1.0 - (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) / 255.0
+        },
+        DissolveOrder::ReadingOrder => {
+            let idx = (pos.y - area.y) as usize * area.width as usize + (pos.x - area.x) as usize;
+            (idx + 1) as f32 / area.area() as f32
+        },
+        DissolveOrder::Random | DissolveOrder::Seeded(_) => 0.0,
+    };
+
+    t.clamp(0.0, 1.0) * MAX_THRESHOLD
+}
+
+/// Dissolves cells at a given alpha. Random thresholds are derived from the position
+/// of each cell, making them independent of the order in which cells are visited.
+#[derive(Clone, Copy)]
+struct DissolvedCells {
+    alpha: f32,
+    area: Rect,
+    order: DissolveOrder,
+    seed: u32,
+    share: f32,
+}
+
+impl DissolvedCells {
+    fn threshold(&self, pos: Position, cell: &Cell) -> f32 {
+        match self.order {
+            DissolveOrder::Random | DissolveOrder::Seeded(_) => {
+                let hash = (pos.y as u32) << 16 | pos.x as u32;
+                SimpleRng::new(mix(self.seed ^ mix(hash))).gen_f32()
+            },
+            order => ordered_threshold(order, self.area, pos, cell),
+        }
+    }
+
+    /// Dissolves the cells, which are expected in reading order.
+    fn dissolve<'a>(&self, cells: impl Iterator<Item = (Position, &'a mut Cell)>) {
+        let (alpha, share) = (self.alpha, self.share);
+
+        // wide characters dissolve as a whole
+        let mut units = WideCells::default();
+        cells.for_each(|(pos, cell)| {
+            let (threshold, visible) = units.resolve(pos, cell, || {
+                (self.threshold(pos, cell) * (1.0 - share), !cell.symbol().trim().is_empty())
+            });
+
+            if alpha > threshold + share {
+                cell.set_char(' ');
+            } else if alpha > threshold && visible {
+                let progress = (alpha - threshold) / share;
+                let idx = (progress * BUILD_UP_GLYPHS.len() as f32) as usize;
+                cell.set_char(BUILD_UP_GLYPHS[idx.min(BUILD_UP_GLYPHS.len() - 1)]);
+            }
+        });
+    }
+}
+
+/// Scrambles the bits of `x`, so that neighboring cells get unrelated thresholds
+/// (the finalizer of MurmurHash3).
+fn mix(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x85eb_ca6b);
+    x ^= x >> 13;
+    x = x.wrapping_mul(0xc2b2_ae35);
+    x ^ (x >> 16)
+}
+
+impl Dissolve {
+    fn dissolved_cells(&self, alpha: f32, area: Rect) -> DissolvedCells {
+        DissolvedCells {
+            alpha,
+            area,
+            order: self.order,
+            seed: self.lcg.clone().gen(),
+            share: if self.build_up { BUILD_UP_SHARE } else { 0.0 },
         }
     }
 }
@@ -32,11 +183,26 @@ impl Shader for Dissolve {
         if self.timer.is_reversed() { "coalesce" } else { "dissolve" }
     }
 
-    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
-        let mut lcg = self.lcg;
-        cell_iter
-            .filter(|_| alpha > lcg.gen_f32())
-            .for_each(|(_, c)| { c.set_char(' '); });
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let alpha = self.timer.alpha();
+
+        if let CellFilter::All = self.cell_filter {
+            let cells = self.dissolved_cells(alpha, area);
+            for_each_row(buf, area, |row, cells_in_row| {
+                let positions = (row.x..).map(|x| Position::new(x, row.y));
+                cells.dissolve(positions.zip(cells_in_row));
+            });
+        } else {
+            let cells = self.cell_iter(buf, area);
+            self.execute(alpha, area, cells);
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator) {
+        self.dissolved_cells(alpha, area).dissolve(cell_iter);
     }
 
     fn done(&self) -> bool {
@@ -70,4 +236,78 @@ impl Shader for Dissolve {
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+    use crate::testing::effect_frames;
+    use crate::{Duration, Interpolation};
+
+    use super::*;
+
+    fn frames(order: DissolveOrder, content: &Buffer) -> Vec<Buffer> {
+        let mut fx = fx::dissolve_ordered(order, (100, Interpolation::Linear));
+        effect_frames(&mut fx, content, [Duration::from_millis(50), Duration::from_millis(50)])
+    }
+
+    #[test]
+    fn test_reading_order() {
+        let content = Buffer::with_lines(["abcd", "efgh"]);
+        let frames = frames(DissolveOrder::ReadingOrder, &content);
+
+        assert_eq!(frames[0], Buffer::with_lines(["    ", "efgh"]));
+        assert_eq!(frames[1], Buffer::with_lines(["    ", "    "]));
+    }
+
+    #[test]
+    fn test_center_out_and_edges_in() {
+        let content = Buffer::with_lines(["abcdefg"]);
+
+        assert_eq!(frames(DissolveOrder::CenterOut, &content)[0], Buffer::with_lines(["ab   fg"]));
+        assert_eq!(frames(DissolveOrder::EdgesIn, &content)[0], Buffer::with_lines(["  cde  "]));
+    }
+
+    #[test]
+    fn test_seeded_order_is_reproducible() {
+        let content = Buffer::with_lines(["abcdefghijklmnop"; 4]);
+
+        let seeded = frames(DissolveOrder::Seeded(7), &content);
+        assert_eq!(seeded, frames(DissolveOrder::Seeded(7), &content));
+        assert_ne!(seeded[0], frames(DissolveOrder::Seeded(8), &content)[0]);
+        assert_eq!(seeded[1], Buffer::with_lines(["                "; 4]));
+    }
+
+    #[test]
+    fn test_thresholds_independent_of_cell_filter() {
+        let content = Buffer::with_lines(["abcdefghijklmnop"; 4]);
+        let filtered = CellFilter::Inner(ratatui::layout::Margin::new(0, 0));
+
+        let mut fx = fx::dissolve_ordered(DissolveOrder::Seeded(7), (100, Interpolation::Linear))
+            .with_cell_selection(filtered);
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(50)]);
+
+        assert_eq!(frames, self::frames(DissolveOrder::Seeded(7), &content)[..1]);
+    }
+
+    #[test]
+    fn test_build_up() {
+        let content = Buffer::with_lines(["a b"]);
+        let mut fx = fx::coalesce_with_build_up((100, Interpolation::Linear));
+
+        let ticks = [Duration::ZERO, Duration::from_millis(70), Duration::from_millis(30)];
+        let frames = effect_frames(&mut fx, &content, ticks);
+
+        assert_eq!(frames[0], Buffer::with_lines(["   "]));
+        assert!(frames.iter().all(|f| f[(1, 0)].symbol() == " "));
+        assert_eq!(frames[2], content);
+
+        // each cell passes through at least one partial glyph
+        let mut fx = fx::coalesce_with_build_up((100, Interpolation::Linear));
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(5); 20]);
+        let partial = |x| frames.iter().any(|f| {
+            BUILD_UP_GLYPHS.iter().any(|g| f[(x, 0)].symbol() == g.to_string())
+        });
+        assert!(partial(0) && partial(2));
+    }
+}
@@ -1,13 +1,19 @@
-use bon::{builder, Builder};
+use bon::Builder;
 use ratatui::layout::Rect;
 use ratatui::prelude::Color;
 
+use ratatui::buffer::Buffer;
+
+use crate::color_batch::{for_each_row, map_cells_bg, map_cells_fg};
 use crate::color_mapper::ColorMapper;
 use crate::effect_timer::EffectTimer;
 use crate::shader::Shader;
-use crate::{CellFilter, CellIterator, Interpolatable};
+use crate::{CellFilter, CellIterator, Duration, Interpolatable};
+
+/// The number of colors in the precomputed gradients.
+const GRADIENT_STEPS: usize = 256;
 
-#[derive(Builder, Clone)]
+#[derive(Builder, Clone, Debug)]
 pub struct FadeColors {
     fg: Option<Color>,
     bg: Option<Color>,
@@ -16,6 +22,25 @@ pub struct FadeColors {
     area: Option<Rect>,
     #[builder(default)]
     cell_filter: CellFilter,
+    #[builder(skip)]
+    fg_mapper: ColorMapper,
+    #[builder(skip)]
+    bg_mapper: ColorMapper,
+}
+
+impl FadeColors {
+    /// Precomputes the gradients from the colors of the first cell in the area, which
+    /// typically holds the dominant colors, e.g. of a uniform background.
+    fn precompute_gradients(&mut self, buf: &Buffer, area: Rect) {
+        let Some(cell) = buf.cell(area.as_position()) else { return };
+
+        if let Some(fg) = self.fg.filter(|_| !self.fg_mapper.is_precomputed()) {
+            self.fg_mapper.precompute(cell.fg, fg, GRADIENT_STEPS);
+        }
+        if let Some(bg) = self.bg.filter(|_| !self.bg_mapper.is_precomputed()) {
+            self.bg_mapper.precompute(cell.bg, bg, GRADIENT_STEPS);
+        }
+    }
 }
 
 impl Shader for FadeColors {
@@ -23,18 +48,53 @@ impl Shader for FadeColors {
         if self.timer.is_reversed() { "fade_from" } else { "fade_to" }
     }
 
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let alpha = self.timer.alpha();
+        self.precompute_gradients(buf, area);
+
+        if let CellFilter::All = self.cell_filter {
+            // uniform alpha over all cells: process the area row by row
+            let (fg_mapper, bg_mapper) = (&self.fg_mapper, &self.bg_mapper);
+            let lerp = |mapper: &ColorMapper, c: Color, to: Color| {
+                mapper.lookup(c, to, alpha).unwrap_or_else(|| c.lerp(&to, alpha))
+            };
+
+            for_each_row(buf, area, |_, cells| {
+                if let Some(fg) = self.fg {
+                    map_cells_fg(cells, |c| lerp(fg_mapper, c, fg));
+                }
+                if let Some(bg) = self.bg {
+                    map_cells_bg(cells, |c| lerp(bg_mapper, c, bg));
+                }
+            });
+        } else {
+            let cells = self.cell_iter(buf, area);
+            self.execute(alpha, area, cells);
+        }
+
+        overflow
+    }
+
     fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
-        let mut fg_mapper = ColorMapper::default();
-        let mut bg_mapper = ColorMapper::default();
+        #[cfg(feature = "underline-color")]
+        let mut underline_mapper = ColorMapper::default();
 
         cell_iter.for_each(|(_, cell)| {
             if let Some(fg) = self.fg.as_ref() {
-                let color = fg_mapper.map(cell.fg, alpha, |c| c.lerp(fg, alpha));
+                let color = self.fg_mapper.lerp(cell.fg, *fg, alpha);
                 cell.set_fg(color);
+
+                // the underline follows the foreground color, unless explicitly set
+                #[cfg(feature = "underline-color")]
+                if cell.underline_color != Color::Reset {
+                    cell.underline_color = underline_mapper
+                        .map(cell.underline_color, alpha, |c| c.lerp(fg, alpha));
+                }
             }
 
             if let Some(bg) = self.bg.as_ref() {
-                let color = bg_mapper.map(cell.bg, alpha, |c| c.lerp(bg, alpha));
+                let color = self.bg_mapper.lerp(cell.bg, *bg, alpha);
                 cell.set_bg(color);
             }
         });
@@ -72,3 +132,35 @@ impl Shader for FadeColors {
         Some(self.cell_filter.clone())
     }
 }
+
+#[cfg(all(test, feature = "underline-color"))]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::style::{Style, Stylize};
+
+    use crate::{fx, Duration, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_fade_underline_color() {
+        let mut buf = Buffer::with_lines(["xy"]);
+        buf[(0, 0)].underline_color = Color::Rgb(255, 0, 0);
+
+        let area = buf.area;
+        let mut fx = fx::fade_to_fg(Color::Rgb(0, 0, 255), (100, Interpolation::Linear));
+        fx.process(Duration::from_millis(100), &mut buf, area);
+
+        assert_eq!(buf[(0, 0)].underline_color, Color::Rgb(0, 0, 255));
+        assert_eq!(buf[(1, 0)].underline_color, Color::Reset);
+    }
+
+    #[test]
+    fn test_style_lerp_underline_color() {
+        let from = Style::new().red().underline_color(Color::Rgb(0, 0, 0));
+        let to = Style::new().blue().underline_color(Color::Rgb(255, 255, 255));
+
+        assert_eq!(from.lerp(&to, 1.0).underline_color, Some(Color::Rgb(255, 255, 255)));
+        assert_eq!(from.lerp(&to, 0.5).underline_color, Some(Color::Rgb(128, 128, 128)));
+    }
+}
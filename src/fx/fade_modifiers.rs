@@ -0,0 +1,163 @@
+use bon::Builder;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator};
+
+/// Animates changes to the text modifiers of cells, e.g. emphasizing text with
+/// [`Modifier::BOLD`]. The modifiers are switched once the timer's alpha reaches
+/// the `threshold`; optionally, [`Modifier::DIM`] is applied while the transition
+/// is in progress to soften the change.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Modifier;
+/// use tachyonfx::fx::FadeModifiers;
+/// use tachyonfx::{Interpolation, IntoEffect};
+///
+/// let fx = FadeModifiers::builder()
+///     .add(Modifier::BOLD)
+///     .remove(Modifier::ITALIC)
+///     .threshold(0.3)
+///     .dim_transition(true)
+///     .timer((400, Interpolation::QuadOut))
+///     .build()
+///     .into_effect();
+/// ```
+#[derive(Builder, Clone, Debug)]
+pub struct FadeModifiers {
+    /// Modifiers added to the cells once the threshold is reached.
+    #[builder(default = Modifier::empty())]
+    add: Modifier,
+    /// Modifiers removed from the cells once the threshold is reached.
+    #[builder(default = Modifier::empty())]
+    remove: Modifier,
+    /// The alpha at which the modifiers are switched. Defaults to 0.5.
+    #[builder(default = 0.5)]
+    threshold: f32,
+    /// Applies [`Modifier::DIM`] while the transition is in progress.
+    #[builder(default)]
+    dim_transition: bool,
+    #[builder(into)]
+    timer: EffectTimer,
+    area: Option<Rect>,
+    #[builder(default)]
+    cell_filter: CellFilter,
+}
+
+impl Shader for FadeModifiers {
+    fn name(&self) -> &'static str {
+        "fade_modifiers"
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let in_transition = self.dim_transition && alpha > 0.0 && alpha < 1.0;
+
+        for (_, cell) in cell_iter {
+            if alpha >= self.threshold {
+                cell.modifier.insert(self.add);
+                cell.modifier.remove(self.remove);
+            }
+
+            if in_transition {
+                cell.modifier.insert(Modifier::DIM);
+            }
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+
+    use crate::{Duration, Interpolation};
+
+    use super::*;
+
+    fn modifiers_at(fx: &FadeModifiers, elapsed: Duration) -> Modifier {
+        let mut buf = Buffer::with_lines(["x"]);
+        buf[(0, 0)].modifier = Modifier::ITALIC;
+
+        let area = buf.area;
+        fx.clone().process(elapsed, &mut buf, area);
+        buf[(0, 0)].modifier
+    }
+
+    #[test]
+    fn test_threshold() {
+        let fx = FadeModifiers::builder()
+            .add(Modifier::BOLD)
+            .remove(Modifier::ITALIC)
+            .threshold(0.3)
+            .timer((100, Interpolation::Linear))
+            .build();
+
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(0)), Modifier::ITALIC);
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(20)), Modifier::ITALIC);
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(30)), Modifier::BOLD);
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(100)), Modifier::BOLD);
+    }
+
+    #[test]
+    fn test_dim_transition() {
+        let fx = FadeModifiers::builder()
+            .add(Modifier::BOLD)
+            .dim_transition(true)
+            .timer((100, Interpolation::Linear))
+            .build();
+
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(0)), Modifier::ITALIC);
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(20)), Modifier::ITALIC | Modifier::DIM);
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(70)), Modifier::ITALIC | Modifier::BOLD | Modifier::DIM);
+        assert_eq!(modifiers_at(&fx, Duration::from_millis(100)), Modifier::ITALIC | Modifier::BOLD);
+    }
+
+    #[test]
+    fn test_fade_modifiers_switches_without_dimming() {
+        let mut fx = crate::fx::fade_modifiers(Modifier::BOLD, Modifier::ITALIC, (100, Interpolation::Linear));
+
+        let mut buf = Buffer::with_lines(["x"]);
+        buf[(0, 0)].modifier = Modifier::ITALIC;
+
+        let area = buf.area;
+        fx.process(Duration::from_millis(20), &mut buf, area);
+        assert_eq!(buf[(0, 0)].modifier, Modifier::ITALIC);
+
+        fx.process(Duration::from_millis(40), &mut buf, area);
+        assert_eq!(buf[(0, 0)].modifier, Modifier::BOLD);
+    }
+}
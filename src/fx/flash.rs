@@ -0,0 +1,132 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator, Duration, Interpolatable};
+
+/// Tints the background of the selected cells with a color, fading back to the
+/// original colors. The original colors are captured on the first frame, so the
+/// effect also works when the underlying content isn't redrawn every frame.
+#[derive(Clone, Debug)]
+pub struct Flash {
+    color: Color,
+    timer: EffectTimer,
+    original_colors: Option<Vec<(Position, Color)>>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Flash {
+    pub fn new(color: Color, timer: EffectTimer) -> Self {
+        Self {
+            color,
+            timer,
+            original_colors: None,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+}
+
+impl Shader for Flash {
+    fn name(&self) -> &'static str {
+        "flash"
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        if self.original_colors.is_none() {
+            let cells = self.cell_iter(buf, area)
+                .map(|(pos, cell)| (pos, cell.bg))
+                .collect();
+            self.original_colors = Some(cells);
+        }
+
+        let overflow = self.timer.process(duration);
+        let alpha = self.timer.alpha();
+
+        let original_colors = self.original_colors.as_deref().unwrap_or_default();
+        for (pos, original) in original_colors {
+            if let Some(cell) = buf.cell_mut(*pos) {
+                cell.set_bg(self.color.lerp(original, alpha));
+            }
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        self.original_colors = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpolation;
+
+    use super::*;
+
+    #[test]
+    fn test_flash_restores_captured_colors() {
+        let original = Color::Rgb(20, 40, 60);
+        let mut buf = Buffer::with_lines(["ab"]);
+        buf.set_style(buf.area, original);
+        buf[(0, 0)].set_bg(original);
+        buf[(1, 0)].set_bg(Color::Rgb(0, 0, 0));
+
+        let area = buf.area;
+        let mut fx = Flash::new(Color::White, EffectTimer::from_ms(100, Interpolation::Linear));
+        fx.process(Duration::from_millis(0), &mut buf, area);
+        assert_eq!(buf[(0, 0)].bg, Color::White);
+        assert_eq!(buf[(1, 0)].bg, Color::White);
+
+        // captured colors are used even though the buffer wasn't redrawn
+        fx.process(Duration::from_millis(100), &mut buf, area);
+        assert_eq!(buf[(0, 0)].bg, original);
+        assert_eq!(buf[(1, 0)].bg, Color::Rgb(0, 0, 0));
+        assert_eq!(buf[(0, 0)].fg, original);
+    }
+}
@@ -0,0 +1,184 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Style;
+
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Interpolatable, Interpolation, RefCount, Shader};
+
+#[cfg(not(feature = "sendable"))]
+type FocusAreaFn = RefCount<dyn Fn() -> Option<Rect>>;
+#[cfg(feature = "sendable")]
+type FocusAreaFn = RefCount<dyn Fn() -> Option<Rect> + Send>;
+
+/// The duration of moving between focus targets.
+const TRANSITION: (u32, Interpolation) = (250, Interpolation::QuadOut);
+
+/// Styles the outline of the focused area, as reported by a closure each frame,
+/// tweening the outline whenever the focus moves to a different area.
+#[derive(Clone)]
+pub struct FocusRing {
+    focused_area: FocusAreaFn,
+    style: Style,
+    /// the tween between the previous and the current focus target
+    tween: Option<(Rect, Rect, EffectTimer)>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl FocusRing {
+    pub fn new(focused_area: FocusAreaFn, style: Style) -> Self {
+        Self {
+            focused_area,
+            style,
+            tween: None,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    fn focused_area(&self) -> Option<Rect> {
+        #[cfg(not(feature = "sendable"))]
+        let f = self.focused_area.borrow();
+        #[cfg(feature = "sendable")]
+        let f = self.focused_area.lock().unwrap();
+        f()
+    }
+
+    /// Returns the area of the outline, between focus targets while tweening.
+    fn ring(&self) -> Option<Rect> {
+        self.tween.map(|(from, to, timer)| from.lerp(&to, timer.alpha()))
+    }
+
+    fn update_target(&mut self, target: Option<Rect>, duration: Duration) {
+        let Some(target) = target else {
+            self.tween = None;
+            return;
+        };
+
+        match self.tween.as_mut() {
+            Some((_, to, timer)) if *to == target => {
+                timer.process(duration);
+            },
+            _ => {
+                let from = self.ring().unwrap_or(target);
+                self.tween = Some((from, target, TRANSITION.into()));
+            },
+        }
+    }
+}
+
+impl Shader for FocusRing {
+    fn name(&self) -> &'static str {
+        "focus_ring"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, _area: Rect) -> Option<Duration> {
+        self.update_target(self.focused_area(), duration);
+
+        if let Some(ring) = self.ring() {
+            let ring = ring.intersection(buf.area);
+            let cells = CellIterator::new(buf, ring, Some(self.cell_filter.clone()));
+            self.execute(1.0, ring, cells);
+        }
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, area: Rect, cell_iter: CellIterator) {
+        let on_outline = |pos: &Position| pos.x == area.left()
+            || pos.x == area.right() - 1
+            || pos.y == area.top()
+            || pos.y == area.bottom() - 1;
+
+        cell_iter
+            .filter(|(pos, _)| on_outline(pos))
+            .for_each(|(_, cell)| { cell.set_style(self.style); });
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn dirty_region(&self) -> Option<Rect> {
+        Some(self.ring().unwrap_or_default())
+    }
+
+    fn reset(&mut self) {
+        self.tween = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use crate::{fx, ref_count};
+
+    use super::*;
+
+    fn highlighted(buf: &Buffer) -> Vec<String> {
+        (0..buf.area.height)
+            .map(|y| (0..buf.area.width)
+                .map(|x| if buf[(x, y)].bg == Color::Blue { '#' } else { '.' })
+                .collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_ring_follows_focus() {
+        let area = Rect::new(0, 0, 8, 3);
+        let focus = ref_count(Rect::new(0, 0, 3, 3));
+        let target = focus.clone();
+
+        let mut ring = fx::focus_ring(
+            move || {
+                #[cfg(not(feature = "sendable"))]
+                return Some(*target.borrow());
+                #[cfg(feature = "sendable")]
+                Some(*target.lock().unwrap())
+            },
+            Style::new().bg(Color::Blue),
+        );
+
+        let mut buf = Buffer::empty(area);
+        ring.process(Duration::from_millis(16), &mut buf, area);
+        assert_eq!(highlighted(&buf), ["###.....", "#.#.....", "###....."]);
+
+        #[cfg(not(feature = "sendable"))]
+        { *focus.borrow_mut() = Rect::new(4, 0, 4, 3); }
+        #[cfg(feature = "sendable")]
+        { *focus.lock().unwrap() = Rect::new(4, 0, 4, 3); }
+
+        // the outline moves over the duration of the transition
+        let mut buf = Buffer::empty(area);
+        ring.process(Duration::from_millis(16), &mut buf, area);
+        assert_eq!(highlighted(&buf), ["###.....", "#.#.....", "###....."]);
+
+        let mut buf = Buffer::empty(area);
+        ring.process(Duration::from_millis(300), &mut buf, area);
+        assert_eq!(highlighted(&buf), ["....####", "....#..#", "....####"]);
+    }
+}
@@ -1,60 +1,211 @@
-use bon::{builder, Builder};
+use bon::Builder;
 use std::fmt::Debug;
 use std::ops::Range;
 
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Position, Rect};
-use crate::{CellFilter, CellIterator, Duration, EffectTimer};
+use ratatui::style::Color;
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, HslConvertable};
+use crate::cell_filter::CellSelector;
+use crate::color_ext::ToRgbComponents;
 use crate::simple_rng::{RangeSampler, SimpleRng};
 use crate::shader::Shader;
+use crate::wide_cells::{char_width, symbol_width};
 
 
 /// Type of glitch transformation to apply to a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GlitchType {
+    /// Flips the case of ASCII letters.
+    ChangeCase,
+    /// Offsets the character by a random value.
+    ChangeCharByValue,
+    /// Replaces the symbol with a random block glyph.
+    BlockGlyph,
+    /// Replaces the symbol with a random character from the glitch charset.
+    ReplaceChar,
+    /// Corrupts a single RGB channel of the foreground color.
+    CorruptColor,
+    /// Tears the foreground and background colors, approximating analog signal
+    /// distortion: a hue jump, an RGB channel swap or a brief inversion.
+    ColorTear,
+}
+
+/// The transformation applied by a single glitch, with any randomized
+/// parameters resolved at the time the glitch is spawned.
 #[derive(Clone, Debug)]
-pub enum GlitchType { // fixme: make non-public again
+enum GlitchAction {
     ChangeCase,
     ChangeCharByValue(i8),
+    ReplaceChar(char),
+    CorruptColor { channel: u8, value: u8 },
+    ColorTear(ColorTear),
+}
+
+/// Color distortion applied by [`GlitchType::ColorTear`], to both fg and bg.
+#[derive(Clone, Copy, Debug)]
+enum ColorTear {
+    HueJump(f32),
+    SwapChannels,
+    Invert,
+}
+
+impl ColorTear {
+    fn apply(self, color: Color) -> Color {
+        match self {
+            ColorTear::HueJump(degrees) => {
+                let (h, s, l) = color.to_hsl();
+                Color::from_hsl((h + degrees) % 360.0, s, l)
+            }
+            ColorTear::SwapChannels => {
+                let (r, g, b) = color.to_rgb();
+                Color::Rgb(g, b, r)
+            }
+            ColorTear::Invert => {
+                let (r, g, b) = color.to_rgb();
+                Color::Rgb(255 - r, 255 - g, 255 - b)
+            }
+        }
+    }
 }
 
 /// A glitch effect that can be applied to a cell.
 #[derive(Builder, Clone, Debug)]
-pub struct GlitchCell {
-    cell_idx: usize,
+struct GlitchCell {
+    position: Position,
     glitch_remaining_ms: u32,
     presleep_remaining_ms: u32,
-    glitch: GlitchType,
+    glitch: GlitchAction,
 }
 
 /// applies a glitch effect to random parts of the screen.
-#[derive(Builder, Clone)]
+///
+/// # Example
+/// ```
+/// use tachyonfx::fx::{Glitch, GlitchType};
+///
+/// let glitch = Glitch::builder()
+///     .cell_glitch_ratio(0.05)
+///     .action_start_delay_ms(100..500)
+///     .action_ms(50..200)
+///     .glitch_types(vec![GlitchType::ReplaceChar, GlitchType::BlockGlyph])
+///     .charset("01".chars().collect())
+///     .build();
+/// ```
+///
+/// The glitch intensity can be ramped over time with an envelope timer:
+/// ```
+/// use tachyonfx::fx::Glitch;
+/// use tachyonfx::Interpolation::QuadOut;
+///
+/// let glitch = Glitch::builder()
+///     .cell_glitch_ratio(0.1)
+///     .action_start_delay_ms(0..200)
+///     .action_ms(50..100)
+///     .intensity((1000, QuadOut))
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
 pub struct Glitch {
     cell_glitch_ratio: f32,
     action_start_delay_ms: Range<u32>,
     action_ms: Range<u32>,
-    #[builder(default)]
+    glitch_types: Vec<GlitchType>,
+    charset: Vec<char>,
+    intensity: Option<EffectTimer>,
     rng: SimpleRng,
-    #[builder(default)]
     selection: CellFilter,
-
-    #[builder(skip)]
     glitch_cells: Vec<GlitchCell>,
+    /// the area the glitch cells were populated for
+    populated_area: Rect,
     area: Option<Rect>,
 }
 
+#[bon::bon]
 impl Glitch {
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        /// The ratio of cells in the area that are glitching at any given time.
+        cell_glitch_ratio: f32,
+        /// The range of the random delay before a glitch is applied.
+        action_start_delay_ms: Range<u32>,
+        /// The range of the random duration of a glitch.
+        action_ms: Range<u32>,
+        /// The glitch transformations to pick from. Defaults to
+        /// [`GlitchType::ChangeCase`] and [`GlitchType::ChangeCharByValue`].
+        #[builder(default = vec![GlitchType::ChangeCase, GlitchType::ChangeCharByValue])]
+        mut glitch_types: Vec<GlitchType>,
+        /// Replacement characters used by [`GlitchType::ReplaceChar`]. An empty
+        /// charset disables `ReplaceChar` glitches.
+        #[builder(default = DEFAULT_CHARSET.chars().collect())]
+        charset: Vec<char>,
+        /// Optional envelope scaling `cell_glitch_ratio` by the timer's alpha, allowing
+        /// the glitch intensity to ramp up (or die down, when reversed) over time.
+        #[builder(into)]
+        intensity: Option<EffectTimer>,
+        #[builder(default)]
+        rng: SimpleRng,
+        #[builder(default)]
+        selection: CellFilter,
+        area: Option<Rect>,
+    ) -> Self {
+        if charset.is_empty() {
+            glitch_types.retain(|t| *t != GlitchType::ReplaceChar);
+        }
+
+        Self {
+            cell_glitch_ratio,
+            action_start_delay_ms,
+            action_ms,
+            glitch_types,
+            charset,
+            intensity,
+            rng,
+            selection,
+            glitch_cells: Vec::new(),
+            populated_area: Rect::default(),
+            area,
+        }
+    }
+
+    /// Returns the number of glitching cells for the area, at the current intensity.
+    fn target_population(&self, area: Rect) -> usize {
+        let ratio = self.cell_glitch_ratio * self.intensity.map_or(1.0, |t| t.alpha());
+        (area.width as f32 * area.height as f32 * ratio).round() as usize
+    }
+
+    /// Drops the glitch cells outside a changed area, along with any excess from
+    /// the area shrinking, before the population is topped up for the new area.
+    fn rebalance_population(&mut self, area: Rect) {
+        if area == self.populated_area {
+            return;
+        }
+
+        self.glitch_cells.retain(|cell| area.contains(cell.position));
+        self.glitch_cells.truncate(self.target_population(area));
+        self.populated_area = area;
+    }
+
     fn ensure_population(
         &mut self,
         screen: &Rect,
     ) {
-        let total_cells = (screen.width as f32 * screen.height as f32 * self.cell_glitch_ratio)
-            .round() as u32;
+        let total_cells = self.target_population(*screen);
 
-        let current_population = self.glitch_cells.len() as u32;
+        let current_population = self.glitch_cells.len();
         if current_population < total_cells {
             for _ in 0..(total_cells - current_population) {
+                let position = Position::new(
+                    screen.x + self.rng.gen_range(0..screen.width),
+                    screen.y + self.rng.gen_range(0..screen.height),
+                );
+                let Some(glitch) = self.glitch_action() else {
+                    break; // no applicable glitch types
+                };
+
                 let cell = GlitchCell::builder()
-                    .cell_idx(self.rng.gen_range(0..(screen.width * screen.height) as usize))
-                    .glitch(self.glitch_type())
+                    .position(position)
+                    .glitch(glitch)
                     .glitch_remaining_ms(self.rng.gen_range(self.action_ms.clone()))
                     .presleep_remaining_ms(self.rng.gen_range(self.action_start_delay_ms.clone()))
                     .build();
@@ -75,13 +226,25 @@ impl Glitch {
         cell.glitch_remaining_ms > 0
     }
 
-    fn glitch_type(&mut self) -> GlitchType {
-        let idx: u32 = self.rng.gen();
-        match idx % 2 {
-            0 => GlitchType::ChangeCase,
-            1 => GlitchType::ChangeCharByValue(-10 + self.rng.gen_range(0..20) as i8),
-            _ => unreachable!(),
-        }
+    fn glitch_action(&mut self) -> Option<GlitchAction> {
+        let idx = self.rng.gen() as usize % self.glitch_types.len().max(1);
+        let action = match self.glitch_types.get(idx)? {
+            GlitchType::ChangeCase        => GlitchAction::ChangeCase,
+            GlitchType::ChangeCharByValue => GlitchAction::ChangeCharByValue(-10 + self.rng.gen_range(0..20) as i8),
+            GlitchType::BlockGlyph        => GlitchAction::ReplaceChar(BLOCK_GLYPHS[self.rng.gen_range(0..BLOCK_GLYPHS.len())]),
+            GlitchType::ReplaceChar       => GlitchAction::ReplaceChar(self.charset[self.rng.gen_range(0..self.charset.len())]),
+            GlitchType::CorruptColor      => GlitchAction::CorruptColor {
+                channel: self.rng.gen_range(0..3u16) as u8,
+                value: self.rng.gen_range(0..256u16) as u8,
+            },
+            GlitchType::ColorTear         => GlitchAction::ColorTear(match self.rng.gen_range(0..3u16) {
+                0 => ColorTear::HueJump(self.rng.gen_range(60.0..300.0)),
+                1 => ColorTear::SwapChannels,
+                _ => ColorTear::Invert,
+            }),
+        };
+
+        Some(action)
     }
 }
 
@@ -96,31 +259,34 @@ impl Shader for Glitch {
         buf: &mut Buffer,
         area: Rect,
     ) -> Option<Duration> {
-        // ensure glitch population meets the cell_glitch_ratio
+        if let Some(timer) = &mut self.intensity {
+            timer.process(duration);
+        }
+
+        // ensure glitch population meets the cell_glitch_ratio, also after resizing
+        self.rebalance_population(area);
         self.ensure_population(&area);
 
         // subtract durations
-        let last_frame_ms = duration.as_millis();
+        #[allow(clippy::unnecessary_cast)] // u128 with the std-duration feature
+        let last_frame_ms = duration.as_millis() as u32;
         self.glitch_cells.iter_mut().for_each(|cell| Self::update_cell(cell, last_frame_ms));
 
-        // remove invalid cells (e.g., from resizing)
-        self.glitch_cells.retain(|cell| cell.cell_idx < buf.content.len());
-
-        let selector = self.selection.selector(area);
+        let selector = CellSelector::with_buffer(area, self.selection.clone(), Some(buf));
 
         // apply glitches to buffer
         self.glitch_cells.iter().filter(|c| c.presleep_remaining_ms == 0).for_each(|cell| {
-            let x = cell.cell_idx % area.width as usize;
-            let y = cell.cell_idx / area.width as usize;
-            let pos = Position::new(area.x + x as u16, area.y + y as u16);
-            let c  = buf.cell_mut(Position::new(area.x + x as u16, area.y + y as u16)).unwrap();
+            let pos = cell.position;
+            let Some(c) = buf.cell_mut(pos) else {
+                return; // area clipped by the buffer
+            };
 
             if !selector.is_valid(pos, c) {
                 return;
             }
 
             match cell.glitch {
-                GlitchType::ChangeCase if c.symbol().is_ascii() => {
+                GlitchAction::ChangeCase if c.symbol().is_ascii() => {
                     let ch = c.symbol().chars().next().unwrap();
                     c.set_char(if ch.is_ascii_uppercase() {
                         ch.to_ascii_lowercase()
@@ -128,7 +294,7 @@ impl Shader for Glitch {
                         ch.to_ascii_uppercase()
                     });
                 }
-                GlitchType::ChangeCharByValue(v) if c.symbol().len() == 1 => {
+                GlitchAction::ChangeCharByValue(v) if c.symbol().len() == 1 => {
                     if c.symbol().chars().next().is_some_and(|ch| ch == ' ') {
                         return;
                     }
@@ -143,6 +309,24 @@ impl Shader for Glitch {
                             .clamp(32, 255) as char
                     });
                 }
+                // wide replacements would spill into the next cell
+                GlitchAction::ReplaceChar(ch) if !c.symbol().trim().is_empty()
+                    && char_width(ch) <= symbol_width(c) =>
+                {
+                    c.set_char(ch);
+                }
+                GlitchAction::CorruptColor { channel, value } => {
+                    let (r, g, b) = c.fg.to_rgb();
+                    c.set_fg(match channel {
+                        0 => Color::Rgb(value, g, b),
+                        1 => Color::Rgb(r, value, b),
+                        _ => Color::Rgb(r, g, value),
+                    });
+                }
+                GlitchAction::ColorTear(tear) => {
+                    c.set_fg(tear.apply(c.fg));
+                    c.set_bg(tear.apply(c.bg));
+                }
                 _ => {}
             }
         });
@@ -175,7 +359,13 @@ impl Shader for Glitch {
         self.selection = strategy;
     }
 
-    fn timer_mut(&mut self) -> Option<&mut EffectTimer> { None }
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        self.intensity.as_mut()
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.intensity
+    }
 
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.selection.clone())
@@ -183,5 +373,184 @@ impl Shader for Glitch {
 
     fn reset(&mut self) {
         self.glitch_cells.clear();
+        if let Some(timer) = &mut self.intensity {
+            timer.reset();
+        }
+    }
+}
+
+const DEFAULT_CHARSET: &str = "!#$%&*+-/<=>?@[]^_{|}~";
+const BLOCK_GLYPHS: &[char] = &['█', '▓', '▒', '░', '▀', '▄', '▌', '▐', '▖', '▗', '▘', '▝'];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glitch(glitch_types: Vec<GlitchType>, charset: &str) -> Glitch {
+        Glitch::builder()
+            .cell_glitch_ratio(1.0)
+            .action_start_delay_ms(0..0)
+            .action_ms(100..100)
+            .glitch_types(glitch_types)
+            .charset(charset.chars().collect())
+            .rng(SimpleRng::new(1234))
+            .build()
+    }
+
+    fn process(fx: &mut Glitch, buf: &mut Buffer) {
+        let area = buf.area;
+        fx.process(Duration::from_millis(16), buf, area);
+    }
+
+    #[test]
+    fn test_replace_char_uses_charset() {
+        let mut buf = Buffer::with_lines(["abcdefgh"]);
+        let mut fx = glitch(vec![GlitchType::ReplaceChar], "x");
+        process(&mut fx, &mut buf);
+
+        let symbols: String = buf.content.iter().map(|c| c.symbol()).collect();
+        assert!(symbols.contains('x'), "no glitches applied: {symbols}");
+        assert!(symbols.chars().zip("abcdefgh".chars()).all(|(a, b)| a == 'x' || a == b));
+    }
+
+    #[test]
+    fn test_block_glyph_skips_whitespace() {
+        let mut buf = Buffer::with_lines(["a b c d "]);
+        let mut fx = glitch(vec![GlitchType::BlockGlyph], "");
+        process(&mut fx, &mut buf);
+
+        buf.content.iter().skip(1).step_by(2).for_each(|c| assert_eq!(c.symbol(), " "));
+        assert!(buf.content.iter().step_by(2).any(|c| BLOCK_GLYPHS.contains(&c.symbol().chars().next().unwrap())));
+    }
+
+    #[test]
+    fn test_empty_charset_disables_replace_char() {
+        let mut buf = Buffer::with_lines(["abcdefgh"]);
+        let mut fx = glitch(vec![GlitchType::ReplaceChar], "");
+        process(&mut fx, &mut buf);
+
+        assert_eq!(buf, Buffer::with_lines(["abcdefgh"]));
+    }
+
+    #[test]
+    fn test_empty_charset_keeps_other_glitch_types() {
+        let mut buf = Buffer::with_lines(["abcdefgh"]);
+        let mut fx = glitch(vec![GlitchType::ReplaceChar, GlitchType::ChangeCase], "");
+        process(&mut fx, &mut buf);
+
+        assert_eq!(fx.glitch_cells.len(), 8);
+        assert!(buf.content.iter().all(|c| c.symbol().chars().all(|ch| ch.is_ascii_alphabetic())));
+        assert_ne!(buf, Buffer::with_lines(["abcdefgh"]));
+    }
+
+    #[test]
+    fn test_wide_replacements_fit_the_cell() {
+        let mut buf = Buffer::with_lines(["ab中c"]);
+        let mut fx = glitch(vec![GlitchType::ReplaceChar], "字");
+        process(&mut fx, &mut buf);
+
+        let symbols: String = buf.content.iter().map(|c| c.symbol()).collect();
+        assert!(symbols == "ab中 c" || symbols == "ab字 c", "unexpected glitches: {symbols}");
+    }
+
+    #[test]
+    fn test_corrupt_color() {
+        let mut buf = Buffer::with_lines(["abcdefgh"]);
+        buf.set_style(buf.area, Color::Rgb(10, 20, 30));
+        let mut fx = glitch(vec![GlitchType::CorruptColor], "");
+        process(&mut fx, &mut buf);
+
+        assert!(buf.content.iter().any(|c| c.fg != Color::Rgb(10, 20, 30)));
+        assert_eq!(buf.content.iter().map(|c| c.symbol()).collect::<String>(), "abcdefgh");
+    }
+
+    #[test]
+    fn test_color_tear() {
+        let mut buf = Buffer::with_lines(["abcdefgh"]);
+        buf.set_style(buf.area, (Color::Rgb(200, 20, 30), Color::Rgb(0, 0, 40)));
+        let mut fx = glitch(vec![GlitchType::ColorTear], "");
+        process(&mut fx, &mut buf);
+
+        assert!(buf.content.iter().any(|c| c.fg != Color::Rgb(200, 20, 30)));
+        assert!(buf.content.iter().any(|c| c.bg != Color::Rgb(0, 0, 40)));
+        assert_eq!(buf.content.iter().map(|c| c.symbol()).collect::<String>(), "abcdefgh");
+    }
+
+    #[test]
+    fn test_color_tear_transforms() {
+        let c = Color::Rgb(10, 20, 30);
+        assert_eq!(ColorTear::SwapChannels.apply(c), Color::Rgb(20, 30, 10));
+        assert_eq!(ColorTear::Invert.apply(c), Color::Rgb(245, 235, 225));
+
+        let (h, _, _) = ColorTear::HueJump(180.0).apply(Color::Rgb(255, 0, 0)).to_hsl();
+        assert!((h - 180.0).abs() < 1.0, "hue: {h}");
+    }
+
+    #[test]
+    fn test_intensity_scales_population() {
+        let mut fx = Glitch::builder()
+            .cell_glitch_ratio(1.0)
+            .action_start_delay_ms(0..0)
+            .action_ms(1000..1000)
+            .intensity(EffectTimer::from_ms(100, crate::Interpolation::Linear))
+            .build();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let area = buf.area;
+
+        fx.process(Duration::from_millis(0), &mut buf, area);
+        assert_eq!(fx.glitch_cells.len(), 0);
+
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(fx.glitch_cells.len(), 50);
+
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(fx.glitch_cells.len(), 100);
+
+        fx.reset();
+        fx.process(Duration::from_millis(0), &mut buf, area);
+        assert_eq!(fx.glitch_cells.len(), 0);
+    }
+
+    #[test]
+    fn test_reversed_intensity_dies_down() {
+        let mut fx = Glitch::builder()
+            .cell_glitch_ratio(1.0)
+            .action_start_delay_ms(0..0)
+            .action_ms(10..10)
+            .intensity(EffectTimer::from_ms(100, crate::Interpolation::Linear).reversed())
+            .build();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 10));
+        let area = buf.area;
+
+        fx.process(Duration::from_millis(0), &mut buf, area);
+        assert_eq!(fx.glitch_cells.len(), 100);
+
+        fx.process(Duration::from_millis(100), &mut buf, area);
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        assert!(fx.glitch_cells.is_empty());
+    }
+
+    #[test]
+    fn test_population_follows_resize() {
+        let mut fx = Glitch::builder()
+            .cell_glitch_ratio(0.5)
+            .action_start_delay_ms(0..0)
+            .action_ms(1000..1000)
+            .build();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+        fx.process(Duration::from_millis(16), &mut buf, Rect::new(0, 0, 4, 20));
+        assert_eq!(fx.glitch_cells.len(), 40);
+
+        // wider, but shorter: the cells outside are dropped, and new ones spread
+        // over the whole area
+        let area = Rect::new(0, 10, 20, 2);
+        fx.process(Duration::from_millis(16), &mut buf, area);
+        assert_eq!(fx.glitch_cells.len(), 20);
+        assert!(fx.glitch_cells.iter().all(|c| area.contains(c.position)));
+        assert!(fx.glitch_cells.iter().any(|c| c.position.x >= 4));
+        assert!(fx.glitch_cells.iter().any(|c| c.position.y == 11));
     }
 }
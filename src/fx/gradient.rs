@@ -0,0 +1,272 @@
+use bon::Builder;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator, Interpolatable};
+
+/// The shape of a [GradientSpec].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientKind {
+    /// A linear gradient running along `angle`, in degrees. 0° runs from
+    /// left to right, 90° from top to bottom.
+    Linear { angle: f32 },
+    /// A radial gradient emanating from `center`, given in coordinates
+    /// relative to the effect area (0.0 to 1.0).
+    Radial { center: (f32, f32) },
+}
+
+impl Default for GradientKind {
+    fn default() -> Self {
+        GradientKind::Linear { angle: 0.0 }
+    }
+}
+
+/// Which colors of the cell a gradient is painted onto.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GradientTarget {
+    /// The foreground color.
+    #[default]
+    Foreground,
+    /// The background color.
+    Background,
+    /// Both the foreground and background colors.
+    Both,
+}
+
+/// Describes a multi-stop gradient, as painted by [gradient](crate::fx::gradient).
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx::{GradientKind, GradientSpec, GradientTarget};
+///
+/// let spec = GradientSpec::builder()
+///     .stops(vec![
+///         (0.0, Color::from_u32(0x1e1e2e)),
+///         (0.5, Color::from_u32(0xcba6f7)),
+///         (1.0, Color::from_u32(0x1e1e2e)),
+///     ])
+///     .kind(GradientKind::Linear { angle: 45.0 })
+///     .target(GradientTarget::Background)
+///     .speed(2.0)
+///     .build();
+/// ```
+#[derive(Builder, Clone, Debug)]
+pub struct GradientSpec {
+    /// The color stops, as `(position, color)` pairs with positions in the range
+    /// 0.0 to 1.0.
+    stops: Vec<(f32, Color)>,
+    /// The shape of the gradient. Defaults to a horizontal linear gradient.
+    #[builder(default)]
+    kind: GradientKind,
+    /// The cell colors the gradient is painted onto.
+    #[builder(default)]
+    target: GradientTarget,
+    /// How many times the gradient cycles across the area over the duration of
+    /// the effect. A speed of 0.0 produces a static gradient.
+    #[builder(default = 1.0)]
+    speed: f32,
+}
+
+impl GradientSpec {
+    /// Returns the color at position `t` of the gradient. Positions outside of
+    /// the stops are clamped to the first and last stop, respectively.
+    pub fn color_at(&self, t: f32) -> Color {
+        let stops = &self.stops;
+        match stops.iter().position(|(pos, _)| *pos > t) {
+            None if stops.is_empty() => Color::Reset,
+            None => stops[stops.len() - 1].1,
+            Some(0) => stops[0].1,
+            Some(idx) => {
+                let (p0, c0) = stops[idx - 1];
+                let (p1, c1) = stops[idx];
+                c0.lerp(&c1, (t - p0) / (p1 - p0))
+            }
+        }
+    }
+
+    /// Returns the position of `pos` along the gradient, before animation is
+    /// applied. Values are in the range 0.0 to 1.0.
+    fn position(&self, area: Rect, pos: Position) -> f32 {
+        // terminal cells are roughly twice as tall as they are wide
+        let x = (pos.x - area.x) as f32;
+        let y = (pos.y - area.y) as f32 * 2.0;
+        let w = area.width.saturating_sub(1) as f32;
+        let h = area.height.saturating_sub(1) as f32 * 2.0;
+
+        match self.kind {
+            GradientKind::Linear { angle } => {
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let project = |x: f32, y: f32| x * cos + y * sin;
+
+                let corners = [project(0.0, 0.0), project(w, 0.0), project(0.0, h), project(w, h)];
+                let min = corners.iter().copied().fold(f32::MAX, f32::min);
+                let max = corners.iter().copied().fold(f32::MIN, f32::max);
+
+                if max > min { (project(x, y) - min) / (max - min) } else { 0.0 }
+            }
+            GradientKind::Radial { center: (cx, cy) } => {
+                let (cx, cy) = (cx * w, cy * h);
+                let distance = |x: f32, y: f32| ((x - cx).powi(2) + (y - cy).powi(2)).sqrt();
+
+                let max = [distance(0.0, 0.0), distance(w, 0.0), distance(0.0, h), distance(w, h)]
+                    .into_iter()
+                    .fold(0.0, f32::max);
+
+                if max > 0.0 { distance(x, y) / max } else { 0.0 }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Gradient {
+    spec: GradientSpec,
+    timer: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Gradient {
+    pub fn new(mut spec: GradientSpec, timer: EffectTimer) -> Self {
+        spec.stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { spec, timer, area: None, cell_filter: CellFilter::All }
+    }
+}
+
+impl Shader for Gradient {
+    fn name(&self) -> &'static str {
+        "gradient"
+    }
+
+    fn execute(&mut self, alpha: f32, area: Rect, cell_iter: CellIterator) {
+        let offset = (alpha * self.spec.speed).fract();
+
+        for (pos, cell) in cell_iter {
+            let t = self.spec.position(area, pos) - offset;
+            let color = self.spec.color_at(if t < 0.0 { t + 1.0 } else { t });
+
+            match self.spec.target {
+                GradientTarget::Foreground => { cell.set_fg(color); },
+                GradientTarget::Background => { cell.set_bg(color); },
+                GradientTarget::Both       => { cell.set_fg(color).set_bg(color); },
+            }
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+
+    use crate::{Duration, Interpolation};
+
+    use super::*;
+
+    const BLACK: Color = Color::Rgb(0, 0, 0);
+    const WHITE: Color = Color::Rgb(255, 255, 255);
+
+    fn spec(kind: GradientKind, speed: f32) -> GradientSpec {
+        GradientSpec::builder()
+            .stops(vec![(0.0, BLACK), (1.0, WHITE)])
+            .kind(kind)
+            .speed(speed)
+            .build()
+    }
+
+    fn render(spec: GradientSpec, elapsed: Duration, buf: &mut Buffer) {
+        let area = buf.area;
+        let mut fx = Gradient::new(spec, EffectTimer::from_ms(100, Interpolation::Linear));
+        fx.process(elapsed, buf, area);
+    }
+
+    #[test]
+    fn test_color_at() {
+        let spec = GradientSpec::builder()
+            .stops(vec![(0.25, BLACK), (0.75, WHITE)])
+            .build();
+
+        assert_eq!(spec.color_at(0.0), BLACK);
+        assert_eq!(spec.color_at(0.25), BLACK);
+        assert_eq!(spec.color_at(0.75), WHITE);
+        assert_eq!(spec.color_at(1.0), WHITE);
+        assert_eq!(spec.color_at(0.5), Color::Rgb(128, 128, 128));
+    }
+
+    #[test]
+    fn test_linear_gradient() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        render(spec(GradientKind::Linear { angle: 0.0 }, 0.0), Duration::from_millis(0), &mut buf);
+
+        assert_eq!(buf[(0, 0)].fg, BLACK);
+        assert_eq!(buf[(0, 1)].fg, BLACK);
+        assert_eq!(buf[(2, 0)].fg, Color::Rgb(128, 128, 128));
+        assert_eq!(buf[(4, 1)].fg, WHITE);
+        assert_eq!(buf[(4, 1)].bg, Color::Reset);
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        render(spec(GradientKind::Linear { angle: 90.0 }, 0.0), Duration::from_millis(0), &mut buf);
+        assert_eq!(buf[(0, 0)].fg, buf[(4, 0)].fg);
+        assert_eq!(buf[(4, 2)].fg, WHITE);
+    }
+
+    #[test]
+    fn test_radial_gradient() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        render(spec(GradientKind::Radial { center: (0.5, 0.5) }, 0.0), Duration::from_millis(0), &mut buf);
+
+        assert_eq!(buf[(2, 1)].fg, BLACK);
+        assert_eq!(buf[(0, 0)].fg, WHITE);
+        assert_eq!(buf[(4, 2)].fg, WHITE);
+    }
+
+    #[test]
+    fn test_gradient_moves_over_time() {
+        let spec = spec(GradientKind::default(), 1.0);
+
+        let mut a = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let mut b = Buffer::empty(Rect::new(0, 0, 5, 1));
+        render(spec.clone(), Duration::from_millis(0), &mut a);
+        render(spec.clone(), Duration::from_millis(50), &mut b);
+        assert_ne!(a, b);
+
+        // a full cycle returns to the start
+        let mut c = Buffer::empty(Rect::new(0, 0, 5, 1));
+        render(spec, Duration::from_millis(100), &mut c);
+        assert_eq!(a, c);
+    }
+}
@@ -1,13 +1,15 @@
-use bon::{builder, Builder};
+use bon::Builder;
+use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
 
 use crate::effect_timer::EffectTimer;
 use crate::shader::Shader;
 use crate::CellFilter;
-use crate::{CellIterator, ColorMapper, HslConvertable, Interpolatable};
+use crate::color_batch::{for_each_row, map_cells_bg, map_cells_fg};
+use crate::{CellIterator, ColorMapper, Duration, HslConvertable, Interpolatable};
 
-#[derive(Builder, Clone, Default)]
+#[derive(Builder, Clone, Default, Debug)]
 pub struct HslShift {
     #[builder(into)]
     timer: EffectTimer,
@@ -18,31 +20,63 @@ pub struct HslShift {
     cell_filter: CellFilter,
 }
 
+fn hsl_lerp(c: Color, hsl: [f32; 3], alpha: f32) -> Color {
+    let (h, s, l) = c.to_hsl();
+
+    let (h, s, l) = (
+        (h + 0.0.lerp(&hsl[0], alpha)) % 360.0,
+        (s + 0.0.lerp(&hsl[1], alpha)).clamp(0.0, 100.0),
+        (l + 0.0.lerp(&hsl[2], alpha)).clamp(0.0, 100.0),
+    );
+
+    HslConvertable::from_hsl(h, s, l)
+}
+
 impl Shader for HslShift {
     fn name(&self) -> &'static str {
         "hsl_shift"
     }
 
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let alpha = self.timer.alpha();
+
+        if let CellFilter::All = self.cell_filter {
+            // uniform alpha over all cells: process the area row by row
+            for_each_row(buf, area, |_, cells| {
+                if let Some(hsl_mod) = self.hsl_mod_fg {
+                    map_cells_fg(cells, |c| hsl_lerp(c, hsl_mod, alpha));
+                }
+                if let Some(hsl_mod) = self.hsl_mod_bg {
+                    map_cells_bg(cells, |c| hsl_lerp(c, hsl_mod, alpha));
+                }
+            });
+        } else {
+            let cells = self.cell_iter(buf, area);
+            self.execute(alpha, area, cells);
+        }
+
+        overflow
+    }
+
     fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
         let mut fg_mapper = ColorMapper::default();
         let mut bg_mapper = ColorMapper::default();
+        #[cfg(feature = "underline-color")]
+        let mut underline_mapper = ColorMapper::default();
 
-        let hsl_lerp = |c: Color, hsl: [f32; 3]| -> Color {
-            let (h, s, l) = c.to_hsl();
-
-            let (h, s, l) = (
-                (h + 0.0.lerp(&hsl[0], alpha)) % 360.0,
-                (s + 0.0.lerp(&hsl[1], alpha)).clamp(0.0, 100.0),
-                (l + 0.0.lerp(&hsl[2], alpha)).clamp(0.0, 100.0),
-            );
-
-            HslConvertable::from_hsl(h, s, l)
-        };
+        let hsl_lerp = |c: Color, hsl: [f32; 3]| hsl_lerp(c, hsl, alpha);
 
         for (_, cell) in cell_iter {
             if let Some(hsl_mod) = self.hsl_mod_fg {
                 let fg = fg_mapper.map(cell.fg, alpha, |c| hsl_lerp(c, hsl_mod));
                 cell.set_fg(fg);
+
+                #[cfg(feature = "underline-color")]
+                if cell.underline_color != Color::Reset {
+                    cell.underline_color = underline_mapper
+                        .map(cell.underline_color, alpha, |c| hsl_lerp(c, hsl_mod));
+                }
             }
             if let Some(hsl_mod) = self.hsl_mod_bg {
                 let bg = bg_mapper.map(cell.bg, alpha, |c| hsl_lerp(c, hsl_mod));
@@ -0,0 +1,145 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::widget::EffectSpan;
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, RefCount, SelectionPolicy, Shader};
+
+/// Catches panics from the wrapped effect during processing. A panicking effect is
+/// marked as done, and the panic message is kept for [Shader::panic_message].
+#[derive(Clone, Debug)]
+pub struct Isolated {
+    effect: Effect,
+    panic_message: Option<String>,
+}
+
+impl Isolated {
+    pub fn new(effect: Effect) -> Self {
+        Self { effect, panic_message: None }
+    }
+}
+
+/// Returns the message of a panic payload, as passed to `panic!`.
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".to_string())
+}
+
+impl Shader for Isolated {
+    fn name(&self) -> &'static str {
+        "isolated"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        if self.panic_message.is_some() {
+            return Some(duration);
+        }
+
+        let effect = &mut self.effect;
+        match catch_unwind(AssertUnwindSafe(|| effect.process(duration, buf, area))) {
+            Ok(overflow) => overflow,
+            Err(payload) => {
+                self.panic_message = Some(payload_message(payload.as_ref()));
+                Some(duration)
+            },
+        }
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.panic_message.is_some() || self.effect.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.effect.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.effect.set_area(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.effect.set_cell_selection(strategy);
+    }
+
+    fn reverse(&mut self) {
+        self.effect.reverse()
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.effect.timer()
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.effect.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.effect.set_input(input);
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.effect.cell_selection()
+    }
+
+    /// Resets the wrapped effect, clearing any caught panic.
+    fn reset(&mut self) {
+        self.panic_message = None;
+        self.effect.reset();
+    }
+
+    fn dirty_region(&self) -> Option<Rect> {
+        self.effect.dirty_region()
+    }
+
+    fn panic_message(&self) -> Option<&str> {
+        self.panic_message.as_deref()
+    }
+
+    fn as_effect_span(&self, offset: Duration) -> EffectSpan {
+        EffectSpan::new(self, offset, vec![self.effect.as_effect_span(offset)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+
+    use super::*;
+
+    #[test]
+    fn test_panic_marks_done() {
+        let area = Rect::new(0, 0, 4, 1);
+        let faulty = fx::effect_fn(0, 100, |frame, _ctx, _cells| {
+            *frame += 1;
+            if *frame == 2 {
+                panic!("frame {frame}");
+            }
+        });
+
+        let mut fx = fx::isolated(faulty);
+        let mut buf = Buffer::empty(area);
+        assert_eq!(fx.process(Duration::from_millis(20), &mut buf, area), None);
+        assert!(fx.running());
+        assert_eq!(fx.panic_message(), None);
+
+        assert_eq!(fx.process(Duration::from_millis(20), &mut buf, area), Some(Duration::from_millis(20)));
+        assert!(fx.done());
+        assert_eq!(fx.panic_message(), Some("frame 2"));
+
+        fx.reset();
+        assert_eq!(fx.panic_message(), None);
+    }
+}
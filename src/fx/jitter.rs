@@ -0,0 +1,168 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::simple_rng::{RangeSampler, SimpleRng};
+use crate::wide_cells::is_narrow;
+use crate::{CellFilter, CellIterator, Duration};
+
+/// Randomly swaps selected cells with nearby cells, for a single frame at a time.
+#[derive(Clone, Debug)]
+pub struct Jitter {
+    timer: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+    rng: SimpleRng,
+    /// the maximum displacement, in cells, along each axis
+    max_offset: u16,
+    /// the number of displacements per cell and second
+    frequency: f32,
+    positions: Vec<Position>,
+}
+
+impl Jitter {
+    pub fn new(max_offset: u16, frequency: f32, timer: EffectTimer) -> Self {
+        Self {
+            timer,
+            area: None,
+            cell_filter: CellFilter::All,
+            rng: SimpleRng::default(),
+            max_offset,
+            frequency,
+            positions: Vec::new(),
+        }
+    }
+
+    /// Picks a random neighbor within `max_offset` cells of `pos`, inside the area.
+    fn displace(&mut self, pos: Position, area: Rect) -> Option<Position> {
+        let max = self.max_offset as i32;
+        let dx = self.rng.gen_range_inclusive(-max..=max);
+        let dy = self.rng.gen_range_inclusive(-max..=max);
+
+        let target = Position::new(
+            u16::try_from(pos.x as i32 + dx).ok()?,
+            u16::try_from(pos.y as i32 + dy).ok()?,
+        );
+
+        (target != pos && area.contains(target)).then_some(target)
+    }
+}
+
+impl Shader for Jitter {
+    fn name(&self) -> &'static str {
+        "jitter"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        if self.max_offset == 0 {
+            return overflow;
+        }
+
+        let chance = self.frequency * duration.as_secs_f32();
+        let area = area.intersection(buf.area);
+
+        let mut positions = core::mem::take(&mut self.positions);
+        positions.clear();
+        positions.extend(self.cell_iter(buf, area).map(|(pos, _)| pos));
+
+        for &pos in &positions {
+            // wide characters stay put, as moving them would break them apart
+            if !self.rng.gen_bool(chance) || !is_narrow(buf, pos) {
+                continue;
+            }
+
+            if let Some(target) = self.displace(pos, area).filter(|&t| is_narrow(buf, t)) {
+                let a = buf[pos].clone();
+                buf[pos] = core::mem::replace(&mut buf[target], a);
+            }
+        }
+
+        self.positions = positions;
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area)
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+    use crate::testing::effect_frames;
+
+    use super::*;
+
+    fn symbols(buf: &Buffer) -> Vec<&str> {
+        let mut symbols: Vec<_> = buf.content.iter().map(|c| c.symbol()).collect();
+        symbols.sort_unstable();
+        symbols
+    }
+
+    #[test]
+    fn test_jitter_swaps_selected_cells() {
+        let content = Buffer::with_lines([
+            "     ",
+            "  a  ",
+            "     ",
+        ]);
+
+        let ticks = [Duration::from_millis(16); 8];
+        let mut fx = fx::jitter(1, 1000.0, 1000)
+            .with_cell_selection(CellFilter::Text);
+
+        let frames = effect_frames(&mut fx, &content, ticks);
+        assert!(frames.iter().all(|f| symbols(f) == symbols(&content)));
+        assert!(frames.iter().any(|f| f != &content));
+        assert!(frames.iter().all(|f| f[(2, 1)].symbol() == " " || f == &content));
+    }
+
+    #[test]
+    fn test_jitter_without_frequency() {
+        let content = Buffer::with_lines(["abc", "def"]);
+        let mut fx = fx::jitter(1, 0.0, 1000);
+
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(16); 4]);
+        assert!(frames.iter().all(|f| f == &content));
+    }
+
+    #[test]
+    fn test_jitter_keeps_wide_chars() {
+        let content = Buffer::with_lines(["a中b", "c文d"]);
+        let mut fx = fx::jitter(1, 1000.0, 1000);
+
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(16); 8]);
+        for frame in frames {
+            assert_eq!(frame[(1, 0)].symbol(), "中");
+            assert_eq!(frame[(1, 1)].symbol(), "文");
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::effect_timer::EffectTimer;
+use crate::fx::invoke_fn;
+use crate::shader::Shader;
+use crate::{ref_count, CellFilter, CellIterator, ColorMapper, Interpolatable, RefCount, ThreadSafetyMarker};
+
+#[cfg(feature = "sendable")]
+type ColorFnSignature = dyn FnMut(Color) -> Color + Send + 'static;
+#[cfg(not(feature = "sendable"))]
+type ColorFnSignature = dyn FnMut(Color) -> Color + 'static;
+
+/// Maps the foreground and background colors of the selected cells with a
+/// user-supplied function. Without a timer, the mapping is applied in full and
+/// the effect never completes; with a timer, the colors are interpolated toward
+/// the mapped colors.
+#[derive(Clone)]
+pub struct MapColors {
+    f: RefCount<ColorFnSignature>,
+    timer: Option<EffectTimer>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl fmt::Debug for MapColors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapColors")
+            .field("timer", &self.timer)
+            .field("area", &self.area)
+            .field("cell_filter", &self.cell_filter)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MapColors {
+    pub fn new<F>(f: F, timer: Option<EffectTimer>) -> Self
+        where F: FnMut(Color) -> Color + ThreadSafetyMarker + 'static
+    {
+        Self {
+            f: ref_count(f),
+            timer,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+}
+
+impl Shader for MapColors {
+    fn name(&self) -> &'static str {
+        "map_colors"
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+
+        let f = &self.f;
+        let transform = |c: Color| {
+            let mapped = invoke_fn!(f, c);
+            c.lerp(&mapped, alpha)
+        };
+
+        for (_, cell) in cell_iter {
+            let fg = fg_mapper.map(cell.fg, alpha, transform);
+            let bg = bg_mapper.map(cell.bg, alpha, transform);
+
+            cell.set_fg(fg);
+            cell.set_bg(bg);
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.timer.is_some_and(|t| t.done())
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        self.timer.as_mut()
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.timer
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        if let Some(timer) = &mut self.timer {
+            timer.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+
+    use crate::{Duration, Interpolation};
+
+    use super::*;
+
+    fn swap_red_blue(c: Color) -> Color {
+        match c {
+            Color::Rgb(r, g, b) => Color::Rgb(b, g, r),
+            c => c,
+        }
+    }
+
+    #[test]
+    fn test_map_colors() {
+        let mut buf = Buffer::with_lines(["x"]);
+        buf.set_style(buf.area, (Color::Rgb(255, 0, 0), Color::Blue));
+
+        let area = buf.area;
+        let mut fx = MapColors::new(swap_red_blue, None);
+        fx.process(Duration::from_millis(16), &mut buf, area);
+
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(0, 0, 255));
+        assert_eq!(buf[(0, 0)].bg, Color::Blue);
+        assert!(!fx.done());
+    }
+
+    #[test]
+    fn test_map_colors_timed() {
+        let timer = EffectTimer::from_ms(100, Interpolation::Linear);
+        let mut fx = MapColors::new(swap_red_blue, Some(timer));
+
+        let mut buf = Buffer::with_lines(["x"]);
+        buf.set_style(buf.area, Color::Rgb(255, 0, 0));
+
+        let area = buf.area;
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(255, 0, 0).lerp(&Color::Rgb(0, 0, 255), 0.5));
+
+        buf.set_style(buf.area, Color::Rgb(255, 0, 0));
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Rgb(0, 0, 255));
+        assert!(fx.done());
+    }
+}
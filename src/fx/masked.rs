@@ -0,0 +1,186 @@
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::color_ext::ToRgbComponents;
+use crate::widget::EffectSpan;
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, Interpolatable, RefCount, SelectionPolicy, Shader};
+
+/// Modulates the intensity of the wrapped effect, cell by cell, with the brightness
+/// of a grayscale mask buffer.
+#[derive(Clone, Debug)]
+pub struct Masked {
+    fx: Effect,
+    mask: RefCount<Buffer>,
+    /// the cells before the wrapped effect was applied, reused across frames
+    original: Vec<Cell>,
+}
+
+impl Masked {
+    pub fn new(fx: Effect, mask: RefCount<Buffer>) -> Self {
+        Self { fx, mask, original: Vec::new() }
+    }
+
+    /// Blends the output of the wrapped effect with the original cells.
+    fn apply_mask(&self, mask: &Buffer, buf: &mut Buffer, area: Rect) {
+        let positions = (area.top()..area.bottom())
+            .flat_map(|y| (area.left()..area.right()).map(move |x| Position::new(x, y)));
+
+        for (pos, original) in positions.zip(self.original.iter()) {
+            let mask_pos = Position::new(
+                mask.area.x + (pos.x - area.x),
+                mask.area.y + (pos.y - area.y),
+            );
+            let intensity = mask.cell(mask_pos).map_or(0.0, brightness);
+
+            blend_cell(original, &mut buf[pos], intensity);
+        }
+    }
+}
+
+/// Blends `from` into `cell`, with `alpha` going from 0.0 (`from`) to 1.0 (`cell`).
+/// Colors are interpolated, while the symbol and modifiers switch at half-way.
+pub(super) fn blend_cell(from: &Cell, cell: &mut Cell, alpha: f32) {
+    if alpha <= 0.0 {
+        *cell = from.clone();
+    } else if alpha < 1.0 {
+        let fg = from.fg.lerp(&cell.fg, alpha);
+        let bg = from.bg.lerp(&cell.bg, alpha);
+        if alpha < 0.5 {
+            *cell = from.clone();
+        }
+        cell.set_fg(fg);
+        cell.set_bg(bg);
+    }
+}
+
+/// The brightness of a mask cell, from 0.0 to 1.0: the luminance of its background,
+/// or of its foreground if brighter and the cell holds a visible symbol.
+fn brightness(cell: &Cell) -> f32 {
+    let luminance = |c: Color| {
+        let (r, g, b) = c.to_rgb();
+        (0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32) / 255.0
+    };
+
+    match cell.symbol().trim().is_empty() {
+        true  => luminance(cell.bg),
+        false => luminance(cell.bg).max(luminance(cell.fg)),
+    }
+}
+
+impl Shader for Masked {
+    fn name(&self) -> &'static str {
+        "masked"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let area = self.fx.area().unwrap_or(area).intersection(buf.area);
+
+        let mut original = core::mem::take(&mut self.original);
+        original.clear();
+        original.extend((area.top()..area.bottom())
+            .flat_map(|y| (area.left()..area.right()).map(move |x| (x, y)))
+            .map(|pos| buf[pos].clone()));
+        self.original = original;
+
+        let overflow = self.fx.process(duration, buf, area);
+
+        #[cfg(not(feature = "sendable"))]
+        let mask = self.mask.borrow();
+        #[cfg(feature = "sendable")]
+        let mask = self.mask.lock().unwrap();
+
+        self.apply_mask(&mask, buf, area);
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.fx.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area);
+    }
+
+    fn set_cell_selection(&mut self, filter: CellFilter) {
+        self.fx.set_cell_selection(filter);
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse();
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.fx.timer()
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.fx.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.fx.set_input(input);
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn reset(&mut self) {
+        self.fx.reset();
+    }
+
+    fn dirty_region(&self) -> Option<Rect> {
+        self.fx.dirty_region()
+    }
+
+    fn as_effect_span(&self, offset: Duration) -> EffectSpan {
+        EffectSpan::new(self, offset, vec![self.fx.as_effect_span(offset)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use crate::testing::effect_frames;
+    use crate::{fx, ref_count, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_masked() {
+        let mut mask = Buffer::with_lines(["  ", "  "]);
+        mask[(0, 0)].set_bg(Color::Rgb(255, 255, 255));
+        mask[(1, 0)].set_bg(Color::Rgb(128, 128, 128));
+        mask[(0, 1)].set_char('#').set_fg(Color::Rgb(255, 255, 255));
+
+        let mut content = Buffer::with_lines(["ab", "cd"]);
+        content.set_style(content.area, Style::default().fg(Color::Rgb(0, 0, 0)));
+
+        let red = Color::Rgb(255, 0, 0);
+        let mut fx = fx::masked(fx::fade_to_fg(red, (100, Interpolation::Linear)), ref_count(mask));
+        let frame = effect_frames(&mut fx, &content, [Duration::from_millis(100)]).remove(0);
+
+        assert_eq!(frame[(0, 0)].fg, red);
+        assert_eq!(frame[(0, 1)].fg, red);
+        assert_eq!(frame[(1, 1)].fg, Color::Rgb(0, 0, 0));
+        assert_eq!(frame[(1, 0)].fg, Color::Rgb(0, 0, 0).lerp(&red, 128.0 / 255.0));
+        assert_eq!(frame[(1, 0)].symbol(), "b");
+    }
+}
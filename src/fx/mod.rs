@@ -1,53 +1,117 @@
+use std::collections::HashMap;
+
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Offset, Size};
-use ratatui::style::Color;
+use ratatui::layout::{Offset, Position, Rect, Size};
+use ratatui::style::{Color, Modifier, Style};
 
-pub use glitch::Glitch;
+pub use glitch::{Glitch, GlitchType};
+pub use quantize_colors::ColorPalette;
+pub use color_filter::Deficiency;
+pub use fade_modifiers::FadeModifiers;
+pub use gradient::{GradientKind, GradientSpec, GradientTarget};
 use ping_pong::PingPong;
 use prolong::{Prolong, ProlongPosition};
 pub use shader_fn::*;
 pub use repeat::RepeatMode;
+pub use ticker::{Ticker, TickerContent};
+pub(crate) use crossfade::Crossfade;
 use slide::SlideCell;
 pub use direction::*;
-use crate::{CellIterator, Duration, RefCount, ThreadSafetyMarker};
+pub use dissolve::DissolveOrder;
+use crate::{ref_count, Anchor, BufferRef, CellFilter, CellIterator, CellSelector, Duration, FxError, Interpolation, RefCount, Sprite, SpritePath, ThreadSafetyMarker};
 use crate::effect::{Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
-use crate::fx::ansi256::Ansi256;
+use crate::pixel_canvas::PixelCanvas;
+use crate::fx::blink::Blink;
+use crate::fx::capture::CaptureInto;
+use crate::fx::quantize_colors::QuantizeColors;
+use crate::fx::color_filter::{ColorFilter, ColorOp};
 use crate::fx::consume_tick::ConsumeTick;
 use crate::fx::containers::{ParallelEffect, SequentialEffect};
+use crate::fx::cursor_trail::CursorTrail;
+use crate::fx::delay_map::DelayMap;
+use crate::fx::dim_behind::DimBehind;
 use crate::fx::dissolve::Dissolve;
 use crate::fx::fade::FadeColors;
+use crate::fx::flash::Flash;
+use crate::fx::focus_ring::FocusRing;
+use crate::fx::gradient::Gradient;
 use crate::fx::hsl_shift::HslShift;
+use crate::fx::jitter::Jitter;
+use crate::fx::map_colors::MapColors;
+use crate::fx::masked::Masked;
+use crate::fx::plasma::Plasma;
+use crate::fx::pulse::Pulse;
+use crate::fx::isolated::Isolated;
 use crate::fx::never_complete::NeverComplete;
+use crate::fx::remap_palette::RemapPalette;
 use crate::fx::repeat::Repeat;
 use crate::fx::resize::ResizeArea;
 use crate::fx::sleep::Sleep;
+use crate::fx::slide_content::SlideContentOut;
+use crate::fx::spotlight::Spotlight;
+use crate::fx::sprite::AnimatedSprite;
+use crate::fx::starfield::Starfield;
+use crate::fx::strobe::Strobe;
+use crate::fx::style_override::StyleOverride;
 use crate::fx::sweep_in::SweepIn;
 use crate::fx::temporary::{IntoTemporaryEffect, TemporaryEffect};
+use crate::fx::time_warp::TimeWarp;
 use crate::fx::translate_buffer::TranslateBuffer;
+use crate::fx::tween_area::TweenArea;
+use crate::fx::wave::Wave;
 
-mod ansi256;
+mod blink;
+mod capture;
+mod color_filter;
 mod consume_tick;
+mod crossfade;
+mod cursor_trail;
 pub(crate) mod containers;
+mod delay_map;
+mod dim_behind;
 mod dissolve;
 mod fade;
+mod fade_modifiers;
+mod flash;
+mod focus_ring;
 mod glitch;
+mod jitter;
+mod gradient;
+mod map_colors;
+mod masked;
 mod never_complete;
 mod ping_pong;
+mod plasma;
+mod pulse;
+mod remap_palette;
 mod repeat;
 mod resize;
 mod sleep;
+mod slide_content;
+mod spotlight;
+mod sprite;
+mod starfield;
+mod strobe;
+mod style_override;
 mod sweep_in;
 mod temporary;
+mod ticker;
+mod time_warp;
 mod translate;
 mod translate_buffer;
+mod tween_area;
+mod wave;
 mod hsl_shift;
+mod isolated;
 mod shader_fn;
 mod slide;
 mod sliding_window_alpha;
 mod offscreen_buffer;
+pub mod palette;
 mod prolong;
 mod direction;
+mod quantize_colors;
 
 /// Creates a custom effect using a user-defined function.
 ///
@@ -130,6 +194,64 @@ where
         .into_effect()
 }
 
+/// Creates a custom effect like [effect_fn], but with a state that is created by `init`
+/// on the first call to [process](Shader::process), from the area the effect is rendered
+/// to. This allows area-sized state, such as per-cell data, to be allocated lazily
+/// rather than guessed up front.
+///
+/// Resetting the effect discards the state; it is created anew by the next call to
+/// `process`, which is why `init` must be `Clone`.
+///
+/// # Arguments
+/// * `init` - Creates the state, given the area of the effect.
+/// * `timer` - An `EffectTimer` instance to control the duration and timing of the effect.
+/// * `f` - A closure that defines the custom effect, as with [effect_fn].
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // each cell lights up after its own random delay
+/// fx::effect_fn_with_setup(
+///     |area| {
+///         let mut rng = tachyonfx::SimpleRng::default();
+///         (0..area.area()).map(|_| rng.gen_f32()).collect::<Vec<_>>()
+///     },
+///     (1000, Interpolation::Linear),
+///     |delays, ctx, cell_iter| {
+///         let alpha = ctx.alpha();
+///         for (i, (_pos, cell)) in cell_iter.enumerate() {
+///             if delays.get(i).is_some_and(|d| *d < alpha) {
+///                 cell.set_fg(Color::White);
+///             }
+///         }
+///     },
+/// );
+/// ```
+pub fn effect_fn_with_setup<I, F, S, T>(init: I, timer: T, mut f: F) -> Effect
+where
+    I: FnOnce(Rect) -> S + Clone + Send + 'static,
+    S: Clone + Send + 'static,
+    T: Into<EffectTimer>,
+    F: FnMut(&mut S, ShaderFnContext, CellIterator) + ThreadSafetyMarker + 'static,
+{
+    let code = move |(init, state): &mut (Option<I>, Option<S>), ctx: ShaderFnContext, cells: CellIterator| {
+        let area = ctx.area;
+        let state = state.get_or_insert_with(|| init.take().expect("setup runs once per reset")(area));
+        f(state, ctx, cells);
+    };
+
+    ShaderFn::builder()
+        .name("shader_fn")
+        .state((Some(init), None))
+        .code(ShaderFnSignature::new_iter(code))
+        .timer(timer)
+        .build()
+        .into_effect()
+}
+
 /// Creates a custom effect using a user-defined function that operates on a buffer.
 ///
 /// This function allows you to define custom effects by providing a closure that will be called
@@ -162,22 +284,186 @@ where
         .into_effect()
 }
 
+/// Creates a custom effect like [effect_fn_buf], which also retains a copy of its area
+/// after each frame. The copy is available to the closure as
+/// [ShaderFnContext::previous_frame], for temporal effects such as trails or motion
+/// detection.
+///
+/// # Arguments
+/// * `state` - An initial state that will be passed to the closure on each invocation.
+/// * `timer` - An `EffectTimer` instance to control the duration and timing of the effect.
+/// * `f` - A closure that defines the custom effect, as with [effect_fn_buf].
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolatable};
+///
+/// // fading trails behind anything moving through the area
+/// fx::effect_fn_buf_with_previous((), 5000, |_, ctx, buf| {
+///     let Some(previous) = ctx.previous_frame else { return };
+///     for pos in ctx.area.positions().filter(|pos| previous.area.contains(*pos)) {
+///         let fg = previous[pos].fg.lerp(&Color::Black, 0.2);
+///         if buf[pos].symbol() == " " {
+///             buf[pos].set_fg(fg).set_symbol(previous[pos].symbol());
+///         }
+///     }
+/// });
+/// ```
+pub fn effect_fn_buf_with_previous<F, S, T>(state: S, timer: T, f: F) -> Effect
+where
+    S: Clone + Send + 'static,
+    T: Into<EffectTimer>,
+    F: FnMut(&mut S, ShaderFnContext, &mut Buffer) + ThreadSafetyMarker + 'static,
+{
+    ShaderFn::builder()
+        .name("shader_fn_buf")
+        .state(state)
+        .code(ShaderFnSignature::new_buffer(f))
+        .timer(timer)
+        .track_previous_frame(true)
+        .build()
+        .into_effect()
+}
+
+/// Creates a custom effect using a user-defined function that operates on a buffer, like
+/// [effect_fn_buf], but also receives a [CellSelector] for the effect's cell filter and
+/// area. This spares buffer-level effects from evaluating [ShaderFnContext::filter]
+/// themselves. Without a cell filter, the selector selects every cell in the area.
+///
+/// # Arguments
+/// * `state` - An initial state that will be passed to the closure on each invocation.
+/// * `timer` - An `EffectTimer` instance to control the duration and timing of the effect.
+/// * `f` - A closure that defines the custom effect. The closure takes four parameters:
+///   * `state`: A mutable reference to the state provided during the creation of the effect.
+///   * `context`: A `ShaderFnContext` instance containing timing and area information.
+///   * `buffer`: A mutable reference to the terminal buffer.
+///   * `selector`: Determines whether a cell is selected by the cell filter.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::layout::Position;
+/// use tachyonfx::{fx, CellFilter};
+///
+/// // mirrors the selected cells of each row
+/// fx::effect_fn_filtered((), 500, |_, ctx, buf, selector| {
+///     let area = ctx.area;
+///     for y in area.top()..area.bottom() {
+///         for x in area.left()..area.left() + area.width / 2 {
+///             let (a, b) = (Position::new(x, y), Position::new(area.right() - 1 - (x - area.left()), y));
+///             if selector.is_valid(a, &buf[a]) && selector.is_valid(b, &buf[b]) {
+///                 let cell = buf[a].clone();
+///                 buf[a] = buf[b].clone();
+///                 buf[b] = cell;
+///             }
+///         }
+///     }
+/// }).with_cell_selection(CellFilter::Text);
+/// ```
+pub fn effect_fn_filtered<F, S, T>(state: S, timer: T, mut f: F) -> Effect
+where
+    S: Clone + Send + 'static,
+    T: Into<EffectTimer>,
+    F: FnMut(&mut S, ShaderFnContext, &mut Buffer, &CellSelector) + ThreadSafetyMarker + 'static,
+{
+    let code = move |state: &mut S, ctx: ShaderFnContext, buf: &mut Buffer| {
+        let filter = ctx.filter.clone().unwrap_or_default();
+        let selector = CellSelector::with_buffer(ctx.area, filter, Some(buf));
+        f(state, ctx, buf, &selector);
+    };
+
+    ShaderFn::builder()
+        .name("shader_fn_filtered")
+        .state(state)
+        .code(ShaderFnSignature::new_buffer(code))
+        .timer(timer)
+        .build()
+        .into_effect()
+}
+
+/// Creates a custom effect using a user-defined function that draws onto a [PixelCanvas].
+///
+/// The canvas covers the effect area at 2x4 braille pixels per cell, and is cleared
+/// before each invocation of the closure. After the closure returns, cells with any set
+/// pixels are replaced by their braille pattern; all other cells are left untouched.
+/// Any cell filter applies to the rendered cells.
+///
+/// # Arguments
+/// * `state` - An initial state that will be passed to the closure on each invocation.
+/// * `timer` - An `EffectTimer` instance to control the duration and timing of the effect.
+/// * `f` - A closure that defines the custom effect. The closure takes three parameters:
+///   * `state`: A mutable reference to the state provided during the creation of the effect.
+///   * `context`: A `ShaderFnContext` instance containing timing and area information.
+///   * `canvas`: A mutable reference to the pixel canvas.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // a dot travelling along a sine wave
+/// fx::effect_fn_pixels((), (2000, Interpolation::Linear), |_, ctx, canvas| {
+///     let x = (ctx.alpha() * (canvas.width() - 1) as f32) as u16;
+///     let y = (canvas.height() as f32 * (0.5 + 0.4 * (x as f32 * 0.2).sin())) as u16;
+///     canvas.set_colored(x, y, Color::LightCyan);
+/// });
+/// ```
+///
+/// [PixelCanvas]: crate::pixel_canvas::PixelCanvas
+pub fn effect_fn_pixels<F, S, T>(state: S, timer: T, mut f: F) -> Effect
+where
+    S: Clone + Send + 'static,
+    T: Into<EffectTimer>,
+    F: FnMut(&mut S, ShaderFnContext, &mut PixelCanvas) + ThreadSafetyMarker + 'static,
+{
+    let code = move |(state, canvas): &mut (S, PixelCanvas), ctx: ShaderFnContext, buf: &mut Buffer| {
+        canvas.resize(ctx.area);
+        let filter = ctx.filter.clone();
+        f(state, ctx, canvas);
+        canvas.render_selected(buf, filter);
+    };
+
+    ShaderFn::builder()
+        .name("shader_fn_pixels")
+        .state((state, PixelCanvas::default()))
+        .code(ShaderFnSignature::new_buffer(code))
+        .timer(timer)
+        .build()
+        .into_effect()
+}
+
 /// changes the hue, saturation, and lightness of the foreground and background colors.
+///
+/// # Panics
+/// If neither `hsl_fg_change` nor `hsl_bg_change` is given; see [try_hsl_shift].
 pub fn hsl_shift<T: Into<EffectTimer>>(
     hsl_fg_change: Option<[f32; 3]>,
     hsl_bg_change: Option<[f32; 3]>,
     timer: T,
 ) -> Effect {
+    try_hsl_shift(hsl_fg_change, hsl_bg_change, timer).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Like [hsl_shift], but returns an [FxError::NoColorTarget] instead of panicking
+/// when neither `hsl_fg_change` nor `hsl_bg_change` is given.
+pub fn try_hsl_shift<T: Into<EffectTimer>>(
+    hsl_fg_change: Option<[f32; 3]>,
+    hsl_bg_change: Option<[f32; 3]>,
+    timer: T,
+) -> Result<Effect, FxError> {
     if hsl_fg_change.is_none() && hsl_bg_change.is_none() {
-        panic!("At least one of the foreground or background color must be changed");
+        return Err(FxError::NoColorTarget { effect: "hsl_shift" });
     }
 
-    HslShift::builder()
+    Ok(HslShift::builder()
         .maybe_hsl_mod_fg(hsl_fg_change)
         .maybe_hsl_mod_bg(hsl_bg_change)
         .timer(timer.into())
         .build()
-        .into_effect()
+        .into_effect())
 }
 
 /// Shifts the foreground color by the specified hue, saturation, and lightness
@@ -189,9 +475,150 @@ pub fn hsl_shift_fg<T: Into<EffectTimer>>(
     hsl_shift(Some(hsl_fg_change), None, timer)
 }
 
+/// Gradually desaturates the foreground and background colors to grayscale
+/// over the specified duration.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, Interpolation};
+///
+/// let fx = fx::grayscale((500, Interpolation::QuadOut));
+/// ```
+pub fn grayscale<T: Into<EffectTimer>>(timer: T) -> Effect {
+    ColorFilter::new(ColorOp::Grayscale, timer.into()).into_effect()
+}
+
+/// Gradually tints the foreground and background colors with a sepia tone
+/// over the specified duration.
+pub fn sepia<T: Into<EffectTimer>>(timer: T) -> Effect {
+    ColorFilter::new(ColorOp::Sepia, timer.into()).into_effect()
+}
+
+/// Gradually inverts the foreground and background colors over the specified
+/// duration.
+pub fn invert<T: Into<EffectTimer>>(timer: T) -> Effect {
+    ColorFilter::new(ColorOp::Invert, timer.into()).into_effect()
+}
+
+/// Simulates how the colors are perceived with the given color vision deficiency.
+/// The effect never completes, making it suitable as a post-process effect for
+/// previewing the accessibility of a color scheme.
+///
+/// # Example
+/// ```
+/// use tachyonfx::fx::{self, Deficiency};
+///
+/// let fx = fx::simulate_color_vision(Deficiency::Deuteranopia);
+/// ```
+pub fn simulate_color_vision(deficiency: Deficiency) -> Effect {
+    let timer = EffectTimer::from_ms(0, Interpolation::Linear);
+    never_complete(ColorFilter::new(ColorOp::ColorVision(deficiency), timer).into_effect())
+}
+
+/// Maps the foreground and background colors of the selected cells with `f`. The
+/// effect never completes, making it a cheap post-process for global recoloring,
+/// e.g. desaturating unfocused panes. Mapped colors are cached between cells.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, HslConvertable};
+/// use ratatui::style::Color;
+///
+/// let desaturate = fx::map_colors(|c: Color| {
+///     let (h, s, l) = c.to_hsl();
+///     Color::from_hsl(h, s * 0.3, l)
+/// });
+/// ```
+pub fn map_colors<F>(f: F) -> Effect
+    where F: FnMut(Color) -> Color + ThreadSafetyMarker + 'static
+{
+    MapColors::new(f, None).into_effect()
+}
+
+/// Gradually transitions the foreground and background colors of the selected cells
+/// to the colors mapped by `f`, over the specified duration.
+///
+/// Refer to [`map_colors`](fn.map_colors.html) for more information.
+pub fn map_colors_timed<F, T>(f: F, timer: T) -> Effect
+    where F: FnMut(Color) -> Color + ThreadSafetyMarker + 'static,
+          T: Into<EffectTimer>,
+{
+    MapColors::new(f, Some(timer.into())).into_effect()
+}
+
+/// Gradually transitions the foreground and background colors found in `mapping`
+/// to their mapped target colors. Colors without a mapping are left untouched.
+/// Useful for animating theme switches, e.g. between light and dark themes.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// let light_to_dark = HashMap::from([
+///     (Color::from_u32(0xfafafa), Color::from_u32(0x1e1e2e)),
+///     (Color::from_u32(0x4c4f69), Color::from_u32(0xcdd6f4)),
+/// ]);
+///
+/// let fx = fx::remap_palette(light_to_dark, (750, Interpolation::SineInOut));
+/// ```
+pub fn remap_palette<T: Into<EffectTimer>>(
+    mapping: HashMap<Color, Color>,
+    timer: T,
+) -> Effect {
+    RemapPalette::new(mapping, timer.into()).into_effect()
+}
+
+/// Paints a moving multi-stop linear or radial gradient onto the foreground and/or
+/// background colors of the selected cells. The gradient scrolls across the area
+/// `speed` times over the duration of the effect; combine with [repeating] for a
+/// continuous animation, e.g. shimmering headers.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx::{self, GradientKind, GradientSpec};
+///
+/// let shimmer = GradientSpec::builder()
+///     .stops(vec![
+///         (0.0, Color::from_u32(0x585b70)),
+///         (0.5, Color::from_u32(0xf5e0dc)),
+///         (1.0, Color::from_u32(0x585b70)),
+///     ])
+///     .kind(GradientKind::Linear { angle: 30.0 })
+///     .build();
+///
+/// let fx = fx::repeating(fx::gradient(shimmer, 2000));
+/// ```
+pub fn gradient<T: Into<EffectTimer>>(spec: GradientSpec, timer: T) -> Effect {
+    Gradient::new(spec, timer.into()).into_effect()
+}
+
 /// Returns an effect that downsamples to 256 color mode.
 pub fn term256_colors() -> Effect {
-    Ansi256::default().into_effect()
+    quantize_colors(ColorPalette::Ansi256)
+}
+
+/// Returns an effect that maps all foreground and background colors to the nearest
+/// color in the given palette. Useful for forcing output into a known palette, e.g.
+/// the colors of the terminal theme, or for simulating terminals with limited color
+/// support.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx::{self, ColorPalette};
+///
+/// let ansi16 = fx::quantize_colors(ColorPalette::Ansi16);
+/// let theme = fx::quantize_colors(ColorPalette::Custom(vec![
+///     Color::from_u32(0x282a36),
+///     Color::from_u32(0xff79c6),
+///     Color::from_u32(0x50fa7b),
+/// ]));
+/// ```
+pub fn quantize_colors(palette: ColorPalette) -> Effect {
+    QuantizeColors::new(palette).into_effect()
 }
 
 /// Repeat the effect indefinitely or for a specified number of times or duration.
@@ -204,6 +631,139 @@ pub fn ping_pong(effect: Effect) -> Effect {
     PingPong::new(effect).into_effect()
 }
 
+/// Blinks the cells selected by the filter, hiding them every other interval with the
+/// `HIDDEN` modifier. Starts out visible, and never completes.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, CellFilter, Duration};
+///
+/// let cursor_blink = fx::blink(Duration::from_millis(530), CellFilter::Text);
+/// ```
+pub fn blink(interval: Duration, filter: CellFilter) -> Effect {
+    Blink::new(interval, filter).into_effect()
+}
+
+/// Smoothly tints the foreground color towards `color` and back, following a sine
+/// wave which peaks halfway through each period. Never completes.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, CellFilter, Duration};
+///
+/// let attention = fx::pulse(Color::Yellow, Duration::from_millis(1200))
+///     .with_cell_selection(CellFilter::Text);
+/// ```
+pub fn pulse(color: Color, period: Duration) -> Effect {
+    Pulse::new(color, period).into_effect()
+}
+
+/// Paints an animated plasma field onto the background of the selected cells, as a
+/// demoscene-style background. The field, a sum of sines, is mapped through the colors
+/// of the palette, interpolating between neighboring colors and wrapping around from
+/// the last color to the first. Never completes.
+///
+/// # Arguments
+/// * `palette` - The colors of the field; at least one is required for any output.
+/// * `speed` - The speed of the animation; the field loops every `TAU / speed` seconds.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx;
+///
+/// let palette = vec![
+///     Color::from_u32(0x1e1e2e),
+///     Color::from_u32(0x89b4fa),
+///     Color::from_u32(0xcba6f7),
+/// ];
+/// let background = fx::plasma(palette, 0.5);
+/// ```
+pub fn plasma(palette: Vec<Color>, speed: f32) -> Effect {
+    Plasma::new(palette, speed).into_effect()
+}
+
+/// Renders parallax-scrolling stars onto the blank cells of the area, for menu and
+/// splash screen backgrounds. Stars in farther layers are dimmer and scroll slower;
+/// text rendered to the area is left untouched. Never completes.
+///
+/// # Arguments
+/// * `depth_layers` - The number of star layers.
+/// * `speed` - How fast the nearest layer scrolls, in columns per second. Positive
+///   speeds scroll the stars to the left.
+///
+/// # Example
+/// ```
+/// use tachyonfx::fx;
+///
+/// let background = fx::starfield(3, 8.0);
+/// ```
+pub fn starfield(depth_layers: u8, speed: f32) -> Effect {
+    Starfield::new(depth_layers, speed).into_effect()
+}
+
+/// Displaces the characters of each column vertically, by whole rows, following a
+/// sine wave traveling along the area. Never completes.
+///
+/// # Arguments
+/// * `amplitude` - The maximum displacement, in rows.
+/// * `wavelength` - The length of a full wave, in columns.
+/// * `speed` - How fast the wave travels, in columns per second. Positive speeds
+///   travel right, negative speeds left.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::fx;
+///
+/// // a floating title, with a row of space above and below
+/// let title = fx::wave(1, 16.0, 12.0).with_area(Rect::new(10, 1, 30, 3));
+/// ```
+pub fn wave(amplitude: u16, wavelength: f32, speed: f32) -> Effect {
+    Wave::new(amplitude, wavelength, speed).into_effect()
+}
+
+/// Continuously scrolls text, or the contents of a shared buffer, through the effect
+/// area at `speed` cells per second, wrapping around when the content has passed.
+/// The effect never completes. Use [Ticker] directly to leave a gap between
+/// repetitions of the content.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::fx::{self, Direction};
+///
+/// let status_bar = fx::ticker("connected to 3 peers · syncing ", 6.0, Direction::RightToLeft)
+///     .with_area(Rect::new(0, 23, 80, 1));
+/// ```
+pub fn ticker<C: Into<TickerContent>>(content: C, speed: f32, direction: Direction) -> Effect {
+    Ticker::new(content, speed, direction).into_effect()
+}
+
+/// Remaps the time passed to an effect along an easing curve, stretched over the
+/// total duration of the effect. This eases a composed effect as a whole, e.g. a
+/// sequence of effects, rather than each of its leaf effects separately.
+///
+/// Time only moves forward: curves overshooting backwards, such as `BackIn`, hold
+/// the effect until the curve catches up. Effects without a duration are passed
+/// the time unchanged.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// let intro = fx::sequence(&[
+///     fx::fade_from_fg(Color::Black, 400),
+///     fx::sweep_in(fx::Direction::LeftToRight, 10, 0, Color::Black, 600),
+/// ]);
+/// let eased = fx::time_warp(intro, Interpolation::SineInOut);
+/// ```
+pub fn time_warp(effect: Effect, curve: Interpolation) -> Effect {
+    TimeWarp::new(effect, curve).into_effect()
+}
+
 /// Repeat the effect indefinitely.
 pub fn repeating(effect: Effect) -> Effect {
     repeat(effect, repeat::RepeatMode::Forever)
@@ -212,10 +772,10 @@ pub fn repeating(effect: Effect) -> Effect {
 /// Creates an effect that sweeps out from a specified color with optional randomness.
 ///
 /// Refer to [`sweep_in`](fn.sweep_in.html) for more information.
-pub fn sweep_out<T: Into<EffectTimer>, C: Into<Color>>(
+pub fn sweep_out<T: Into<EffectTimer>, G: Into<GradientLength>, C: Into<Color>, J: Into<SweepJitter>>(
     direction: Direction,
-    gradient_length: u16,
-    randomness: u16,
+    gradient_length: G,
+    randomness: J,
     faded_color: C,
     timer: T,
 ) -> Effect {
@@ -239,9 +799,12 @@ pub fn sweep_out<T: Into<EffectTimer>, C: Into<Color>>(
 ///
 /// * `gradient_length` - The length of the gradient transition in cells. This determines
 ///   how smooth the transition is between the faded color and the original content.
+///   Pass an `f32` to specify the length as a fraction of the area instead, so that the
+///   transition scales with the size of the area.
 ///
 /// * `randomness` - The maximum random offset applied to each column or row of the effect.
 ///   Higher values create a more irregular, "noisy" transition. Set to 0 for a uniform sweep.
+///   Pass a [`SweepJitter`] to control the distribution and seed of the offsets.
 ///
 /// * `faded_color` - The color from which the content sweeps in.
 ///
@@ -286,17 +849,62 @@ pub fn sweep_out<T: Into<EffectTimer>, C: Into<Color>>(
 /// # See Also
 ///
 /// * [`sweep_out`](fn.sweep_out.html) - For the reverse effect.
-pub fn sweep_in<T: Into<EffectTimer>, C: Into<Color>>(
+pub fn sweep_in<T: Into<EffectTimer>, G: Into<GradientLength>, C: Into<Color>, J: Into<SweepJitter>>(
     direction: Direction,
-    gradient_length: u16,
-    randomness: u16,
+    gradient_length: G,
+    randomness: J,
     faded_color: C,
     timer: T,
 ) -> Effect {
-    SweepIn::new(direction, gradient_length, randomness, faded_color.into(), timer.into())
+    sweep_in_gradient(direction, gradient_length, randomness, vec![(0.0, faded_color.into())], timer)
+}
+
+/// Creates an effect that sweeps in from a multi-color gradient with optional randomness.
+///
+/// Works like [`sweep_in`](fn.sweep_in.html), but the leading edge of the sweep is
+/// colored by a list of `(position, color)` gradient stops instead of a single faded
+/// color. Positions range from 0.0 (fully faded) to 1.0 (the original content); the
+/// last stop fades into the original content.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, EffectTimer, Interpolation};
+/// use tachyonfx::fx::Direction;
+/// use ratatui::style::Color;
+///
+/// let sweep_effect = fx::sweep_in_gradient(
+///     Direction::LeftToRight,
+///     15,
+///     0,
+///     vec![(0.0, Color::Black), (0.5, Color::from_u32(0x8839ef))],
+///     EffectTimer::from_ms(1000, Interpolation::Linear)
+/// );
+/// ```
+pub fn sweep_in_gradient<T: Into<EffectTimer>, G: Into<GradientLength>, J: Into<SweepJitter>>(
+    direction: Direction,
+    gradient_length: G,
+    randomness: J,
+    faded_colors: Vec<(f32, Color)>,
+    timer: T,
+) -> Effect {
+    SweepIn::new(direction, gradient_length.into(), randomness.into(), faded_colors, timer.into())
         .into_effect()
 }
 
+/// Creates an effect that sweeps out to a multi-color gradient with optional randomness.
+///
+/// Refer to [`sweep_in_gradient`](fn.sweep_in_gradient.html) for more information.
+pub fn sweep_out_gradient<T: Into<EffectTimer>, G: Into<GradientLength>, J: Into<SweepJitter>>(
+    direction: Direction,
+    gradient_length: G,
+    randomness: J,
+    faded_colors: Vec<(f32, Color)>,
+    timer: T,
+) -> Effect {
+    sweep_in_gradient(direction.flipped(), gradient_length, randomness, faded_colors, timer)
+        .reversed()
+}
+
 /// Creates an effect that slides terminal cells in from a specified direction with a gradient.
 ///
 /// This function creates a sliding effect that moves terminal cells in from a specified direction.
@@ -305,7 +913,10 @@ pub fn sweep_in<T: Into<EffectTimer>, C: Into<Color>>(
 ///
 /// # Arguments
 /// * `direction` - The direction from which the cells slide in.
-/// * `gradient_length` - The length of the gradient used for the sliding effect.
+/// * `gradient_length` - The length of the gradient used for the sliding effect, in cells,
+///   or as a fraction of the area when given as an `f32`.
+/// * `randomness` - The maximum random offset applied to each column or row, or a
+///   [`SweepJitter`] controlling the distribution and seed of the offsets.
 /// * `color_behind_cells` - The color behind the sliding cells.
 /// * `timer` - An `EffectTimer` instance to control the duration and timing of the effect.
 ///
@@ -330,10 +941,10 @@ pub fn sweep_in<T: Into<EffectTimer>, C: Into<Color>>(
 ///
 /// This example creates a sliding effect that moves cells in from the left to the right
 /// with a gradient length of 10 and a black background color over two seconds.
-pub fn slide_in<T: Into<EffectTimer>, C: Into<Color>>(
+pub fn slide_in<T: Into<EffectTimer>, G: Into<GradientLength>, C: Into<Color>, J: Into<SweepJitter>>(
     direction: Direction,
-    gradient_length: u16,
-    randomness: u16,
+    gradient_length: G,
+    randomness: J,
     color_behind_cells: C,
     timer: T,
 ) -> Effect {
@@ -349,7 +960,10 @@ pub fn slide_in<T: Into<EffectTimer>, C: Into<Color>>(
 ///
 /// # Arguments
 /// * `direction` - The direction in which the cells slide out.
-/// * `gradient_length` - The length of the gradient used for the sliding effect.
+/// * `gradient_length` - The length of the gradient used for the sliding effect, in cells,
+///   or as a fraction of the area when given as an `f32`.
+/// * `randomness` - The maximum random offset applied to each column or row, or a
+///   [`SweepJitter`] controlling the distribution and seed of the offsets.
 /// * `color_behind_cells` - The color behind the sliding cells.
 /// * `timer` - An `EffectTimer` instance to control the duration and timing of the effect.
 ///
@@ -369,10 +983,10 @@ pub fn slide_in<T: Into<EffectTimer>, C: Into<Color>>(
 ///
 /// This example creates a sliding effect that moves cells out from the top to the bottom
 /// with a gradient length of 10 and a black background color over two seconds.
-pub fn slide_out<T: Into<EffectTimer>, C: Into<Color>>(
+pub fn slide_out<T: Into<EffectTimer>, G: Into<GradientLength>, C: Into<Color>, J: Into<SweepJitter>>(
     direction: Direction,
-    gradient_length: u16,
-    randomness: u16,
+    gradient_length: G,
+    randomness: J,
     color_behind_cells: C,
     timer: T,
 ) -> Effect {
@@ -388,12 +1002,42 @@ pub fn slide_out<T: Into<EffectTimer>, C: Into<Color>>(
         .timer(timer)
         .color_behind_cell(color_behind_cells.into())
         .gradient_length(gradient_length)
-        .randomness_extent(randomness)
+        .jitter(randomness)
         .direction(direction)
         .build()
         .into_effect()
 }
 
+/// Slides the already rendered content of the effect area out in the specified direction,
+/// revealing the given background color.
+///
+/// The content of the area is captured on the first frame, so the snapshot keeps moving
+/// out even as the application renders new content beneath the effect. Unlike `slide_out`,
+/// which dissolves cells with a gradient, the content moves as a whole, similar to
+/// translating an aux buffer with `translate_buf`, but without having to manage one.
+///
+/// # Arguments
+/// * `direction` - The direction in which the content moves.
+/// * `color_behind` - The background color revealed behind the content.
+/// * `timer` - Controls the duration and interpolation of the effect.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+/// use tachyonfx::fx::Direction;
+///
+/// fx::slide_content_out(Direction::UpToDown, Color::Black, (400, Interpolation::QuadIn));
+/// ```
+pub fn slide_content_out<T: Into<EffectTimer>, C: Into<Color>>(
+    direction: Direction,
+    color_behind: C,
+    timer: T,
+) -> Effect {
+    SlideContentOut::new(direction, color_behind.into(), timer.into()).into_effect()
+}
+
 /// Translates an effect by a specified amount over a specified duration.
 ///
 /// This function creates a translation effect that moves an existing effect by a given
@@ -434,9 +1078,32 @@ pub fn translate<T: Into<EffectTimer>>(
     translate::Translate::new(fx, translate_by, timer.into()).into_effect()
 }
 
-/// Creates an effect that translates the contents of an auxiliary buffer onto the main buffer.
+/// Creates an effect that moves the effect area by fractions of its width and height,
+/// e.g. `(0.5, 0.0)` moves it right by half its width. Unlike [translate], transitions
+/// cover the same relative distance regardless of the size of the terminal.
 ///
-/// This function creates a `TranslateBuffer` shader, which efficiently translates pre-rendered
+/// The fractions are resolved against the area of the first processed frame.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // slide the area out to the left, past its own width
+/// fx::translate_rel(Some(fx::fade_to_fg(Color::Black, 500)), (-1.0, 0.0), (500, Interpolation::QuadIn));
+/// ```
+pub fn translate_rel<T: Into<EffectTimer>>(
+    fx: Option<Effect>,
+    translate_by: (f32, f32),
+    timer: T,
+) -> Effect {
+    translate::Translate::new_relative(fx, translate_by, timer.into()).into_effect()
+}
+
+/// Creates an effect that translates the contents of an auxiliary buffer onto the main buffer.
+///
+/// This function creates a `TranslateBuffer` shader, which efficiently translates pre-rendered
 /// content without re-rendering it on every frame. It's particularly useful for large or complex
 /// content that doesn't change frequently.
 ///
@@ -446,17 +1113,71 @@ pub fn translate<T: Into<EffectTimer>>(
 /// * `timer` - Specifies the duration and interpolation of the translation effect. Can be any type
 ///   that implements `Into<EffectTimer>`.
 /// * `aux_buffer` - A shared reference to the auxiliary buffer containing the pre-rendered content
-///   to be translated.
+///   to be translated. Pass a weak reference, from [ref_count_weak](crate::ref_count_weak), to avoid
+///   keeping the buffer alive; the effect renders nothing once the buffer is dropped.
 ///
 /// # Returns
 ///
 /// Returns an `Effect` that can be used with other effects or applied directly to a buffer.
-pub fn translate_buf<T: Into<EffectTimer>>(
+pub fn translate_buf<T: Into<EffectTimer>, B: Into<BufferRef>>(
+    translate_by: Offset,
+    aux_buffer: B,
+    timer: T,
+) -> Effect {
+    TranslateBuffer::new(aux_buffer.into(), translate_by, timer.into()).into_effect()
+}
+
+/// Creates an effect that scrolls the contents of an auxiliary buffer in place, within
+/// the area of the auxiliary buffer. Content exiting one edge re-enters from the opposite
+/// edge, so translating by the full width or height of the buffer completes a seamless loop.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Offset, Rect};
+/// use tachyonfx::*;
+///
+/// // a marquee along the bottom row of an 80x24 screen
+/// let ticker = ref_count(Buffer::empty(Rect::new(0, 23, 80, 1)));
+/// fx::repeating(fx::translate_buf_wrapping(Offset { x: -80, y: 0 }, ticker, 8000));
+/// ```
+pub fn translate_buf_wrapping<T: Into<EffectTimer>, B: Into<BufferRef>>(
+    translate_by: Offset,
+    aux_buffer: B,
+    timer: T,
+) -> Effect {
+    TranslateBuffer::new(aux_buffer.into(), translate_by, timer.into())
+        .with_wrapping()
+        .into_effect()
+}
+
+/// Creates an effect that translates the contents of an auxiliary buffer onto the main
+/// buffer, rendering vertical motion in half-cell increments.
+///
+/// Works like [translate_buf], but on frames where the content lies halfway between two
+/// rows, the colors of adjacent rows are composed using `▀` glyphs. This doubles the
+/// vertical resolution of the motion, resulting in noticeably smoother scrolling. Cells
+/// containing text can't be split, and are rendered at whole-row positions.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Offset, Rect};
+/// use tachyonfx::*;
+///
+/// let aux_buffer = ref_count(Buffer::empty(Rect::new(0, 0, 40, 10)));
+/// fx::translate_buf_smooth(Offset { x: 0, y: 12 }, aux_buffer, (800, Interpolation::QuadOut));
+/// ```
+pub fn translate_buf_smooth<T: Into<EffectTimer>, B: Into<BufferRef>>(
     translate_by: Offset,
-    aux_buffer: RefCount<Buffer>,
+    aux_buffer: B,
     timer: T,
 ) -> Effect {
-    TranslateBuffer::new(aux_buffer, translate_by, timer.into()).into_effect()
+    TranslateBuffer::new(aux_buffer.into(), translate_by, timer.into())
+        .with_half_blocks()
+        .into_effect()
 }
 
 /// Resizes the area of the wrapped effect to the specified dimensions over a specified duration.
@@ -499,6 +1220,75 @@ pub fn resize_area<T: Into<EffectTimer>>(
     ResizeArea::new(fx, initial_size, timer.into()).into_effect()
 }
 
+/// Creates an effect that resizes the area of a wrapped effect, keeping the given
+/// anchor point of the area fixed.
+///
+/// This works like [resize_area], which is anchored to the center, but lets the area
+/// grow from or shrink toward any corner or edge, e.g. for popups attached to the
+/// bottom of the screen.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::layout::Size;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let timer = EffectTimer::from_ms(300, Interpolation::QuadOut);
+/// let effect = fx::fade_from_fg(Color::Black, timer);
+/// fx::resize_area_anchored(Some(effect), Size::new(0, 1), Anchor::Bottom, timer);
+/// ```
+pub fn resize_area_anchored<T: Into<EffectTimer>>(
+    fx: Option<Effect>,
+    initial_size: Size,
+    anchor: Anchor,
+    timer: T,
+) -> Effect {
+    ResizeArea::new(fx, initial_size, timer.into())
+        .with_anchor(anchor)
+        .into_effect()
+}
+
+/// Creates an effect that interpolates the area of an effect between two rects.
+///
+/// Both the position and size of the area are tweened, generalizing `translate` (position
+/// only) and `resize_area` (size only). Intermediate areas are computed with fractional
+/// precision and only snapped to the cell grid when applied.
+///
+/// # Arguments
+/// * `fx` - An optional `Effect`, receives the tweened area.
+/// * `from` - The area at the start of the effect.
+/// * `to` - The area at the end of the effect.
+/// * `timer` - An `EffectTimer` instance to control the duration and timing of the tween.
+///
+/// # Returns
+/// * An `Effect` instance that applies the tweened area to the given effect or as a
+///   standalone effect.
+///
+/// # Usage Notes
+/// As with `translate` and `resize_area`, this effect should be applied before rendering any
+/// affected widgets; retrieve the current area with the `area()` function of the effect.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::*;
+///
+/// let timer = EffectTimer::from_ms(500, Interpolation::QuadOut);
+/// let effect = fx::fade_from_fg(Color::Black, timer);
+/// fx::tween_area(Some(effect), Rect::new(0, 0, 10, 3), Rect::new(20, 5, 40, 12), timer);
+/// ```
+pub fn tween_area<T: Into<EffectTimer>>(
+    fx: Option<Effect>,
+    from: Rect,
+    to: Rect,
+    timer: T,
+) -> Effect {
+    TweenArea::new(fx, from, to, timer.into()).into_effect()
+}
+
 /// Creates an effect that renders to an offscreen buffer.
 ///
 /// This function wraps an existing effect and redirects its rendering to a separate buffer,
@@ -507,7 +1297,8 @@ pub fn resize_area<T: Into<EffectTimer>>(
 ///
 /// # Arguments
 /// * `fx` - The effect to be rendered offscreen.
-/// * `render_target` - A shared, mutable reference to the offscreen `Buffer`.
+/// * `render_target` - A shared, mutable reference to the offscreen `Buffer`. With a weak
+///   reference, the effect completes once the buffer has been dropped.
 ///
 /// # Returns
 /// * An `Effect` that renders to the specified offscreen buffer.
@@ -538,8 +1329,104 @@ pub fn resize_area<T: Into<EffectTimer>>(
 /// This example creates an offscreen buffer and applies a fade effect to it. The effect can be
 /// processed independently of the main render buffer, allowing for more complex or
 /// performance-intensive effects to be computed separately.
-pub fn offscreen_buffer(fx: Effect, render_target: RefCount<Buffer>) -> Effect {
-    offscreen_buffer::OffscreenBuffer::new(fx, render_target).into_effect()
+pub fn offscreen_buffer<B: Into<BufferRef>>(fx: Effect, render_target: B) -> Effect {
+    offscreen_buffer::OffscreenBuffer::new(fx, render_target.into()).into_effect()
+}
+
+/// Modulates the intensity of an effect, cell by cell, with a grayscale mask.
+///
+/// The brightness of each mask cell - the luminance of its background, or of its
+/// foreground when brighter and holding a visible symbol - determines how much of the
+/// wrapped effect shows through: fully at white, not at all at black. The colors of
+/// partially masked cells are blended, while their symbols switch at half intensity.
+/// The mask is aligned with the top-left corner of the effect area; cells outside the
+/// mask are left untouched.
+///
+/// # Arguments
+/// * `fx` - The effect to mask.
+/// * `mask` - The mask, e.g. a logo or text pre-rendered in grayscale.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use ratatui::widgets::{Block, Widget};
+/// use tachyonfx::{fx, ref_count, Interpolation};
+///
+/// let area = Rect::new(0, 0, 40, 10);
+/// let mut mask = Buffer::empty(area);
+/// Block::new()
+///     .style(Style::default().bg(Color::White))
+///     .render(Rect::new(10, 2, 20, 6), &mut mask);
+///
+/// // only the cells covered by the white block are faded
+/// fx::masked(fx::fade_to_fg(Color::Black, (500, Interpolation::Linear)), ref_count(mask));
+/// ```
+pub fn masked(fx: Effect, mask: RefCount<Buffer>) -> Effect {
+    Masked::new(fx, mask).into_effect()
+}
+
+/// Creates an effect that renders a sprite, moving it along a path.
+///
+/// The sprite is rendered on top of the existing content each frame, with transparent
+/// cells - see [Sprite] - leaving the content beneath untouched. Positions along the
+/// path are relative to the top-left corner of the effect area, and the sprite is
+/// clipped to the area.
+///
+/// # Arguments
+/// * `sprite` - The sprite to render.
+/// * `path` - A fixed `Offset`, a `(from, to)` tuple of offsets or a `Vec` of waypoints.
+/// * `timer` - Controls the duration and interpolation of the movement.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::layout::Offset;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation, Sprite};
+///
+/// let ghost = Sprite::from_ansi("\x1b[97;40m .-. \n(o o)\n| O |\n'~~~'")
+///     .with_color_key(Color::Black);
+///
+/// let path = (Offset { x: -6, y: 2 }, Offset { x: 80, y: 2 });
+/// fx::sprite(ghost, path, (3000, Interpolation::SineInOut));
+/// ```
+pub fn sprite<P: Into<SpritePath>, T: Into<EffectTimer>>(
+    sprite: Sprite,
+    path: P,
+    timer: T,
+) -> Effect {
+    AnimatedSprite::new(sprite, path.into(), timer.into()).into_effect()
+}
+
+/// Creates an effect that copies the current contents of the buffer into an auxiliary
+/// buffer, then immediately completes.
+///
+/// Only the cells within the area of the effect are captured; the remaining cells of the
+/// auxiliary buffer are marked as skipped, so that they're omitted when rendering it. The
+/// auxiliary buffer is resized to match the main buffer.
+///
+/// Combined with `fx::translate_buf()` in a sequence, this enables freeze-frame
+/// transitions: capture the current frame, then animate the copy while the application
+/// renders new content.
+///
+/// # Examples
+///
+/// ```no_run
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Offset, Rect};
+/// use tachyonfx::*;
+///
+/// let snapshot = ref_count(Buffer::empty(Rect::default()));
+/// fx::sequence(&[
+///     fx::capture_into(snapshot.clone()),
+///     fx::translate_buf(Offset { x: 0, y: 24 }, snapshot, (500, Interpolation::QuadIn)),
+/// ]);
+/// ```
+pub fn capture_into(target: RefCount<Buffer>) -> Effect {
+    CaptureInto::new(target).into_effect()
 }
 
 /// Runs the effects in sequence, one after the other. Reports completion
@@ -554,6 +1441,201 @@ pub fn parallel(effects: &[Effect]) -> Effect {
     ParallelEffect::new(effects.into()).into_effect()
 }
 
+/// Runs the effects in parallel, all at the same time. Reports completion as soon
+/// as any of the effects has completed, cancelling the others - the first effect to
+/// finish wins.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // the dissolve stops when the fade completes
+/// fx::parallel_any(&[
+///     fx::fade_from_fg(Color::Black, (800, Interpolation::QuadOut)),
+///     fx::never_complete(fx::dissolve(400)),
+/// ]);
+/// ```
+pub fn parallel_any(effects: &[Effect]) -> Effect {
+    ParallelEffect::first_wins(effects.into()).into_effect()
+}
+
+/// Leaves a fading trail behind a moving cursor, tinting the background of the cells
+/// the cursor has passed. The cursor position is read from `cursor_position` each frame;
+/// when the cursor jumps several cells, the cells in between are part of the trail.
+///
+/// This effect never completes.
+///
+/// # Arguments
+/// * `cursor_position` - Returns the current cursor position, if any.
+/// * `color` - The color of the trail.
+/// * `decay` - How long it takes for a cell of the trail to fade out.
+///
+/// # Example
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use ratatui::layout::Position;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Duration};
+///
+/// // updated by the input widget as the user types
+/// let cursor = Arc::new(Mutex::new(Some(Position::new(4, 2))));
+///
+/// let position = cursor.clone();
+/// let trail = fx::cursor_trail(
+///     move || *position.lock().unwrap(),
+///     Color::Rgb(80, 120, 200),
+///     Duration::from_millis(400),
+/// );
+/// ```
+pub fn cursor_trail<F>(cursor_position: F, color: Color, decay: Duration) -> Effect
+where
+    F: Fn() -> Option<Position> + ThreadSafetyMarker + 'static,
+{
+    CursorTrail::new(ref_count(cursor_position), color, decay).into_effect()
+}
+
+/// Dims everything outside a circle of light following the mouse pointer, as
+/// reported by the [InputState](crate::InputState) attached with
+/// [Effect::with_input]. Cells within `radius` of the pointer are left untouched, and
+/// the light fades out over a soft edge. Nothing is dimmed while the mouse position
+/// is unknown.
+///
+/// This effect never completes.
+///
+/// # Arguments
+/// * `radius` - The radius of the light, in cells.
+/// * `amount` - How far the cells outside the light are darkened, from 0.0 to 1.0 (black).
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, ref_count, InputState};
+///
+/// let input = ref_count(InputState::default());
+/// let spotlight = fx::spotlight(10.0, 0.6).with_input(input.clone());
+/// ```
+pub fn spotlight(radius: f32, amount: f32) -> Effect {
+    Spotlight::new(radius, amount).into_effect()
+}
+
+/// Highlights the outline of the focused area, as reported by `focused_area` each
+/// frame, by applying `style` to the cells along its edges. When the focus moves, the
+/// outline glides over to the new area, resizing along the way. Nothing is drawn while
+/// `focused_area` returns `None`.
+///
+/// This effect never completes.
+///
+/// # Arguments
+/// * `focused_area` - Returns the area of the currently focused widget, if any.
+/// * `style` - The style applied to the outline.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Color, Style};
+/// use std::sync::{Arc, Mutex};
+/// use tachyonfx::fx;
+///
+/// // updated by the application's layout and focus handling
+/// let focused = Arc::new(Mutex::new(Some(Rect::new(2, 2, 20, 3))));
+///
+/// let focus = focused.clone();
+/// let ring = fx::focus_ring(
+///     move || *focus.lock().unwrap(),
+///     Style::new().fg(Color::LightCyan),
+/// );
+/// ```
+pub fn focus_ring<F>(focused_area: F, style: Style) -> Effect
+where
+    F: Fn() -> Option<Rect> + ThreadSafetyMarker + 'static,
+{
+    FocusRing::new(ref_count(focused_area), style).into_effect()
+}
+
+/// Offsets the timeline of an effect per cell, by the delay returned for each cell
+/// position. This generalizes staggered effects to arbitrary spatial delay fields,
+/// e.g. based on the distance to a point, the column index or noise.
+///
+/// Cells hold the initial state of the effect until their delay has passed, and the
+/// end state once the effect has completed for them. The effect completes once it has
+/// completed for all cells.
+///
+/// The delays are evaluated once for the area, and each distinct delay is processed
+/// by its own instance of the effect, restricted to the cells sharing that delay.
+/// Continuous delay fields are quantized to at most 32 evenly spaced delays, bounding
+/// the number of instances. Resizing the area re-evaluates the delays without
+/// restarting the effect.
+///
+/// # Arguments
+/// * `fx` - The effect to delay per cell. Best suited for effects applied cell by
+///   cell, such as fades.
+/// * `delay` - Returns the delay of the cell at a position.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Duration, Interpolation};
+///
+/// // ripples out from the top-left corner
+/// let fade = fx::fade_from_fg(Color::Black, (400, Interpolation::QuadOut));
+/// fx::delay_map(fade, |pos| {
+///     let distance = ((pos.x as f32).powi(2) + (2.0 * pos.y as f32).powi(2)).sqrt();
+///     Duration::from_millis(distance as u32 * 20)
+/// });
+/// ```
+pub fn delay_map<F>(fx: Effect, delay: F) -> Effect
+where
+    F: Fn(Position) -> Duration + ThreadSafetyMarker + 'static,
+{
+    DelayMap::new(fx, ref_count(delay)).into_effect()
+}
+
+/// Darkens everything outside the modal area, for drawing attention to a modal
+/// dialog. The cells fade towards black by `amount` over the duration of the timer,
+/// then remain dimmed for as long as the effect is kept around.
+///
+/// Reverse the effect to restore the cells when the modal closes; the reversed effect
+/// completes once the dimming has faded out.
+///
+/// # Arguments
+/// * `modal_area` - The area left untouched.
+/// * `amount` - How far the cells are darkened, from 0.0 (not at all) to 1.0 (black).
+/// * `timer` - Controls the duration of the dimming.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{fx, EffectManager, HandoffPolicy, Interpolation};
+///
+/// let modal = Rect::new(10, 5, 40, 10);
+/// let dim = fx::dim_behind(modal, 0.6, (300, Interpolation::QuadOut));
+///
+/// let mut effects = EffectManager::default();
+/// effects.add_unique_effect("dim", HandoffPolicy::Replace, dim.clone());
+///
+/// // when the modal closes
+/// effects.add_unique_effect("dim", HandoffPolicy::Replace, dim.reversed());
+/// ```
+pub fn dim_behind<T: Into<EffectTimer>>(modal_area: Rect, amount: f32, timer: T) -> Effect {
+    DimBehind::new(modal_area, amount, 0.0, timer.into()).into_effect()
+}
+
+/// Like [dim_behind], but also desaturates the cells outside the modal area.
+///
+/// # Arguments
+/// * `modal_area` - The area left untouched.
+/// * `amount` - How far the cells are darkened, from 0.0 (not at all) to 1.0 (black).
+/// * `desaturation` - How much of the saturation is removed, from 0.0 to 1.0 (grayscale).
+/// * `timer` - Controls the duration of the dimming.
+pub fn dim_behind_desaturated<T: Into<EffectTimer>>(
+    modal_area: Rect,
+    amount: f32,
+    desaturation: f32,
+    timer: T,
+) -> Effect {
+    DimBehind::new(modal_area, amount, desaturation, timer.into()).into_effect()
+}
+
 /// Dissolves the current text into the new text over the specified duration. The
 /// `cycle_len` parameter specifies the number of cell states are tracked before
 /// it cycles and repeats.
@@ -568,6 +1650,67 @@ pub fn coalesce<T: Into<EffectTimer>>(timer: T) -> Effect {
         .into_effect()
 }
 
+/// Coalesces the text like [coalesce()], but with each cell materializing through
+/// partial block glyphs before resolving into its symbol. Blank cells stay blank.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, Interpolation};
+///
+/// fx::coalesce_with_build_up((800, Interpolation::QuadOut));
+/// ```
+pub fn coalesce_with_build_up<T: Into<EffectTimer>>(timer: T) -> Effect {
+    Dissolve::new(timer.into().reversed())
+        .with_build_up()
+        .into_effect()
+}
+
+/// Dissolves the current text in the given order, so that the shape of the
+/// transition can match the content - e.g. reading order for prose, or center-out
+/// for a logo.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, Interpolation};
+/// use tachyonfx::fx::DissolveOrder;
+///
+/// fx::dissolve_ordered(DissolveOrder::CenterOut, (800, Interpolation::QuadIn));
+/// fx::dissolve_ordered(DissolveOrder::Seeded(42), 800);
+/// ```
+pub fn dissolve_ordered<T: Into<EffectTimer>>(order: DissolveOrder, timer: T) -> Effect {
+    Dissolve::new(timer.into())
+        .with_order(order)
+        .into_effect()
+}
+
+/// The reverse of [dissolve_ordered()]: cells are restored in the reverse order,
+/// i.e. the cells dissolved last are the first to reappear.
+pub fn coalesce_ordered<T: Into<EffectTimer>>(order: DissolveOrder, timer: T) -> Effect {
+    Dissolve::new(timer.into().reversed())
+        .with_order(order)
+        .into_effect()
+}
+
+/// Randomly swaps selected cells with a neighboring cell, for a single frame at a
+/// time, for a nervous or damaged look. Displaced cells return to their place on the
+/// next frame.
+///
+/// # Arguments
+/// * `max_offset` - The maximum displacement, in cells, along each axis.
+/// * `frequency` - How often each selected cell is displaced, in times per second.
+/// * `timer` - The duration of the effect.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, CellFilter};
+///
+/// // the text shivers for two seconds, about three times per second and character
+/// fx::jitter(1, 3.0, 2000)
+///     .with_cell_selection(CellFilter::Text);
+/// ```
+pub fn jitter<T: Into<EffectTimer>>(max_offset: u16, frequency: f32, timer: T) -> Effect {
+    Jitter::new(max_offset, frequency, timer.into()).into_effect()
+}
 
 /// Fades the foreground color to the specified color over the specified duration.
 pub fn fade_to_fg<T: Into<EffectTimer>, C: Into<Color>>(
@@ -577,6 +1720,32 @@ pub fn fade_to_fg<T: Into<EffectTimer>, C: Into<Color>>(
     fade(Some(fg), None, timer.into(), false)
 }
 
+/// Fades the foreground and background colors to the given colors over the specified
+/// duration, leaving a color unchanged when `None`. Unlike the other fades, the colors
+/// are optional, e.g. when read from user configuration.
+///
+/// # Errors
+/// Returns an [FxError::NoColorTarget] if neither `fg` nor `bg` is given.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, FxError};
+///
+/// let fade = fx::try_fade(None, Some(Color::Black), 500);
+/// assert!(fade.is_ok());
+///
+/// let fade = fx::try_fade(None::<Color>, None, 500);
+/// assert_eq!(fade.unwrap_err(), FxError::NoColorTarget { effect: "fade_to" });
+/// ```
+pub fn try_fade<T: Into<EffectTimer>, C: Into<Color>>(
+    fg: Option<C>,
+    bg: Option<C>,
+    timer: T,
+) -> Result<Effect, FxError> {
+    try_fade_colors(fg, bg, timer.into(), false)
+}
+
 /// Fades the foreground color from the specified color over the specified duration.
 pub fn fade_from_fg<T: Into<EffectTimer>, C: Into<Color>>(
     fg: C,
@@ -594,6 +1763,80 @@ pub fn fade_to<T: Into<EffectTimer>, C: Into<Color>>(
     fade(Some(fg), Some(bg), timer.into(), false)
 }
 
+/// Animates a change of text modifiers, adding `add` and removing `remove` from
+/// the cells halfway through the effect. Use [`FadeModifiers::builder()`] to
+/// configure the threshold at which the modifiers switch, or to soften the
+/// transition with [`Modifier::DIM`].
+///
+/// # Example
+/// ```
+/// use ratatui::style::Modifier;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// let emphasize = fx::fade_modifiers(Modifier::BOLD, Modifier::DIM, (300, Interpolation::Linear));
+/// ```
+pub fn fade_modifiers<T: Into<EffectTimer>>(
+    add: Modifier,
+    remove: Modifier,
+    timer: T,
+) -> Effect {
+    FadeModifiers::builder()
+        .add(add)
+        .remove(remove)
+        .timer(timer)
+        .build()
+        .into_effect()
+}
+
+/// Briefly tints the background of the selected cells with `color`, fading back
+/// to the original colors. The original colors are captured on the first frame,
+/// making it suitable for animating e.g. list selection changes.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// let selected_row = Rect::new(0, 4, 40, 1);
+/// let fx = fx::flash(Color::from_u32(0x45475a), (300, Interpolation::QuadOut))
+///     .with_area(selected_row);
+/// ```
+pub fn flash<T: Into<EffectTimer>, C: Into<Color>>(color: C, timer: T) -> Effect {
+    Flash::new(color.into(), timer.into()).into_effect()
+}
+
+/// Flashes the background of the selected cells with `color`, `flash_count` times over
+/// the duration of the timer. Each flash is brief, fading out from full intensity, and
+/// the flashes are separated by randomized gaps - like lightning, or a dramatic alert.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::fx;
+///
+/// // three flashes of lightning, within a second
+/// let fx = fx::strobe(Color::from_u32(0xe0e8ff), 3, 1000);
+/// ```
+pub fn strobe<T: Into<EffectTimer>, C: Into<Color>>(color: C, flash_count: u16, timer: T) -> Effect {
+    Strobe::new(color.into(), flash_count, timer.into()).into_effect()
+}
+
+/// Applies the style to the selected cells for the duration of the effect. The
+/// cells are restored to their original style once the effect completes.
+///
+/// # Example
+/// ```
+/// use ratatui::style::{Color, Style, Stylize};
+/// use tachyonfx::{fx, CellFilter};
+///
+/// let highlight = fx::style(Style::new().black().on_yellow().bold(), 500)
+///     .with_cell_selection(CellFilter::Text);
+/// ```
+pub fn style<T: Into<EffectTimer>>(style: Style, timer: T) -> Effect {
+    StyleOverride::new(style, timer.into()).into_effect()
+}
+
 /// Fades from the specified the background and foreground colors over the specified duration.
 pub fn fade_from<T: Into<EffectTimer>, C: Into<Color>>(
     fg: C,
@@ -659,7 +1902,7 @@ pub fn delay<T: Into<EffectTimer>>(duration: T, effect: Effect) -> Effect {
 /// # Arguments
 ///
 /// * `duration` - The additional duration to add before the effect starts. This can be
-///                any type that can be converted into an `EffectTimer`.
+///   any type that can be converted into an `EffectTimer`.
 /// * `effect` - The original effect to be prolonged.
 ///
 /// # Returns
@@ -692,7 +1935,7 @@ pub fn prolong_start<T: Into<EffectTimer>>(duration: T, effect: Effect) -> Effec
 /// # Arguments
 ///
 /// * `duration` - The additional duration to add after the effect completes. This can be
-///                any type that can be converted into an `EffectTimer`.
+///   any type that can be converted into an `EffectTimer`.
 /// * `effect` - The original effect to be prolonged.
 ///
 /// # Returns
@@ -718,6 +1961,34 @@ pub fn prolong_end<T: Into<EffectTimer>>(duration: T, effect: Effect) -> Effect
     Prolong::new(ProlongPosition::End, duration.into(), effect).into_effect()
 }
 
+/// Creates an effect that pauses another effect once it reaches a given progress.
+///
+/// The wrapped effect runs until `alpha` of its duration has elapsed, holds its state
+/// for the specified duration, and then continues to completion. Complements
+/// [prolong_start()] and [prolong_end()], which pad the extremes of an effect.
+///
+/// # Arguments
+///
+/// * `alpha` - The progress at which to pause, as a share of the effect's duration,
+///   from 0.0 to 1.0. The effect's interpolation doesn't apply.
+/// * `duration` - How long to pause the effect.
+/// * `effect` - The effect to pause.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::{fx, Interpolation};
+///
+/// // slides in halfway, lingers for a second, then slides in fully
+/// fx::hold_at(0.5, 1000,
+///     fx::slide_in(fx::Direction::LeftToRight, 10, 0, Color::Black, (800, Interpolation::QuadOut))
+/// );
+/// ```
+pub fn hold_at<T: Into<EffectTimer>>(alpha: f32, duration: T, effect: Effect) -> Effect {
+    Prolong::new(ProlongPosition::At(alpha), duration.into(), effect).into_effect()
+}
+
 /// Creates an effect that consumes a single tick of processing time.
 ///
 /// This function creates an effect that does nothing but mark itself as complete
@@ -731,6 +2002,36 @@ pub fn consume_tick() -> Effect {
     ConsumeTick::default().into_effect()
 }
 
+/// Wraps an effect, catching any panic raised while processing it. The panicking
+/// effect is marked as done, and the panic message is available through
+/// [Shader::panic_message] on the returned effect.
+///
+/// Keeps a faulty custom shader, e.g. one built with [effect_fn], from taking down
+/// the application along with the terminal state. Note that the buffer may be left
+/// partially processed, that the panic hook still runs - by default printing the
+/// message to stderr - and that panics can't be caught when built with
+/// `panic = "abort"`.
+///
+/// # Example
+/// ```
+/// use ratatui::{buffer::Buffer, layout::Rect};
+/// use tachyonfx::{fx, Duration, Shader};
+///
+/// let mut effect = fx::isolated(fx::effect_fn((), 500, |_, _, _| {
+///     panic!("faulty shader");
+/// }));
+///
+/// # std::panic::set_hook(Box::new(|_| {}));
+/// let area = Rect::new(0, 0, 10, 1);
+/// effect.process(Duration::from_millis(16), &mut Buffer::empty(area), area);
+///
+/// assert!(effect.done());
+/// assert_eq!(effect.panic_message(), Some("faulty shader"));
+/// ```
+pub fn isolated(effect: Effect) -> Effect {
+    Isolated::new(effect).into_effect()
+}
+
 /// An effect that forces the wrapped effect to never report completion,
 /// effectively making it run indefinitely. Once the effect reaches the end,
 /// it will continue to process the effect without advancing the duration.
@@ -757,16 +2058,25 @@ fn fade<C: Into<Color>>(
     timer: EffectTimer,
     reverse: bool,
 ) -> Effect {
+    try_fade_colors(fg, bg, timer, reverse).unwrap_or_else(|e| panic!("{e}"))
+}
+
+fn try_fade_colors<C: Into<Color>>(
+    fg: Option<C>,
+    bg: Option<C>,
+    timer: EffectTimer,
+    reverse: bool,
+) -> Result<Effect, FxError> {
     if fg.is_none() && bg.is_none() {
-        panic!("At least one of fg or bg must be provided");
+        return Err(FxError::NoColorTarget { effect: if reverse { "fade_from" } else { "fade_to" } });
     }
 
-    FadeColors::builder()
+    Ok(FadeColors::builder()
         .maybe_fg(fg.map(Into::into))
         .maybe_bg(bg.map(Into::into))
         .timer(if reverse { timer.reversed() } else { timer })
         .build()
-        .into_effect()
+        .into_effect())
 }
 
 
@@ -790,11 +2100,12 @@ pub (crate) use invoke_fn;
 
 #[cfg(test)]
 mod tests {
+    use ratatui::layout::Margin;
     use ratatui::prelude::Color;
     use crate::fx::offscreen_buffer::OffscreenBuffer;
     use crate::fx::translate::Translate;
     use super::*;
-    use crate::Shader;
+    use crate::{CellFilter, SelectionPolicy, Shader};
 
     const DIRECTIONS: [Direction; 4] = [
         Direction::DownToUp,
@@ -892,32 +2203,190 @@ mod tests {
     }
 
     #[test]
+    fn test_cell_selection_policy_propagation() {
+        // cell filters of the effects hosted by the parallel effect
+        let filters = |fx: &Effect| fx.as_effect_span(Duration::ZERO).children[0].children.iter()
+            .map(|span| span.cell_filter.to_string())
+            .collect::<Vec<_>>();
+
+        let fx = never_complete(parallel(&[
+            fade_to_fg(Color::Red, 100).with_cell_selection(CellFilter::Text),
+            dissolve(100),
+        ]));
+
+        let replaced = fx.with_cell_selection_policy(CellFilter::FgColor(Color::Red), SelectionPolicy::Replace);
+        assert_eq!(filters(&replaced), vec!["fg(#800000)", "fg(#800000)"]);
+
+        let intersected = fx.with_cell_selection_policy(CellFilter::FgColor(Color::Red), SelectionPolicy::Intersect);
+        assert_eq!(filters(&intersected), vec!["all_of(fg(#800000), text)", "fg(#800000)"]);
+
+        let unioned = fx.with_cell_selection_policy(CellFilter::FgColor(Color::Red), SelectionPolicy::Union);
+        assert_eq!(filters(&unioned), vec!["any_of(fg(#800000), text)", "all"]);
+    }
+
+    #[test]
+    fn test_ping_pong_forwards_cell_selection() {
+        let area = Rect::new(0, 0, 2, 1);
+        let fx = ping_pong(fade_to_fg(Color::Red, 100).with_cell_selection(CellFilter::Text));
+
+        let mut selected = fx.with_cell_selection(CellFilter::Not(CellFilter::Text.into()));
+        let mut buf = Buffer::with_lines(["a-"]);
+        selected.process(Duration::from_millis(100), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Reset);
+        assert_eq!(buf[(1, 0)].fg, Color::Red);
+
+        let intersected = fx.with_cell_selection_policy(CellFilter::FgColor(Color::Red), SelectionPolicy::Intersect);
+        assert_eq!(
+            intersected.cell_selection().map(|filter| filter.to_string()),
+            Some("all_of(fg(#800000), text)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_effect_fn_pixels() {
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::with_lines(["abc"]);
+
+        let mut fx = effect_fn_pixels((), 100, |_, _, canvas| {
+            (0..canvas.width()).for_each(|x| canvas.set(x, 3));
+        }).with_cell_selection(CellFilter::Inner(Margin::new(1, 0)));
+        fx.process(Duration::from_millis(10), &mut buf, area);
+
+        assert_eq!(buf, Buffer::with_lines(["a⣀c"]));
+    }
+
+    #[test]
+    fn test_effect_fn_buf_with_previous() {
+        let area = Rect::new(0, 0, 3, 1);
+
+        // writes the previous frame's first symbol, shifted one cell to the right
+        let mut fx = effect_fn_buf_with_previous((), 100, |_, ctx, buf| {
+            let symbol = ctx.previous_frame
+                .map_or("-", |prev| prev[(0, 0)].symbol())
+                .to_string();
+            buf[(1, 0)].set_symbol(&symbol);
+            buf[(0, 0)].set_symbol("x");
+        });
+
+        let mut buf = Buffer::with_lines(["abc"]);
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["x-c"]));
+
+        let mut buf = Buffer::with_lines(["abc"]);
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["xxc"]));
+
+        fx.reset();
+        let mut buf = Buffer::with_lines(["abc"]);
+        fx.process(Duration::from_millis(10), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["x-c"]));
+    }
+
+    #[test]
+    fn test_effect_fn_filtered() {
+        let area = Rect::new(0, 0, 5, 1);
+        let mut buf = Buffer::with_lines(["a-b-c"]);
+
+        let mut fx = effect_fn_filtered((), 100, |_, ctx, buf, selector| {
+            for pos in ctx.area.positions() {
+                if selector.is_valid(pos, &buf[pos]) {
+                    buf[pos].set_symbol("x");
+                }
+            }
+        }).with_cell_selection(CellFilter::AllOf(vec![
+            CellFilter::Text,
+            CellFilter::Inner(Margin::new(1, 0)),
+        ]));
+        fx.process(Duration::from_millis(10), &mut buf, area);
+
+        assert_eq!(buf, Buffer::with_lines(["a-x-c"]));
+    }
+
+    #[test]
+    fn test_effect_fn_with_setup() {
+        let area = Rect::new(1, 0, 2, 1);
+        let mut fx = effect_fn_with_setup(
+            |area: Rect| (area, 0),
+            100,
+            |(setup_area, frames), ctx, mut cell_iter| {
+                assert_eq!(*setup_area, ctx.area);
+                *frames += 1;
+                cell_iter.next().unwrap().1.set_symbol(&frames.to_string());
+            },
+        );
+
+        let process = |fx: &mut Effect| {
+            let mut buf = Buffer::with_lines(["abc"]);
+            fx.process(Duration::from_millis(10), &mut buf, area);
+            buf
+        };
+
+        assert_eq!(process(&mut fx), Buffer::with_lines(["a1c"]));
+        assert_eq!(process(&mut fx), Buffer::with_lines(["a2c"]));
+
+        // state is created anew after a reset
+        fx.reset();
+        assert_eq!(process(&mut fx), Buffer::with_lines(["a1c"]));
+    }
+
+    // sizes with the default 4-byte Duration, and the 8-byte "micros-duration" Duration
+    #[test]
+    #[cfg_attr(feature = "std-duration", ignore)]
     fn assert_sizes() {
-        let verify_size = |actual: usize, expected: usize| {
+        let verify_size = |actual: usize, expected: usize, expected_micros: usize| {
+            let expected = if cfg!(feature = "micros-duration") { expected_micros } else { expected };
             assert_eq!(actual, expected);
         };
 
-        verify_size(size_of::<EffectTimer>(),      12);
-        verify_size(size_of::<Ansi256>(),          10);
-        verify_size(size_of::<ConsumeTick>(),       1);
-        verify_size(size_of::<Dissolve>(),         80);
-        verify_size(size_of::<FadeColors>(),       80);
-        verify_size(size_of::<Glitch>(),          112);
-        verify_size(size_of::<HslShift>(),        104);
-        verify_size(size_of::<NeverComplete>(),    16);
-        verify_size(size_of::<OffscreenBuffer>(),  24);
-        verify_size(size_of::<ParallelEffect>(),   24);
-        verify_size(size_of::<PingPong>(),         72);
-        verify_size(size_of::<Prolong>(),          32);
-        verify_size(size_of::<Repeat>(),           32);
-        verify_size(size_of::<ResizeArea>(),       56);
-        verify_size(size_of::<SequentialEffect>(), 32);
-        verify_size(size_of::<ShaderFn<()>>(),    112);
-        verify_size(size_of::<Sleep>(),            12);
-        verify_size(size_of::<SlideCell>(),        80);
-        verify_size(size_of::<SweepIn>(),          80);
-        verify_size(size_of::<TemporaryEffect>(),  32);
-        verify_size(size_of::<Translate>(),        72);
-        verify_size(size_of::<TranslateBuffer>(),  32);
+        verify_size(size_of::<EffectTimer>(),      12,  24);
+        verify_size(size_of::<AnimatedSprite>(),   88, 104);
+        verify_size(size_of::<CaptureInto>(),      24,  24);
+        verify_size(size_of::<ColorFilter>(),      72,  88);
+        verify_size(size_of::<ConsumeTick>(),       1,   1);
+        verify_size(size_of::<QuantizeColors>(),   40,  40);
+        verify_size(size_of::<Dissolve>(),         88,  96);
+        verify_size(size_of::<DimBehind>(),        88, 104);
+        verify_size(size_of::<FocusRing>(),       120, 136);
+        verify_size(size_of::<CursorTrail>(),     112, 120);
+        verify_size(size_of::<Spotlight>(),        80,  80);
+        verify_size(size_of::<DelayMap>(),         72,  80);
+        verify_size(size_of::<FadeColors>(),      176, 192);
+        verify_size(size_of::<FadeModifiers>(),    80,  96);
+        verify_size(size_of::<Flash>(),           104, 112);
+        verify_size(size_of::<Glitch>(),          176, 192);
+        verify_size(size_of::<Jitter>(),          104, 120);
+        verify_size(size_of::<Strobe>(),           80,  96);
+        verify_size(size_of::<Starfield>(),        88,  88);
+        verify_size(size_of::<Plasma>(),           96,  96);
+        verify_size(size_of::<Masked>(),           48,  48);
+        verify_size(size_of::<Crossfade>(),        80,  88);
+        verify_size(size_of::<Gradient>(),        120, 136);
+        verify_size(size_of::<HslShift>(),        104, 120);
+        verify_size(size_of::<Isolated>(),         40,  40);
+        verify_size(size_of::<MapColors>(),       88, 104);
+        verify_size(size_of::<NeverComplete>(),    16,  16);
+        verify_size(size_of::<OffscreenBuffer>(),  32,  32);
+        verify_size(size_of::<ParallelEffect>(),   40,  40);
+        verify_size(size_of::<PingPong>(),         24,  24);
+        verify_size(size_of::<Prolong>(),          40,  56);
+        verify_size(size_of::<RemapPalette>(),    120, 136);
+        verify_size(size_of::<Repeat>(),           32,  48);
+        verify_size(size_of::<ResizeArea>(),       56,  72);
+        verify_size(size_of::<SequentialEffect>(), 40,  40);
+        verify_size(size_of::<ShaderFn<()>>(),    152, 168);
+        verify_size(size_of::<Sleep>(),            12,  24);
+        verify_size(size_of::<SlideCell>(),       136, 144);
+        verify_size(size_of::<SlideContentOut>(),  64,  72);
+        verify_size(size_of::<StyleOverride>(),    88, 104);
+        verify_size(size_of::<SweepIn>(),         152, 168);
+        verify_size(size_of::<TemporaryEffect>(),  32,  40);
+        verify_size(size_of::<TimeWarp>(),        32,  48);
+        verify_size(size_of::<Ticker>(),          80,  80);
+        verify_size(size_of::<Blink>(),           72,  72);
+        verify_size(size_of::<Pulse>(),           72,  80);
+        verify_size(size_of::<Wave>(),            48,  48);
+        verify_size(size_of::<Translate>(),        72,  80);
+        verify_size(size_of::<TranslateBuffer>(),  40,  56);
+        verify_size(size_of::<TweenArea>(),        72,  88);
     }
 }
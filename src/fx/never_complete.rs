@@ -1,11 +1,11 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use crate::{CellFilter, CellIterator, Duration, EffectTimer};
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, InputState, RefCount, SelectionPolicy};
 use crate::effect::Effect;
 use crate::widget::EffectSpan;
 use crate::shader::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct NeverComplete {
     effect: Effect,
 }
@@ -39,6 +39,14 @@ impl Shader for NeverComplete {
         self.effect.set_cell_selection(strategy);
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.effect.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.effect.set_input(input);
+    }
+
     fn reverse(&mut self) {
         self.effect.reverse()
     }
@@ -55,6 +63,10 @@ impl Shader for NeverComplete {
         self.effect.reset();
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        self.effect.dirty_region()
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         EffectSpan::new(self, offset, vec![self.effect.as_effect_span(offset)])
     }
@@ -1,20 +1,27 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use crate::{CellFilter, CellIterator, Duration, Effect, RefCount, Shader};
+use crate::{BufferRef, CellFilter, CellIterator, Duration, Effect, InputState, RefCount, SelectionPolicy, Shader};
 use crate::widget::EffectSpan;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct OffscreenBuffer {
     fx: Effect,
-    render_target: RefCount<Buffer>,
+    render_target: BufferRef,
 }
 
 impl OffscreenBuffer {
-    pub fn new(fx: Effect, render_target: RefCount<Buffer>) -> Self {
+    pub fn new(fx: Effect, render_target: BufferRef) -> Self {
         Self { fx, render_target }
     }
 }
 
+fn area_of(buffer: &RefCount<Buffer>) -> Rect {
+    #[cfg(not(feature = "sendable"))]
+    return buffer.as_ref().borrow().area;
+    #[cfg(feature = "sendable")]
+    buffer.lock().unwrap().area
+}
+
 impl Shader for OffscreenBuffer {
     fn name(&self) -> &'static str {
         "offscreen_buffer"
@@ -26,13 +33,17 @@ impl Shader for OffscreenBuffer {
         _buf: &mut Buffer,
         _area: Rect
     ) -> Option<Duration> {
-        let area = self.area().unwrap(); // guaranteed to be Some
+        let Some(render_target) = self.render_target.upgrade() else {
+            return Some(duration); // the weakly referenced buffer is gone
+        };
+
+        let area = self.fx.area().unwrap_or_else(|| area_of(&render_target));
         #[cfg(not(feature = "sendable"))] {
-            let target = &mut self.render_target.as_ref().borrow_mut();
+            let target = &mut render_target.as_ref().borrow_mut();
             self.fx.process(duration, target, area);
         };
         #[cfg(feature = "sendable")] {
-            let mut target = self.render_target.lock().unwrap();
+            let mut target = render_target.lock().unwrap();
             self.fx.process(duration, &mut target, area);
         };
 
@@ -42,25 +53,16 @@ impl Shader for OffscreenBuffer {
     fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
 
     fn done(&self) -> bool {
-        self.fx.done()
+        self.fx.done() || self.render_target.upgrade().is_none()
     }
 
     fn clone_box(&self) -> Box<dyn Shader> {
         Box::new(self.clone())
     }
 
-    #[cfg(not(feature = "sendable"))]
-    fn area(&self) -> Option<Rect> {
-        self.fx.area()
-            .unwrap_or_else(|| *self.render_target.as_ref().borrow().area())
-            .into()
-    }
-
-    #[cfg(feature = "sendable")]
     fn area(&self) -> Option<Rect> {
         self.fx.area()
-            .unwrap_or_else(|| self.render_target.lock().unwrap().area)
-            .into()
+            .or_else(|| self.render_target.upgrade().map(|target| area_of(&target)))
     }
 
     fn set_area(&mut self, area: Rect) {
@@ -71,6 +73,18 @@ impl Shader for OffscreenBuffer {
         self.fx.set_cell_selection(filter);
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.fx.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.fx.set_input(input);
+    }
+
+    fn dirty_region(&self) -> Option<Rect> {
+        Some(Rect::default()) // renders to the offscreen buffer
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         EffectSpan::new(self, offset, vec![self.fx.as_effect_span(offset)])
     }
@@ -0,0 +1,153 @@
+//! Color palettes for categorical data.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+use ratatui::style::Color;
+
+use crate::{FxError, HslConvertable, RangeSampler, SimpleRng};
+
+/// The seed for shuffling the colors, fixed to keep the assignments stable across runs.
+const SHUFFLE_SEED: u32 = 0x5eed_c01d;
+
+/// Assigns stable, distinct colors to string keys.
+///
+/// The hues are spread evenly over a hue range, at a fixed saturation and lightness,
+/// and shuffled so that keys adjacent in sort order don't get similar colors. The same
+/// set of keys always yields the same colors, regardless of the order or duplicates
+/// in which they are given.
+///
+/// Useful for coloring per-series data, or for telling effects apart, as done by
+/// [EffectTimeline](crate::widget::EffectTimeline).
+///
+/// # Example
+/// ```
+/// use tachyonfx::fx::palette::CategoricalPalette;
+///
+/// let palette = CategoricalPalette::new(["cpu", "memory", "disk", "network"]);
+///
+/// let cpu = palette.color_of("cpu").unwrap();
+/// assert_ne!(Some(cpu), palette.color_of("memory"));
+/// assert_eq!(palette.color_of("gpu"), None);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CategoricalPalette {
+    key_to_color: Vec<(String, Color)>,
+}
+
+impl CategoricalPalette {
+    /// Creates a palette over the full hue range, with moderately saturated colors.
+    pub fn new<K: Into<String>>(keys: impl IntoIterator<Item = K>) -> Self {
+        Self::with_hsl(keys, 0.0..360.0, 60.0, 60.0)
+    }
+
+    /// Creates a palette spreading the hues over the `hue` range, in degrees, with the
+    /// given saturation and lightness, both from 0 to 100.
+    ///
+    /// # Panics
+    /// If the hue range falls outside 0 to 360, or saturation or lightness outside
+    /// 0 to 100; see [CategoricalPalette::try_with_hsl].
+    pub fn with_hsl<K: Into<String>>(
+        keys: impl IntoIterator<Item = K>,
+        hue: Range<f32>,
+        saturation: f32,
+        lightness: f32,
+    ) -> Self {
+        Self::try_with_hsl(keys, hue, saturation, lightness).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Like [CategoricalPalette::with_hsl], but returns an [FxError::OutOfRange]
+    /// instead of panicking on invalid parameters.
+    pub fn try_with_hsl<K: Into<String>>(
+        keys: impl IntoIterator<Item = K>,
+        hue: Range<f32>,
+        saturation: f32,
+        lightness: f32,
+    ) -> Result<Self, FxError> {
+        FxError::check_range("hue range start", hue.start, 0.0, 360.0)?;
+        FxError::check_range("hue range end", hue.end, 0.0, 360.0)?;
+        FxError::check_range("saturation", saturation, 0.0, 100.0)?;
+        FxError::check_range("lightness", lightness, 0.0, 100.0)?;
+
+        let keys: BTreeSet<String> = keys.into_iter().map(Into::into).collect();
+
+        let hue_range = hue.end - hue.start;
+        let len = keys.len();
+        let mut colors: Vec<Color> = (0..len)
+            .map(|idx| hue.start + hue_range * idx as f32 / len as f32)
+            .map(|hue| Color::from_hsl(hue, saturation, lightness))
+            .collect();
+
+        shuffle(&mut colors, &mut SimpleRng::new(SHUFFLE_SEED));
+
+        Ok(Self {
+            key_to_color: keys.into_iter().zip(colors).collect(),
+        })
+    }
+
+    /// Returns the color assigned to `key`, if the palette has the key.
+    pub fn color_of(&self, key: &str) -> Option<Color> {
+        self.key_to_color
+            .binary_search_by(|(k, _)| k.as_str().cmp(key))
+            .ok()
+            .map(|idx| self.key_to_color[idx].1)
+    }
+
+    /// Returns the keys and their colors, in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Color)> {
+        self.key_to_color.iter().map(|(k, c)| (k.as_str(), *c))
+    }
+
+    /// Returns the number of keys in the palette.
+    pub fn len(&self) -> usize {
+        self.key_to_color.len()
+    }
+
+    /// Returns true if the palette has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.key_to_color.is_empty()
+    }
+}
+
+fn shuffle<T>(vec: &mut [T], rng: &mut SimpleRng) {
+    let len = vec.len();
+    for i in 0..len {
+        let j = rng.gen_range(i..len);
+        vec.swap(i, j);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shuffle() {
+        let mut lcg = SimpleRng::new(12345);
+        let mut vec = vec![1, 2, 3, 4, 5];
+        let original = vec.clone();
+
+        shuffle(&mut vec, &mut lcg);
+
+        assert_ne!(vec, original);
+        assert_eq!(vec.len(), original.len());
+        assert_eq!(vec.iter().sum::<i32>(), original.iter().sum::<i32>());
+    }
+
+    #[test]
+    fn test_colors_are_stable_and_distinct() {
+        let palette = CategoricalPalette::new(["b", "a", "c", "a"]);
+        assert_eq!(palette, CategoricalPalette::new(["c", "b", "a"]));
+        assert_eq!(palette.len(), 3);
+
+        let colors: BTreeSet<String> = palette.iter().map(|(_, c)| c.to_string()).collect();
+        assert_eq!(colors.len(), 3);
+        assert_eq!(palette.iter().map(|(k, _)| k).collect::<Vec<_>>(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_invalid_hsl() {
+        let err = CategoricalPalette::try_with_hsl(["a"], 0.0..360.0, 60.0, 120.0).unwrap_err();
+        assert_eq!(err, FxError::OutOfRange { param: "lightness", value: 120.0, min: 0.0, max: 100.0 });
+    }
+}
@@ -1,14 +1,13 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 
-use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, Shader};
-use crate::widget::EffectSpan;
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, RefCount, SelectionPolicy, Shader};
+use crate::widget::{EffectSpan, SpanIteration};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct PingPong {
     fx: Effect,
     is_reversing: bool,
-    strategy: CellFilter,
 }
 
 impl PingPong {
@@ -16,7 +15,6 @@ impl PingPong {
         Self {
             fx,
             is_reversing: false,
-            strategy: CellFilter::default(),
         }
     }
 }
@@ -65,7 +63,15 @@ impl Shader for PingPong {
     }
 
     fn set_cell_selection(&mut self, strategy: CellFilter) {
-        self.strategy = strategy;
+        self.fx.set_cell_selection(strategy);
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.fx.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.fx.set_input(input);
     }
 
     fn reverse(&mut self) {
@@ -80,12 +86,17 @@ impl Shader for PingPong {
         self.fx.timer().as_ref().map(|t| *t * 2)
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        self.fx.dirty_region()
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         EffectSpan::new(self, offset, vec![self.fx.as_effect_span(offset)])
+            .with_iteration(SpanIteration::PingPong)
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
-        Some(self.strategy.clone())
+        self.fx.cell_selection()
     }
 
     fn reset(&mut self) {
@@ -0,0 +1,142 @@
+use core::f32::consts::TAU;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Interpolatable, Shader};
+
+/// An animated plasma field, a sum of sines, mapped through a cyclic color palette
+/// onto the background of the selected cells.
+#[derive(Clone, Debug)]
+pub struct Plasma {
+    palette: Vec<Color>,
+    /// radians per second
+    speed: f32,
+    /// the phase of the animation, in radians
+    phase: f32,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Plasma {
+    pub fn new(palette: Vec<Color>, speed: f32) -> Self {
+        Self { palette, speed, phase: 0.0, area: None, cell_filter: CellFilter::All }
+    }
+
+    /// The value of the field at the cell offset `(x, y)` into the area, in the range
+    /// 0.0 to 1.0. The phases of the terms are whole multiples of the animation phase,
+    /// so the field loops seamlessly.
+    fn value(&self, x: f32, y: f32) -> f32 {
+        let t = self.phase;
+        // cells are roughly twice as tall as they are wide
+        let y = y * 2.0;
+
+        let v = (x / 8.0 + t).sin()
+            + (y / 6.0 - 2.0 * t).sin()
+            + ((x + y) / 10.0 + t).sin()
+            + ((x * x + y * y).sqrt() / 6.0 - 3.0 * t).sin();
+
+        (v / 4.0 + 1.0) / 2.0
+    }
+
+    /// Maps `v` to the palette, interpolating between neighboring colors and
+    /// wrapping from the last color back to the first.
+    fn color_at(&self, v: f32) -> Color {
+        let n = self.palette.len();
+        let t = v.rem_euclid(1.0) * n as f32;
+        let i = (t as usize).min(n - 1);
+
+        self.palette[i].lerp(&self.palette[(i + 1) % n], t - i as f32)
+    }
+}
+
+impl Shader for Plasma {
+    fn name(&self) -> &'static str {
+        "plasma"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        self.phase = (self.phase + self.speed * duration.as_secs_f32()).rem_euclid(TAU);
+
+        let cells = self.cell_iter(buf, area);
+        self.execute(1.0, area, cells);
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, area: Rect, cell_iter: CellIterator) {
+        if self.palette.is_empty() {
+            return;
+        }
+
+        cell_iter.for_each(|(pos, cell)| {
+            let Position { x, y } = pos;
+            let v = self.value((x - area.x) as f32, (y - area.y) as f32);
+            cell.set_bg(self.color_at(v));
+        });
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.phase = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+    use crate::testing::effect_frames;
+
+    use super::*;
+
+    #[test]
+    fn test_plasma_palette() {
+        let palette = vec![Color::Rgb(0, 0, 0), Color::Rgb(255, 255, 255)];
+        let plasma = Plasma::new(palette, 1.0);
+
+        assert_eq!(plasma.color_at(0.0), Color::Rgb(0, 0, 0));
+        assert_eq!(plasma.color_at(0.5), Color::Rgb(255, 255, 255));
+        assert_eq!(plasma.color_at(1.0), Color::Rgb(0, 0, 0));
+
+        let values = (0..40).flat_map(|x| (0..10).map(move |y| (x as f32, y as f32)));
+        assert!(values.map(|(x, y)| plasma.value(x, y)).all(|v| (0.0..=1.0).contains(&v)));
+    }
+
+    #[test]
+    fn test_plasma_animates() {
+        let palette = vec![Color::Red, Color::Blue, Color::Green];
+        let mut fx = fx::plasma(palette, 2.0);
+
+        let frames = effect_frames(&mut fx, Rect::new(0, 0, 20, 5), [Duration::ZERO, Duration::from_millis(250)]);
+        assert_ne!(frames[0], frames[1]);
+        assert!(frames[1].content.iter().all(|c| c.bg != Color::Reset));
+    }
+}
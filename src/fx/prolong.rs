@@ -1,21 +1,25 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, Shader};
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, RefCount, SelectionPolicy, Shader};
 use crate::Interpolation::Linear;
 use crate::widget::EffectSpan;
 
 /// Specifies the position where the additional duration should be applied in a `Prolong` effect.
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum ProlongPosition {
     Start,
     End,
+    /// Pauses the inner effect once it's reached the given share of its duration.
+    At(f32),
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Prolong {
     inner: Effect,
     timer: EffectTimer,
     position: ProlongPosition,
+    /// time processed by the inner effect, up to the point where it's held
+    consumed: Duration,
 }
 
 impl Prolong {
@@ -28,8 +32,15 @@ impl Prolong {
             inner,
             timer: additional_duration,
             position,
+            consumed: Duration::ZERO,
         }
     }
+
+    /// Returns the time into the inner effect at which it's held.
+    fn hold_point(&self, alpha: f32) -> Duration {
+        let inner_duration = self.inner.timer().unwrap_or_default().duration();
+        Duration::from_secs_f32(inner_duration.as_secs_f32() * alpha.clamp(0.0, 1.0))
+    }
 }
 
 /// A shader that wraps an inner effect and prolongs its duration either at the start or end.
@@ -38,6 +49,7 @@ impl Shader for Prolong {
         match self.position {
             ProlongPosition::Start => "prolong_start",
             ProlongPosition::End   => "prolong_end",
+            ProlongPosition::At(_) => "hold_at",
         }
     }
 
@@ -51,6 +63,21 @@ impl Shader for Prolong {
                 let overflow = self.inner.process(duration, buf, area);
                 self.timer.process(overflow?)
             }
+            ProlongPosition::At(alpha) => {
+                // the inner effect is processed once per frame, with the time left
+                // over before and after the hold
+                let hold_point = self.hold_point(alpha);
+                let before = hold_point.saturating_sub(self.consumed).min(duration);
+                self.consumed = self.consumed.saturating_add(before);
+
+                let rest = duration.saturating_sub(before);
+                let after = match rest.is_zero() {
+                    true  => Duration::ZERO,
+                    false => self.timer.process(rest).unwrap_or_default(),
+                };
+
+                self.inner.process(before.saturating_add(after), buf, area)
+            }
         }
     }
 
@@ -81,6 +108,14 @@ impl Shader for Prolong {
         self.inner.set_cell_selection(strategy);
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.inner.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.inner.set_input(input);
+    }
+
     /// Returns the total duration of the prolonged effect.
     ///
     /// # Returns
@@ -90,17 +125,22 @@ impl Shader for Prolong {
         let self_duration = self.timer.duration();
         let inner_duration = self.inner.timer().unwrap_or_default().duration();
 
-        Some(EffectTimer::new(self_duration + inner_duration, Linear))
+        Some(EffectTimer::new(self_duration.saturating_add(inner_duration), Linear))
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
         self.inner.cell_selection()
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        self.inner.dirty_region()
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         let inner_offset = match self.position {
-            ProlongPosition::Start => offset + self.timer.duration(),
-            ProlongPosition::End   => offset
+            ProlongPosition::Start => offset.saturating_add(self.timer.duration()),
+            ProlongPosition::End   => offset,
+            ProlongPosition::At(_) => offset,
         };
         EffectSpan::new(self, offset, vec![self.inner.as_effect_span(inner_offset)])
     }
@@ -108,5 +148,41 @@ impl Shader for Prolong {
     fn reset(&mut self) {
         self.timer.reset();
         self.inner.reset();
+        self.consumed = Duration::ZERO;
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Style};
+
+    use crate::testing::effect_frames;
+    use crate::{fx, Interpolatable, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_hold_at() {
+        let red = Color::Rgb(200, 0, 0);
+        let mut fx = fx::hold_at(0.5, 200, fx::fade_to_fg(red, (100, Interpolation::Linear)));
+
+        let mut content = Buffer::with_lines(["x"]);
+        content.set_style(content.area, Style::default().fg(Color::Rgb(0, 0, 0)));
+
+        let ms = Duration::from_millis;
+        let fg: Vec<Color> = effect_frames(&mut fx, &content, [ms(25), ms(50), ms(100), ms(75), ms(25), ms(25)])
+            .iter()
+            .map(|f| f[(0, 0)].fg)
+            .collect();
+
+        // held at 50ms from 75ms until 275ms, after which the fade resumes
+        let half = Color::Rgb(0, 0, 0).lerp(&red, 0.5);
+        assert_ne!(fg[0], half);
+        assert_eq!(fg[1..4], [half, half, half]);
+        assert_eq!(fg[4], Color::Rgb(0, 0, 0).lerp(&red, 0.75));
+        assert_eq!(fg[5], red);
+
+        assert!(fx.done());
+        assert_eq!(fx.timer().unwrap().duration(), ms(300));
+    }
+}
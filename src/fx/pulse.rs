@@ -0,0 +1,117 @@
+use core::f32::consts::TAU;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::{CellFilter, CellIterator, ColorMapper, Duration, EffectTimer, Interpolatable, Shader};
+
+/// Smoothly tints the foreground color towards a color and back, once per period.
+#[derive(Clone, Debug)]
+pub struct Pulse {
+    color: Color,
+    period: Duration,
+    /// time into the current period, in seconds
+    elapsed: f32,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Pulse {
+    pub fn new(color: Color, period: Duration) -> Self {
+        Self { color, period, elapsed: 0.0, area: None, cell_filter: CellFilter::All }
+    }
+
+    /// The strength of the tint: 0.0 at the start of each period, peaking at 1.0 halfway.
+    fn alpha(&self) -> f32 {
+        let period = self.period.as_secs_f32();
+        if period == 0.0 {
+            return 0.0;
+        }
+
+        0.5 - 0.5 * (TAU * self.elapsed / period).cos()
+    }
+}
+
+impl Shader for Pulse {
+    fn name(&self) -> &'static str {
+        "pulse"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let period = self.period.as_secs_f32();
+        if period > 0.0 {
+            self.elapsed = (self.elapsed + duration.as_secs_f32()).rem_euclid(period);
+        }
+
+        let alpha = self.alpha();
+        let cells = self.cell_iter(buf, area);
+        self.execute(alpha, area, cells);
+
+        None
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let mut fg_mapper = ColorMapper::default();
+        let color = self.color;
+
+        cell_iter.for_each(|(_, cell)| {
+            let fg = fg_mapper.map(cell.fg, alpha, |c| c.lerp(&color, alpha));
+            cell.set_fg(fg);
+        });
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use crate::fx;
+    use crate::testing::effect_frames;
+
+    use super::*;
+
+    #[test]
+    fn test_pulse() {
+        let mut content = Buffer::with_lines(["x"]);
+        content.set_style(content.area, Style::default().fg(Color::Rgb(0, 0, 0)));
+
+        let mut fx = fx::pulse(Color::Rgb(255, 255, 255), Duration::from_millis(400));
+        let frames = effect_frames(&mut fx, content, [Duration::from_millis(200); 2]);
+
+        assert_eq!(frames[0][(0, 0)].fg, Color::Rgb(255, 255, 255));
+        assert_eq!(frames[1][(0, 0)].fg, Color::Rgb(0, 0, 0));
+    }
+}
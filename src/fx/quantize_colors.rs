@@ -0,0 +1,185 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::{CellIterator, Duration};
+use crate::color_ext::{AsIndexedColor, ToRgbComponents};
+use crate::color_mapper::ColorMapper;
+use crate::CellFilter;
+use crate::shader::Shader;
+
+/// The target palette used by [quantize_colors](crate::fx::quantize_colors).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ColorPalette {
+    /// The 16 named terminal colors.
+    Ansi16,
+    /// The 256 indexed terminal colors.
+    #[default]
+    Ansi256,
+    /// A user-defined palette, e.g. the colors of the terminal theme. Colors
+    /// are mapped to the nearest palette entry; an empty palette leaves
+    /// colors unchanged.
+    Custom(Vec<Color>),
+}
+
+impl ColorPalette {
+    /// Returns the palette color closest to `color`.
+    pub fn nearest(&self, color: Color) -> Color {
+        match self {
+            ColorPalette::Ansi16  => nearest_color(ANSI16, color).unwrap_or(color),
+            ColorPalette::Ansi256 => color.as_indexed_color(),
+            ColorPalette::Custom(colors) => nearest_color(colors, color).unwrap_or(color),
+        }
+    }
+}
+
+fn nearest_color(palette: &[Color], color: Color) -> Option<Color> {
+    let (r, g, b) = color.to_rgb();
+    let distance = |c: &Color| {
+        let (pr, pg, pb) = c.to_rgb();
+        let dr = r as i32 - pr as i32;
+        let dg = g as i32 - pg as i32;
+        let db = b as i32 - pb as i32;
+        dr * dr + dg * dg + db * db
+    };
+
+    palette.iter()
+        .min_by_key(|c| distance(c))
+        .copied()
+}
+
+const ANSI16: &[Color] = &[
+    Color::Black,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Gray,
+    Color::DarkGray,
+    Color::LightRed,
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightBlue,
+    Color::LightMagenta,
+    Color::LightCyan,
+    Color::White,
+];
+
+#[derive(Clone, Default, Debug)]
+pub struct QuantizeColors {
+    palette: ColorPalette,
+    area: Option<Rect>,
+}
+
+impl QuantizeColors {
+    pub fn new(palette: ColorPalette) -> Self {
+        Self { palette, area: None }
+    }
+}
+
+impl Shader for QuantizeColors {
+    fn name(&self) -> &'static str {
+        "quantize_colors"
+    }
+
+    fn process(
+        &mut self,
+        _duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+        #[cfg(feature = "underline-color")]
+        let mut underline_mapper = ColorMapper::default();
+
+        let safe_area = area.intersection(buf.area);
+        for y in area.top()..safe_area.bottom() {
+            for x in area.left()..safe_area.right() {
+                let cell = buf.cell_mut(Position::new(x, y))?;
+                let fg = fg_mapper.map(cell.fg, 0.0, |c| self.palette.nearest(c));
+                let bg = bg_mapper.map(cell.bg, 0.0, |c| self.palette.nearest(c));
+
+                cell.set_fg(fg);
+                cell.set_bg(bg);
+
+                #[cfg(feature = "underline-color")]
+                if cell.underline_color != Color::Reset {
+                    cell.underline_color = underline_mapper
+                        .map(cell.underline_color, 0.0, |c| self.palette.nearest(c));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // handled by process
+    }
+
+    fn done(&self) -> bool { false }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quantize(palette: ColorPalette, fg: Color) -> Color {
+        let mut buf = Buffer::with_lines(["x"]);
+        buf.set_style(buf.area, fg);
+
+        let area = buf.area;
+        QuantizeColors::new(palette).process(Duration::from_millis(16), &mut buf, area);
+        buf.content[0].fg
+    }
+
+    #[test]
+    fn test_ansi16() {
+        assert_eq!(quantize(ColorPalette::Ansi16, Color::Rgb(250, 10, 5)), Color::LightRed);
+        assert_eq!(quantize(ColorPalette::Ansi16, Color::Rgb(5, 120, 130)), Color::Cyan);
+        assert_eq!(quantize(ColorPalette::Ansi16, Color::Rgb(3, 3, 3)), Color::Black);
+    }
+
+    #[test]
+    fn test_ansi256() {
+        assert_eq!(quantize(ColorPalette::Ansi256, Color::Rgb(255, 0, 0)), Color::Indexed(196));
+    }
+
+    #[test]
+    fn test_custom_palette() {
+        let palette = ColorPalette::Custom(vec![
+            Color::Rgb(40, 42, 54),
+            Color::Rgb(255, 121, 198),
+            Color::Rgb(80, 250, 123),
+        ]);
+
+        assert_eq!(quantize(palette.clone(), Color::Rgb(230, 100, 180)), Color::Rgb(255, 121, 198));
+        assert_eq!(quantize(palette.clone(), Color::Rgb(0, 0, 0)), Color::Rgb(40, 42, 54));
+        assert_eq!(quantize(palette, Color::LightGreen), Color::Rgb(80, 250, 123));
+    }
+
+    #[test]
+    fn test_empty_custom_palette_is_noop() {
+        let c = Color::Rgb(1, 2, 3);
+        assert_eq!(quantize(ColorPalette::Custom(vec![]), c), c);
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::color_mapper::ColorMapper;
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator, Interpolatable};
+
+#[derive(Clone, Debug)]
+pub struct RemapPalette {
+    mapping: HashMap<Color, Color>,
+    timer: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl RemapPalette {
+    pub fn new(mapping: HashMap<Color, Color>, timer: EffectTimer) -> Self {
+        Self { mapping, timer, area: None, cell_filter: CellFilter::All }
+    }
+
+    fn remap(&self, color: Color, alpha: f32) -> Color {
+        self.mapping.get(&color)
+            .map(|target| color.lerp(target, alpha))
+            .unwrap_or(color)
+    }
+}
+
+impl Shader for RemapPalette {
+    fn name(&self) -> &'static str {
+        "remap_palette"
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let mut fg_mapper = ColorMapper::default();
+        let mut bg_mapper = ColorMapper::default();
+        #[cfg(feature = "underline-color")]
+        let mut underline_mapper = ColorMapper::default();
+
+        cell_iter.for_each(|(_, cell)| {
+            let fg = fg_mapper.map(cell.fg, alpha, |c| self.remap(c, alpha));
+            let bg = bg_mapper.map(cell.bg, alpha, |c| self.remap(c, alpha));
+
+            cell.set_fg(fg);
+            cell.set_bg(bg);
+
+            #[cfg(feature = "underline-color")]
+            if cell.underline_color != Color::Reset {
+                cell.underline_color = underline_mapper
+                    .map(cell.underline_color, alpha, |c| self.remap(c, alpha));
+            }
+        });
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+
+    use crate::{Duration, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_remap_palette() {
+        let light_bg = Color::Rgb(250, 250, 250);
+        let dark_bg = Color::Rgb(20, 20, 20);
+        let accent = Color::Rgb(0, 128, 255);
+
+        let mut buf = Buffer::with_lines(["ab"]);
+        buf.set_style(Rect::new(0, 0, 1, 1), (accent, light_bg));
+        buf.set_style(Rect::new(1, 0, 1, 1), (Color::Red, Color::Blue));
+
+        let mut fx = RemapPalette::new(
+            HashMap::from([(light_bg, dark_bg), (accent, Color::Rgb(255, 128, 0))]),
+            EffectTimer::from_ms(100, Interpolation::Linear),
+        );
+
+        let area = buf.area;
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_ne!(buf.content[0].bg, light_bg);
+        assert_ne!(buf.content[0].bg, dark_bg);
+
+        // colors without a mapping are left untouched
+        assert_eq!(buf.content[1].fg, Color::Red);
+        assert_eq!(buf.content[1].bg, Color::Blue);
+
+        // remap the original colors once the transition is complete
+        let mut buf = Buffer::with_lines(["ab"]);
+        buf.set_style(Rect::new(0, 0, 1, 1), (accent, light_bg));
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert!(fx.done());
+        assert_eq!(buf.content[0].fg, Color::Rgb(255, 128, 0));
+        assert_eq!(buf.content[0].bg, dark_bg);
+    }
+}
@@ -1,12 +1,12 @@
 use ratatui::buffer::Buffer;
 use ratatui::prelude::Rect;
-use crate::{CellFilter, CellIterator, Duration, EffectTimer};
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, InputState, RefCount, SelectionPolicy};
 
 use crate::effect::Effect;
-use crate::widget::EffectSpan;
+use crate::widget::{EffectSpan, SpanIteration};
 use crate::shader::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Repeat {
     fx: Effect,
     mode: RepeatMode,
@@ -69,7 +69,7 @@ impl Shader for Repeat {
                 if d < duration {
                     let overflow = duration - d;
                     self.mode = RepeatMode::Duration(Duration::ZERO);
-                    self.process_effect(d, buf, area).map(|d| Some(d + overflow)).unwrap_or(Some(overflow))
+                    self.process_effect(d, buf, area).map(|d| Some(d.saturating_add(overflow))).unwrap_or(Some(overflow))
                 } else {
                     self.mode = RepeatMode::Duration(d - duration);
                     self.process_effect(duration, buf, area)
@@ -102,6 +102,14 @@ impl Shader for Repeat {
         self.fx.set_cell_selection(strategy);
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.fx.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.fx.set_input(input);
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         None
     }
@@ -114,8 +122,16 @@ impl Shader for Repeat {
         }
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        self.fx.dirty_region()
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
-        EffectSpan::new(self, offset, vec![self.fx.as_effect_span(offset)])
+        let child = self.fx.as_effect_span(offset);
+        let period = child.end - child.start;
+
+        EffectSpan::new(self, offset, vec![child])
+            .with_iteration(SpanIteration::Repeat { period })
     }
 
     fn cell_selection(&self) -> Option<CellFilter> {
@@ -128,7 +144,7 @@ impl Shader for Repeat {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum RepeatMode {
     Forever,
     Times(u32),
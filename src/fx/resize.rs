@@ -3,20 +3,20 @@ use ratatui::layout::Size;
 use ratatui::prelude::Rect;
 use ratatui::widgets::Clear;
 use ratatui::widgets::Widget;
-use crate::{CellFilter, CellIterator, Duration};
+use crate::{Anchor, CellFilter, RectExt, CellIterator, Duration, InputState, RefCount, SelectionPolicy};
 use crate::effect::Effect;
 use crate::effect_timer::EffectTimer;
 use crate::widget::EffectSpan;
 use crate::interpolation::Interpolatable;
-use crate::rect_ext::CenteredShrink;
 use crate::shader::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct ResizeArea {
     fx: Option<Effect>,
     area: Option<Rect>,
     original_area: Option<Rect>,
     initial_size: Size,
+    anchor: Anchor,
     timer: EffectTimer,
 }
 
@@ -26,7 +26,12 @@ impl ResizeArea {
         initial_size: Size,
         timer: EffectTimer
     ) -> Self {
-        Self { fx, initial_size, timer, original_area: None, area: None }
+        Self { fx, initial_size, anchor: Anchor::Center, timer, original_area: None, area: None }
+    }
+
+    pub fn with_anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
     }
 }
 
@@ -53,7 +58,7 @@ impl Shader for ResizeArea {
         let w = self.initial_size.width.lerp(&target_area.width, a);
         let h = self.initial_size.height.lerp(&target_area.height, a);
         
-        let resized_area = target_area.inner_centered(w, h);
+        let resized_area = target_area.anchored(self.anchor, Size::new(w, h));
         Clear.render(resized_area, buf);
         self.set_area(resized_area);
         
@@ -100,6 +105,18 @@ impl Shader for ResizeArea {
         }
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.propagate_cell_selection(filter, policy);
+        }
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_input(input);
+        }
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         Some(&mut self.timer)
     }
@@ -125,4 +142,31 @@ impl Shader for ResizeArea {
             fx.reset();
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpolation::Linear;
+
+    use super::*;
+
+    #[test]
+    fn test_resize_anchored() {
+        let screen = Rect::new(0, 0, 20, 10);
+        let mut buf = Buffer::empty(screen);
+
+        let timer = EffectTimer::from_ms(100, Linear);
+        let mut fx = ResizeArea::new(None, Size::new(0, 0), timer)
+            .with_anchor(Anchor::BottomRight);
+
+        // the area is resized based on the alpha before processing the frame
+        fx.process(Duration::from_millis(50), &mut buf, screen);
+        assert_eq!(fx.area(), Some(Rect::new(20, 10, 0, 0)));
+
+        fx.process(Duration::from_millis(50), &mut buf, screen);
+        assert_eq!(fx.area(), Some(Rect::new(10, 5, 10, 5)));
+
+        fx.process(Duration::from_millis(0), &mut buf, screen);
+        assert_eq!(fx.area(), Some(screen));
+    }
+}
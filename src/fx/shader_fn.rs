@@ -1,14 +1,18 @@
-use bon::{bon, builder, Builder};
+use std::fmt;
+
+use bon::{bon, Builder};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 
-use crate::{ref_count, CellFilter, CellIterator, Duration, EffectTimer, RefCount, Shader};
+use crate::{ref_count, CellFilter, CellIterator, Duration, EffectTimer, InputState, RefCount, Shader};
+use crate::input_state::read_input;
 use crate::fx::invoke_fn;
 use crate::ThreadSafetyMarker;
 
-#[derive(Builder, Clone)]
+#[derive(Builder, Clone, Debug)]
 pub struct ShaderFn<S: Clone> {
     state: S,
+    #[builder(skip = Some(state.clone()))]
     original_state: Option<S>,
     name: &'static str,
     code: ShaderFnSignature<S>,
@@ -18,6 +22,14 @@ pub struct ShaderFn<S: Clone> {
 
     cell_filter: Option<CellFilter>,
     area: Option<Rect>,
+
+    /// retains a copy of each frame's output, for [ShaderFnContext::previous_frame]
+    #[builder(default)]
+    track_previous_frame: bool,
+    #[builder(skip)]
+    previous_frame: Option<Buffer>,
+    #[builder(skip)]
+    input: Option<RefCount<InputState>>,
 }
 
 #[cfg(feature = "sendable")]
@@ -36,6 +48,15 @@ pub enum ShaderFnSignature<S> {
     Buffer(RefCount<FnBufSignature<S>>),
 }
 
+impl<S> fmt::Debug for ShaderFnSignature<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaderFnSignature::Iter(_)   => f.write_str("Iter(..)"),
+            ShaderFnSignature::Buffer(_) => f.write_str("Buffer(..)"),
+        }
+    }
+}
+
 impl<S> ShaderFnSignature<S> {
     pub fn new_iter<F>(f: F) -> Self
         where F: FnMut(&mut S, ShaderFnContext, CellIterator) + ThreadSafetyMarker + 'static
@@ -56,6 +77,14 @@ pub struct ShaderFnContext<'a> {
     pub timer: &'a EffectTimer,
     pub area: Rect,
     pub filter: Option<CellFilter>,
+    /// The area of the buffer as it was after the previous frame, for temporal
+    /// effects such as trails. Only available when the shader tracks the previous
+    /// frame, as with [effect_fn_buf_with_previous](crate::fx::effect_fn_buf_with_previous);
+    /// `None` on the first frame and after a reset.
+    pub previous_frame: Option<&'a Buffer>,
+    /// The latest user input, if the effect has been given an input state with
+    /// [Effect::with_input](crate::Effect::with_input).
+    pub input: Option<InputState>,
 }
 
 impl<'a> ShaderFnContext<'a> {
@@ -63,13 +92,17 @@ impl<'a> ShaderFnContext<'a> {
         area: Rect,
         filter: Option<CellFilter>,
         last_tick: Duration,
-        timer: &'a EffectTimer
+        timer: &'a EffectTimer,
+        previous_frame: Option<&'a Buffer>,
+        input: Option<InputState>,
     ) -> Self {
         Self {
             last_tick,
             timer,
             area,
             filter,
+            previous_frame,
+            input,
         }
     }
 
@@ -99,7 +132,10 @@ impl<S: Clone + ThreadSafetyMarker + 'static> ShaderFn<S> {
             code: ShaderFnSignature::new_iter(code),
             timer: timer.into(),
             cell_filter,
-            area
+            area,
+            track_previous_frame: false,
+            previous_frame: None,
+            input: None,
         }
     }
 
@@ -122,11 +158,25 @@ impl<S: Clone + ThreadSafetyMarker + 'static> ShaderFn<S> {
             code: ShaderFnSignature::new_buffer(code),
             timer: timer.into(),
             cell_filter,
-            area
+            area,
+            track_previous_frame: false,
+            previous_frame: None,
+            input: None,
         }
     }
 }
 
+impl<S: Clone> ShaderFn<S> {
+    fn retain_frame(&mut self, buf: &Buffer, area: Rect) {
+        let area = area.intersection(buf.area);
+        let previous = match self.previous_frame.as_mut() {
+            Some(previous) if previous.area == area => previous,
+            _ => self.previous_frame.insert(Buffer::empty(area)),
+        };
+
+        area.positions().for_each(|pos| previous[pos] = buf[pos].clone());
+    }
+}
 
 impl<S: Clone + ThreadSafetyMarker + 'static> Shader for ShaderFn<S> {
     fn name(&self) -> &'static str {
@@ -141,18 +191,24 @@ impl<S: Clone + ThreadSafetyMarker + 'static> Shader for ShaderFn<S> {
     ) -> Option<Duration> {
         let overflow = self.timer.process(duration);
 
+        let previous = self.previous_frame.as_ref();
+        let input = self.input.as_ref().map(read_input);
         match self.code.clone() {
             ShaderFnSignature::Iter(f) => {
-                let cells = self.cell_iter(buf, area);
-                let ctx = ShaderFnContext::new(area, self.cell_filter.clone(), duration, &self.timer);
+                let cells = CellIterator::new(buf, area, self.cell_filter.clone());
+                let ctx = ShaderFnContext::new(area, self.cell_filter.clone(), duration, &self.timer, previous, input);
                 invoke_fn!(f, &mut self.state, ctx, cells)
             }
             ShaderFnSignature::Buffer(f) => {
-                let ctx = ShaderFnContext::new(area, self.cell_filter.clone(), duration, &self.timer);
+                let ctx = ShaderFnContext::new(area, self.cell_filter.clone(), duration, &self.timer, previous, input);
                 invoke_fn!(f, &mut self.state, ctx, buf)
             }
         }
 
+        if self.track_previous_frame {
+            self.retain_frame(buf, area);
+        }
+
         overflow
     }
 
@@ -178,6 +234,10 @@ impl<S: Clone + ThreadSafetyMarker + 'static> Shader for ShaderFn<S> {
         self.cell_filter = Some(filter);
     }
 
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.input = Some(input);
+    }
+
     fn cell_selection(&self) -> Option<CellFilter> {
         self.cell_filter.clone()
     }
@@ -185,5 +245,6 @@ impl<S: Clone + ThreadSafetyMarker + 'static> Shader for ShaderFn<S> {
     fn reset(&mut self) {
         self.timer.reset();
         self.state = self.original_state.as_ref().unwrap().clone();
+        self.previous_frame = None;
     }
 }
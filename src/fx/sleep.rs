@@ -6,7 +6,7 @@ use crate::effect_timer::EffectTimer;
 use crate::widget::EffectSpan;
 use crate::shader::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Sleep {
     timer: EffectTimer,
 }
@@ -46,6 +46,10 @@ impl Shader for Sleep {
         Some(self.timer)
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        Some(Rect::default())
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         EffectSpan::new(self, offset, Vec::default())
     }
@@ -1,24 +1,29 @@
-use bon::{builder, Builder};
+use bon::Builder;
 use ratatui::buffer::{Buffer, Cell};
 use ratatui::layout::{Position, Rect};
 use ratatui::style::Color;
 
 use crate::fx::sliding_window_alpha::SlidingWindowAlpha;
-use crate::fx::{Direction, DirectionalVariance};
+use crate::fx::{Direction, DirectionalVariance, GradientLength, SweepJitter};
+use crate::wide_cells::WideCells;
 use crate::{CellFilter, CellIterator, Duration, EffectTimer, Shader};
 
 /// A shader that applies a directional sliding effect to terminal cells.
-#[derive(Builder, Clone)]
+#[derive(Builder, Clone, Debug)]
 pub struct SlideCell {
     /// The color behind the sliding cell.
     color_behind_cell: Color,
     /// The direction of the sliding effect.
     direction: Direction,
     /// The length of the gradient used for the sliding effect.
-    gradient_length: u16,
-    /// The extent of randomness applied to the sliding effect.
-    #[builder(default)]
-    randomness_extent: u16,
+    #[builder(into)]
+    gradient_length: GradientLength,
+    /// The random offsets applied per row or column of the sliding effect.
+    #[builder(default, into)]
+    jitter: SweepJitter,
+    /// The number of times the jitter has been rerolled.
+    #[builder(skip)]
+    run: u32,
     /// The timer controlling the duration and progress of the effect.
     #[builder(into)]
     timer: EffectTimer,
@@ -27,6 +32,9 @@ pub struct SlideCell {
     /// The cell selection strategy used to filter cells.
     #[builder(default)]
     cell_filter: CellFilter,
+    /// Scratch buffer for the per-column variances and progress, reused across frames.
+    #[builder(skip)]
+    col_variances: Vec<(i16, f32)>,
 }
 
 impl SlideCell {
@@ -57,17 +65,23 @@ impl Shader for SlideCell {
 
         let direction = self.direction;
 
-        let window_alpha = SlidingWindowAlpha::builder()
+        let gradient_len = self.gradient_length.resolve(area, direction) + self.jitter.extent() as f32;
+        let window = |progress: f32| SlidingWindowAlpha::builder()
             .direction(direction)
-            .progress(alpha)
+            .progress(progress)
             .area(area)
-            .gradient_len(self.gradient_length + self.randomness_extent)
+            .gradient_len(gradient_len)
             .build();
 
-        let mut axis_jitter = DirectionalVariance::from(area, direction, self.randomness_extent);
+        let mut axis_jitter = DirectionalVariance::from(area, direction, self.jitter, self.run);
 
-        let update_cell = |cell: &mut Cell, pos: Position| {
-            match window_alpha.alpha(pos) {
+        // scratch buffer reused across frames; taken to avoid borrowing self
+        let mut col_variances = std::mem::take(&mut self.col_variances);
+
+        // wide characters slide as a whole, following their leading cell
+        let mut units = WideCells::default();
+        let mut update_cell = |cell: &mut Cell, pos: Position, window_pos: Position, window_alpha: &SlidingWindowAlpha| {
+            match units.resolve(pos, cell, || window_alpha.alpha(window_pos)) {
                 0.0 => {},
                 1.0 => {
                     cell.set_char(' ');
@@ -83,30 +97,32 @@ impl Shader for SlideCell {
         };
 
         let safe_area = area.intersection(buf.area);
-        if self.randomness_extent == 0 || [Direction::LeftToRight, Direction::RightToLeft].contains(&direction) {
-            for y in area.y..area.y + safe_area.height {
+        if self.jitter.is_rigid() || [Direction::LeftToRight, Direction::RightToLeft].contains(&direction) {
+            for y in safe_area.top()..safe_area.bottom() {
                 let row_variance = axis_jitter.next();
-                for x in area.x..area.x + safe_area.width {
+                let window_alpha = window(axis_jitter.line_progress(alpha));
+                for x in safe_area.left()..safe_area.right() {
                     let pos = Position { x, y };
                     let cell = buf.cell_mut(pos).unwrap();
-                    update_cell(cell, offset(pos, row_variance));
+                    update_cell(cell, pos, offset(pos, row_variance), &window_alpha);
                 }
             }
         } else {
-            let col_variances = (area.x..area.x + area.width)
-                .map(|_| axis_jitter.next().1)
-                .collect::<Vec<i16>>();
+            col_variances.clear();
+            col_variances.extend((area.x..area.x + area.width)
+                .map(|_| (axis_jitter.next().1, axis_jitter.line_progress(alpha))));
 
-            for y in area.y..area.y + safe_area.height {
-                for x in area.x..area.x + safe_area.width {
+            for y in safe_area.top()..safe_area.bottom() {
+                for x in safe_area.left()..safe_area.right() {
                     let pos = Position { x, y };
-                    let col_variance = (0, col_variances[(x - area.x) as usize]);
+                    let (variance, progress) = col_variances[(x - area.x) as usize];
                     let cell = buf.cell_mut(pos).unwrap();
-                    update_cell(cell, offset(pos, col_variance));
+                    update_cell(cell, pos, offset(pos, (0, variance)), &window(progress));
                 }
             }
         }
 
+        self.col_variances = col_variances;
         overflow
     }
 
@@ -141,6 +157,13 @@ impl Shader for SlideCell {
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        if self.jitter.rerolls_on_reset() {
+            self.run = self.run.wrapping_add(1);
+        }
+    }
 }
 
 const SHRINK_V: &[char; 9] = &['█', '▇', '▆', '▅', '▄', '▃', '▂', '▁', ' '];
@@ -152,4 +175,44 @@ fn offset(p: Position, translate: (i16, i16)) -> Position {
         x: (p.x as i16 + translate.0).max(0) as _,
         y: (p.y as i16 + translate.1).max(0) as _,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::effect_frames;
+    use crate::{fx, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_wide_chars_slide_as_a_whole() {
+        let content = Buffer::with_lines(["中文字符测试"]);
+        let mut fx = fx::slide_out(Direction::LeftToRight, 4, 0, Color::Black, (100, Interpolation::Linear));
+
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(50); 2]);
+        let frame = &frames[0];
+        assert!((0..12).step_by(2).any(|x| frame[(x, 0)].symbol() != content[(x, 0)].symbol()));
+        assert!((0..12).step_by(2).any(|x| frame[(x, 0)].symbol() == content[(x, 0)].symbol()));
+        for x in (0..12).step_by(2) {
+            let (lead, trailing) = (&frame[(x, 0)], &frame[(x + 1, 0)]);
+            if lead.symbol() != content[(x, 0)].symbol() {
+                assert_eq!(lead.symbol(), trailing.symbol(), "at column {x}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_duration_spread_staggers_columns() {
+        let content = Buffer::with_lines(vec!["x".repeat(20); 10]);
+        let jitter = SweepJitter::new(0).with_duration_spread(0.8);
+        let mut fx = fx::slide_out(Direction::UpToDown, 0, jitter, Color::Black, (100, Interpolation::Linear));
+
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(50); 2]);
+        let slid_per_column: Vec<usize> = (0..20)
+            .map(|x| (0..10).filter(|&y| frames[0][(x, y)].symbol() != "x").count())
+            .collect();
+
+        assert!(slid_per_column.iter().any(|n| *n != slid_per_column[0]), "{slid_per_column:?}");
+        assert!(frames[1].content.iter().all(|c| c.symbol() == " "));
+    }
+}
@@ -0,0 +1,133 @@
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::fx::Direction;
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Interpolatable, Shader};
+
+/// Snapshots the rendered content of its area on the first frame, then slides the
+/// snapshot out of the area, revealing a background color.
+#[derive(Clone, Debug)]
+pub struct SlideContentOut {
+    direction: Direction,
+    color_behind: Color,
+    timer: EffectTimer,
+    area: Option<Rect>,
+    snapshot: Option<Buffer>,
+}
+
+impl SlideContentOut {
+    pub fn new(direction: Direction, color_behind: Color, timer: EffectTimer) -> Self {
+        Self { direction, color_behind, timer, area: None, snapshot: None }
+    }
+
+    fn offset(&self, area: Rect, alpha: f32) -> (i32, i32) {
+        let (w, h) = (area.width as i32, area.height as i32);
+        match self.direction {
+            Direction::LeftToRight => (0.lerp(&w, alpha), 0),
+            Direction::RightToLeft => (0.lerp(&-w, alpha), 0),
+            Direction::UpToDown    => (0, 0.lerp(&h, alpha)),
+            Direction::DownToUp    => (0, 0.lerp(&-h, alpha)),
+        }
+    }
+}
+
+impl Shader for SlideContentOut {
+    fn name(&self) -> &'static str {
+        "slide_content_out"
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let area = area.intersection(buf.area);
+
+        let (dx, dy) = self.offset(area, self.timer.alpha());
+        let snapshot = self.snapshot.get_or_insert_with(|| {
+            let mut snapshot = Buffer::empty(area);
+            area.positions().for_each(|pos| snapshot[pos] = buf[pos].clone());
+            snapshot
+        });
+
+        let mut behind = Cell::default();
+        behind.set_bg(self.color_behind);
+
+        for pos in area.positions() {
+            let src = (pos.x as i32 - dx, pos.y as i32 - dy);
+            let src = (u16::try_from(src.0), u16::try_from(src.1));
+
+            buf[pos] = match src {
+                (Ok(x), Ok(y)) if snapshot.area.contains(Position::new(x, y)) =>
+                    snapshot[(x, y)].clone(),
+                _ => behind.clone(),
+            };
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        self.snapshot = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpolation::Linear;
+
+    use super::*;
+
+    #[test]
+    fn test_slide_content_out() {
+        let timer = EffectTimer::from_ms(100, Linear);
+        let mut fx = SlideContentOut::new(Direction::LeftToRight, Color::Reset, timer);
+
+        let area = Rect::new(1, 0, 4, 2);
+        let mut buf = Buffer::with_lines(["xabcd", "xefgh"]);
+
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["x  ab", "x  ef"]));
+
+        // content rendered after the first frame is ignored
+        buf[(1, 0)].set_char('y');
+        fx.process(Duration::from_millis(25), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["x   a", "x   e"]));
+
+        fx.process(Duration::from_millis(25), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["x    ", "x    "]));
+        assert!(fx.done());
+    }
+}
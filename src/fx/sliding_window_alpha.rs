@@ -15,7 +15,7 @@ impl SlidingWindowAlpha {
         direction: Direction,
         area: Rect,
         progress: f32,
-        gradient_len: u16,
+        gradient_len: f32,
     ) -> Self {
         let alpha_fn = match direction {
             Direction::UpToDown    => slide_up,
@@ -40,8 +40,7 @@ impl SlidingWindowAlpha {
     }
 }
 
-fn gradient(progress: f32, coordinate: u16, area_len: u16, gradient_len: u16) -> Range<f32> {
-    let gradient_len = gradient_len as f32;
+fn gradient(progress: f32, coordinate: u16, area_len: u16, gradient_len: f32) -> Range<f32> {
     let start = (coordinate as f32 - gradient_len) + ((area_len as f32 + gradient_len) * progress);
     let end = start + gradient_len;
 
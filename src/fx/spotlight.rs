@@ -0,0 +1,161 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::input_state::read_input;
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, InputState, Interpolatable, RefCount, Shader};
+
+const DIMMED: Color = Color::Rgb(0, 0, 0);
+
+/// The width of the soft edge of the light, relative to the radius.
+const EDGE: f32 = 0.5;
+
+/// Dims the cells outside a circle of light centered on the mouse pointer.
+#[derive(Clone)]
+pub struct Spotlight {
+    radius: f32,
+    amount: f32,
+    input: Option<RefCount<InputState>>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Spotlight {
+    pub fn new(radius: f32, amount: f32) -> Self {
+        Self {
+            radius: radius.max(0.0),
+            amount: amount.clamp(0.0, 1.0),
+            input: None,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    /// Returns how far the cell at `pos` is dimmed, given the pointer position.
+    fn dimming(&self, pointer: Position, pos: Position) -> f32 {
+        // cells are about twice as tall as they are wide
+        let dx = pos.x as f32 - pointer.x as f32;
+        let dy = 2.0 * (pos.y as f32 - pointer.y as f32);
+        let distance = (dx * dx + dy * dy).sqrt();
+
+        let edge = (self.radius * EDGE).max(1.0);
+        self.amount * ((distance - self.radius) / edge).clamp(0.0, 1.0)
+    }
+}
+
+impl Shader for Spotlight {
+    fn name(&self) -> &'static str {
+        "spotlight"
+    }
+
+    fn process(&mut self, _duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let cells = self.cell_iter(buf, area);
+        self.execute(1.0, area, cells);
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let Some(pointer) = self.input.as_ref().and_then(|i| read_input(i).mouse_position) else {
+            return;
+        };
+
+        cell_iter.for_each(|(pos, cell)| {
+            let dimming = self.dimming(pointer, pos);
+            if dimming > 0.0 {
+                cell.set_fg(cell.fg.lerp(&DIMMED, dimming));
+                cell.set_bg(cell.bg.lerp(&DIMMED, dimming));
+            }
+        });
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.input = Some(input);
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use crate::{fx, ref_count};
+
+    use super::*;
+
+    #[test]
+    fn test_spotlight_follows_mouse() {
+        let area = Rect::new(0, 0, 9, 1);
+        let white = Color::Rgb(255, 255, 255);
+        let input = ref_count(InputState::default());
+
+        let mut spotlight = fx::spotlight(2.0, 1.0).with_input(input.clone());
+        let mut process = || {
+            let mut buf = Buffer::empty(area);
+            buf.set_style(area, Style::new().bg(white));
+            spotlight.process(Duration::from_millis(16), &mut buf, area);
+            (0..9).map(|x| if buf[(x, 0)].bg == white { '#' } else { '.' }).collect::<String>()
+        };
+
+        // nothing is dimmed without a mouse position
+        assert_eq!(process(), "#########");
+
+        #[cfg(not(feature = "sendable"))]
+        { input.borrow_mut().mouse_position = Some(Position::new(4, 0)); }
+        #[cfg(feature = "sendable")]
+        { input.lock().unwrap().mouse_position = Some(Position::new(4, 0)); }
+        assert_eq!(process(), "..#####..");
+    }
+
+    #[test]
+    fn test_input_reaches_nested_effects() {
+        let input = ref_count(InputState { mouse_position: None, last_key: Some('x') });
+        let seen = ref_count(None);
+
+        let observed = seen.clone();
+        let effect = fx::effect_fn((), 100, move |_, ctx, _| {
+            #[cfg(not(feature = "sendable"))]
+            { *observed.borrow_mut() = ctx.input.and_then(|i| i.last_key); }
+            #[cfg(feature = "sendable")]
+            { *observed.lock().unwrap() = ctx.input.and_then(|i| i.last_key); }
+        });
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut fx = fx::sequence(&[fx::never_complete(effect)]).with_input(input);
+        fx.process(Duration::from_millis(16), &mut Buffer::empty(area), area);
+
+        #[cfg(not(feature = "sendable"))]
+        assert_eq!(*seen.borrow(), Some('x'));
+        #[cfg(feature = "sendable")]
+        assert_eq!(*seen.lock().unwrap(), Some('x'));
+    }
+}
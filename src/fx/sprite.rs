@@ -0,0 +1,96 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Shader, Sprite, SpritePath};
+
+/// Renders a sprite, moving it along a path over the lifetime of the effect.
+#[derive(Clone, Debug)]
+pub struct AnimatedSprite {
+    sprite: Sprite,
+    path: SpritePath,
+    timer: EffectTimer,
+    area: Option<Rect>,
+}
+
+impl AnimatedSprite {
+    pub fn new(sprite: Sprite, path: SpritePath, timer: EffectTimer) -> Self {
+        Self { sprite, path, timer, area: None }
+    }
+}
+
+impl Shader for AnimatedSprite {
+    fn name(&self) -> &'static str {
+        "sprite"
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+
+        let offset = self.path.position(self.timer.alpha());
+        self.sprite.render(offset, area, buf);
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {}
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Offset;
+
+    use crate::Interpolation::Linear;
+
+    use super::*;
+
+    #[test]
+    fn test_sprite_moves_along_path() {
+        let sprite = Sprite::from_ansi("<>");
+        let path = SpritePath::Line(Offset { x: 0, y: 0 }, Offset { x: 4, y: 1 });
+        let mut fx = AnimatedSprite::new(sprite, path, EffectTimer::from_ms(100, Linear));
+
+        let area = Rect::new(1, 0, 6, 2);
+        let mut buf = Buffer::with_lines(["........", "........"]);
+        fx.process(Duration::from_millis(25), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["..<>....", "........"]));
+
+        let mut buf = Buffer::with_lines(["........", "........"]);
+        fx.process(Duration::from_millis(75), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["........", ".....<>."]));
+    }
+}
@@ -0,0 +1,168 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Shader};
+
+/// Star glyphs, from the nearest layer to the farthest.
+const GLYPHS: [&str; 4] = ["*", "+", "·", "."];
+
+/// The scroll offsets wrap around after this many columns; star positions repeat
+/// with the same period.
+const WRAP: f32 = 65536.0;
+
+/// Roughly one in `DENSITY` cells holds a star, per layer.
+const DENSITY: u32 = 48;
+
+/// Parallax-scrolling stars, drawn onto the blank cells of the area.
+#[derive(Clone, Debug)]
+pub struct Starfield {
+    /// columns per second, of the nearest layer
+    speed: f32,
+    /// the scroll offset of each layer, nearest first
+    offsets: Vec<f32>,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Starfield {
+    pub fn new(depth_layers: u8, speed: f32) -> Self {
+        Self {
+            speed,
+            offsets: vec![0.0; depth_layers as usize],
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    /// Returns the nearest layer with a star at the given cell, if any.
+    fn star_at(&self, x: u16, y: u16) -> Option<usize> {
+        self.offsets.iter().enumerate().find_map(|(layer, offset)| {
+            let column = (x as f32 + offset).floor() as u32 & (WRAP as u32 - 1);
+            (hash(column, y as u32, layer as u32) % DENSITY == 0).then_some(layer)
+        })
+    }
+
+    /// The glyph and color of stars in the layer; farther stars are dimmer.
+    fn star_style(&self, layer: usize) -> (&'static str, Color) {
+        let glyph = GLYPHS[layer.min(GLYPHS.len() - 1)];
+        let depth = layer as f32 / self.offsets.len() as f32;
+        let v = (255.0 - 175.0 * depth) as u8;
+
+        (glyph, Color::Rgb(v, v, v))
+    }
+}
+
+impl Shader for Starfield {
+    fn name(&self) -> &'static str {
+        "starfield"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let distance = self.speed * duration.as_secs_f32();
+        self.offsets.iter_mut().enumerate().for_each(|(layer, offset)| {
+            *offset = (*offset + distance / (layer + 1) as f32).rem_euclid(WRAP);
+        });
+
+        let cells = self.cell_iter(buf, area);
+        self.execute(1.0, area, cells);
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        cell_iter
+            .filter(|(_, cell)| cell.symbol() == " ")
+            .for_each(|(pos, cell)| {
+                if let Some(layer) = self.star_at(pos.x, pos.y) {
+                    let (glyph, color) = self.star_style(layer);
+                    cell.set_symbol(glyph);
+                    cell.set_fg(color);
+                }
+            });
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.offsets.iter_mut().for_each(|offset| *offset = 0.0);
+    }
+}
+
+/// Scatters the stars of each layer, deterministically.
+fn hash(x: u32, y: u32, layer: u32) -> u32 {
+    let mut h = x.wrapping_mul(0x9e37_79b1)
+        ^ y.wrapping_mul(0x85eb_ca77)
+        ^ layer.wrapping_mul(0xc2b2_ae3d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2c1b_3c6d);
+    h ^= h >> 12;
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+    use crate::testing::effect_frames;
+
+    use super::*;
+
+    fn stars(buf: &Buffer) -> Vec<(u16, u16)> {
+        let area = buf.area;
+        (area.top()..area.bottom())
+            .flat_map(|y| (area.left()..area.right()).map(move |x| (x, y)))
+            .filter(|&(x, y)| buf[(x, y)].symbol() != " ")
+            .collect()
+    }
+
+    #[test]
+    fn test_starfield_scrolls_nearest_layer() {
+        let content = Buffer::empty(Rect::new(0, 0, 80, 24));
+        let mut fx = fx::starfield(1, 10.0);
+
+        let frames = effect_frames(&mut fx, &content, [Duration::ZERO, Duration::from_millis(100)]);
+        let shifted: Vec<_> = stars(&frames[0]).into_iter()
+            .filter(|&(x, _)| x > 0)
+            .map(|(x, y)| (x - 1, y))
+            .collect();
+
+        assert!(!shifted.is_empty());
+        assert!(shifted.iter().all(|pos| stars(&frames[1]).contains(pos)));
+        assert!(frames[1].content.iter().all(|c| [" ", "*"].contains(&c.symbol())));
+    }
+
+    #[test]
+    fn test_starfield_keeps_content() {
+        let content = Buffer::with_lines(vec!["x".repeat(80); 4]);
+        let mut fx = fx::starfield(3, 10.0);
+
+        let frames = effect_frames(&mut fx, &content, [Duration::from_millis(100)]);
+        assert_eq!(frames[0], content);
+    }
+}
@@ -0,0 +1,142 @@
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::simple_rng::{RangeSampler, SimpleRng};
+use crate::{CellFilter, CellIterator, Interpolatable};
+
+/// The share of the timer covered by flashes; the remainder is split into gaps.
+const FLASH_SHARE: f32 = 0.4;
+
+/// Tints the background of the selected cells with a series of brief flashes,
+/// separated by randomized gaps.
+#[derive(Clone, Debug)]
+pub struct Strobe {
+    color: Color,
+    flash_count: u16,
+    timer: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+    rng: SimpleRng,
+}
+
+impl Strobe {
+    pub fn new(color: Color, flash_count: u16, timer: EffectTimer) -> Self {
+        Self {
+            color,
+            flash_count,
+            timer,
+            area: None,
+            cell_filter: CellFilter::All,
+            rng: SimpleRng::default(),
+        }
+    }
+
+    /// Returns the intensity of the flashes at `alpha`: fading from 1.0 at the start
+    /// of each flash to 0.0, and 0.0 between flashes.
+    fn intensity(&self, alpha: f32) -> f32 {
+        if self.flash_count == 0 {
+            return 0.0;
+        }
+
+        // the gaps before, between and after the flashes are drawn from a copy
+        // of the rng, so that they are the same every frame
+        let gap_weights = || {
+            let mut rng = self.rng;
+            (0..=self.flash_count).map(move |_| rng.gen_range(0.5..1.5))
+        };
+
+        let flash_len = FLASH_SHARE / self.flash_count as f32;
+        let gap_scale = (1.0 - FLASH_SHARE) / gap_weights().sum::<f32>();
+
+        let mut start = 0.0;
+        for weight in gap_weights().take(self.flash_count as usize) {
+            start += weight * gap_scale;
+            if (start..start + flash_len).contains(&alpha) {
+                return 1.0 - (alpha - start) / flash_len;
+            }
+            start += flash_len;
+        }
+
+        0.0
+    }
+}
+
+impl Shader for Strobe {
+    fn name(&self) -> &'static str {
+        "strobe"
+    }
+
+    fn execute(&mut self, alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        let intensity = self.intensity(alpha);
+        if intensity == 0.0 {
+            return;
+        }
+
+        cell_iter.for_each(|(_, cell)| {
+            cell.set_bg(cell.bg.lerp(&self.color, intensity));
+        });
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpolation;
+
+    use super::*;
+
+    #[test]
+    fn test_strobe_flash_count() {
+        let fx = Strobe::new(Color::White, 3, EffectTimer::from_ms(1000, Interpolation::Linear));
+
+        // count the rising edges of the flashes
+        let intensities: Vec<f32> = (0..=1000).map(|ms| fx.intensity(ms as f32 / 1000.0)).collect();
+        let flashes = intensities.windows(2)
+            .filter(|w| w[0] == 0.0 && w[1] > 0.0)
+            .count();
+
+        assert_eq!(flashes, 3);
+        assert_eq!(intensities[0], 0.0);
+        assert_eq!(intensities[1000], 0.0);
+        assert!(intensities.iter().all(|i| (0.0..=1.0).contains(i)));
+    }
+
+    #[test]
+    fn test_strobe_without_flashes() {
+        let fx = Strobe::new(Color::White, 0, EffectTimer::from_ms(1000, Interpolation::Linear));
+        assert_eq!(fx.intensity(0.5), 0.0);
+    }
+}
@@ -0,0 +1,114 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+
+use crate::effect_timer::EffectTimer;
+use crate::shader::Shader;
+use crate::{CellFilter, CellIterator, Duration};
+
+/// Applies a [Style] to the selected cells for as long as the timer is running.
+/// The cells are left untouched once the timer completes, restoring their
+/// original style.
+#[derive(Clone, Debug)]
+pub struct StyleOverride {
+    style: Style,
+    timer: EffectTimer,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl StyleOverride {
+    pub fn new(style: Style, timer: EffectTimer) -> Self {
+        Self { style, timer, area: None, cell_filter: CellFilter::All }
+    }
+}
+
+impl Shader for StyleOverride {
+    fn name(&self) -> &'static str {
+        "style"
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        area: Rect,
+    ) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        if !self.timer.done() {
+            let cells = self.cell_iter(buf, area);
+            self.execute(self.timer.alpha(), area, cells);
+        }
+
+        overflow
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, cell_iter: CellIterator) {
+        for (_, cell) in cell_iter {
+            cell.set_style(self.style);
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier, Stylize};
+
+    use crate::Interpolation;
+
+    use super::*;
+
+    #[test]
+    fn test_style_override() {
+        let style = Style::new().fg(Color::Black).bg(Color::Yellow).bold();
+        let mut fx = StyleOverride::new(style, EffectTimer::from_ms(100, Interpolation::Linear));
+
+        let mut buf = Buffer::with_lines(["ab"]);
+        buf[(1, 0)].set_fg(Color::Red).modifier = Modifier::ITALIC;
+        let original = buf.clone();
+
+        let area = buf.area;
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(buf[(0, 0)].fg, Color::Black);
+        assert_eq!(buf[(1, 0)].bg, Color::Yellow);
+        assert_eq!(buf[(1, 0)].modifier, Modifier::ITALIC | Modifier::BOLD);
+
+        // no longer applied once the effect completes
+        let mut buf = original.clone();
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert!(fx.done());
+        assert_eq!(buf, original);
+    }
+}
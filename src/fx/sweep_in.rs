@@ -4,46 +4,78 @@ use ratatui::prelude::Color;
 
 use Interpolation::CircOut;
 
+use crate::color_batch::for_each_row;
 use crate::effect_timer::EffectTimer;
 use crate::fx::sliding_window_alpha::SlidingWindowAlpha;
-use crate::fx::{Direction, DirectionalVariance};
+use crate::fx::{Direction, DirectionalVariance, GradientLength, SweepJitter};
 use crate::interpolation::{Interpolatable, Interpolation};
 use crate::shader::Shader;
 use crate::CellFilter;
 use crate::{CellIterator, ColorMapper, Duration};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct SweepIn {
-    gradient_length: u16,
-    randomness_extent: u16,
-    faded_color: Color,
+    gradient_length: GradientLength,
+    jitter: SweepJitter,
+    /// the number of times the jitter has been rerolled
+    run: u32,
+    faded_colors: Vec<(f32, Color)>,
     timer: EffectTimer,
     direction: Direction,
     area: Option<Rect>,
     cell_filter: CellFilter,
+    line_variances: Vec<((i16, i16), f32)>,
 }
 
 
 impl SweepIn {
     pub fn new(
         direction: Direction,
-        gradient_length: u16,
-        randomness: u16,
-        faded_color: Color,
+        gradient_length: GradientLength,
+        jitter: SweepJitter,
+        faded_colors: Vec<(f32, Color)>,
         lifetime: EffectTimer,
     ) -> Self {
+        let mut faded_colors = faded_colors;
+        faded_colors.sort_by(|a, b| a.0.total_cmp(&b.0));
+
         Self {
             direction,
             gradient_length,
-            randomness_extent: randomness,
-            faded_color,
+            jitter,
+            run: 0,
+            faded_colors,
             timer: if direction.flips_timer() { lifetime.reversed() } else { lifetime },
             area: None,
             cell_filter: CellFilter::All,
+            line_variances: Vec::new(),
         }
     }
 }
 
+/// Returns the color of `original` at alpha `a` of the sweep's leading edge.
+/// The faded colors are interpolated between their stops, with the last
+/// stop fading into the original color.
+fn faded_color(stops: &[(f32, Color)], original: Color, a: f32) -> Color {
+    let Some(&(last_pos, last_color)) = stops.last() else {
+        return original;
+    };
+
+    if a >= last_pos {
+        let a = if last_pos < 1.0 { (a - last_pos) / (1.0 - last_pos) } else { 1.0 };
+        return last_color.tween(&original, a, CircOut);
+    }
+
+    match stops.iter().position(|(pos, _)| *pos > a) {
+        Some(idx) if idx > 0 => {
+            let (p0, c0) = stops[idx - 1];
+            let (p1, c1) = stops[idx];
+            c0.lerp(&c1, (a - p0) / (p1 - p0))
+        }
+        _ => stops[0].1,
+    }
+}
+
 impl Shader for SweepIn {
     fn name(&self) -> &'static str {
         if self.timer.is_reversed() ^ self.direction.flips_timer() {
@@ -60,63 +92,71 @@ impl Shader for SweepIn {
 
         let direction = self.direction;
 
-        let window_alpha = SlidingWindowAlpha::builder()
+        let gradient_len = self.gradient_length.resolve(area, direction) + self.jitter.extent() as f32;
+        let window = |progress: f32| SlidingWindowAlpha::builder()
             .direction(direction)
-            .progress(alpha)
+            .progress(progress)
             .area(area)
-            .gradient_len(self.gradient_length + self.randomness_extent)
+            .gradient_len(gradient_len)
             .build();
 
-        let mut axis_jitter = DirectionalVariance::from(area, direction, self.randomness_extent);
-
-        let mut fg_mapper = ColorMapper::default();
-        let mut bg_mapper = ColorMapper::default();
-
-        let mut apply_alpha = |cell: &mut Cell, pos: Position| {
-            match window_alpha.alpha(pos) {
-                0.0 => {
-                    cell.set_fg(self.faded_color);
-                    cell.set_bg(self.faded_color);
-                },
-                1.0 => {} // nothing to do
-                a => {
-                    let fg = fg_mapper
-                        .map(cell.fg, a, |c| self.faded_color.tween(&c, a, CircOut));
-                    let bg = bg_mapper
-                        .map(cell.bg, a, |c| self.faded_color.tween(&c, a, CircOut));
-
-                    cell.set_fg(fg);
-                    cell.set_bg(bg);
-                }
-            }
-        };
+        let mut axis_jitter = DirectionalVariance::from(area, direction, self.jitter, self.run);
 
-        let safe_area = area.intersection(buf.area);
-        if self.randomness_extent == 0 || [Direction::LeftToRight, Direction::RightToLeft].contains(&direction) {
-            for y in area.y..area.y + safe_area.height {
-                let row_variance = axis_jitter.next();
-                for x in area.x..area.x + safe_area.width {
-                    let pos = Position { x, y };
-                    let cell = buf.cell_mut(pos).unwrap();
-                    apply_alpha(cell, offset(pos, row_variance));
-                }
-            }
-        } else {
-            let col_variances = (area.x..area.x + area.width)
-                .map(|_| axis_jitter.next().1)
-                .collect::<Vec<i16>>();
+        // variances and progress of each row, or each column when the jitter is applied
+        // across columns; scratch buffer reused across frames, taken to avoid borrowing self
+        let per_row = self.jitter.is_rigid()
+            || [Direction::LeftToRight, Direction::RightToLeft].contains(&direction);
+        let lines = if per_row { area.height } else { area.width };
+
+        let mut line_variances = std::mem::take(&mut self.line_variances);
+        line_variances.clear();
+        line_variances.extend((0..lines).map(|_| {
+            let variance = axis_jitter.next();
+            (variance, axis_jitter.line_progress(alpha))
+        }));
+
+        let faded_colors = &self.faded_colors;
+        let line_variances_ref = &line_variances;
+        for_each_row(buf, area, |row, cells| {
+            let mut fg_mapper = ColorMapper::default();
+            let mut bg_mapper = ColorMapper::default();
 
-            for y in area.y..area.y + safe_area.height {
-                for x in area.x..area.x + safe_area.width {
-                    let pos = Position { x, y };
-                    let cell = buf.cell_mut(pos).unwrap();
-                    let col_variance = (0, col_variances[(x - area.x) as usize]);
+            let mut apply_alpha = |cell: &mut Cell, pos: Position, window_alpha: &SlidingWindowAlpha| {
+                match window_alpha.alpha(pos) {
+                    0.0 => {
+                        let faded_color = faded_colors.first()
+                            .map_or(Color::Reset, |(_, c)| *c);
 
-                    apply_alpha(cell, offset(pos, col_variance));
+                        cell.set_fg(faded_color);
+                        cell.set_bg(faded_color);
+                    },
+                    1.0 => {} // nothing to do
+                    a => {
+                        let fg = fg_mapper.map(cell.fg, a, |c| faded_color(faded_colors, c, a));
+                        let bg = bg_mapper.map(cell.bg, a, |c| faded_color(faded_colors, c, a));
+
+                        cell.set_fg(fg);
+                        cell.set_bg(bg);
+                    }
+                }
+            };
+
+            let positions = (row.x..).map(|x| Position { x, y: row.y });
+            if per_row {
+                let (variance, progress) = line_variances_ref[(row.y - area.y) as usize];
+                let window_alpha = window(progress);
+                for (pos, cell) in positions.zip(cells) {
+                    apply_alpha(cell, offset(pos, variance), &window_alpha);
+                }
+            } else {
+                for (pos, cell) in positions.zip(cells) {
+                    let (variance, progress) = line_variances_ref[(pos.x - area.x) as usize];
+                    apply_alpha(cell, offset(pos, variance), &window(progress));
                 }
             }
-        }
+        });
 
+        self.line_variances = line_variances;
         overflow
     }
     fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
@@ -152,6 +192,13 @@ impl Shader for SweepIn {
     fn cell_selection(&self) -> Option<CellFilter> {
         Some(self.cell_filter.clone())
     }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        if self.jitter.rerolls_on_reset() {
+            self.run = self.run.wrapping_add(1);
+        }
+    }
 }
 
 fn offset(p: Position, translate: (i16, i16)) -> Position {
@@ -159,4 +206,58 @@ fn offset(p: Position, translate: (i16, i16)) -> Position {
         x: (p.x as i16 + translate.0).max(0) as _,
         y: (p.y as i16 + translate.1).max(0) as _,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sweep(faded_colors: Vec<(f32, Color)>) -> SweepIn {
+        let timer = EffectTimer::from_ms(100, Interpolation::Linear);
+        SweepIn::new(Direction::LeftToRight, 10.into(), SweepJitter::default(), faded_colors, timer)
+    }
+
+    #[test]
+    fn test_faded_color_single_stop() {
+        let fx = sweep(vec![(0.0, Color::Black)]);
+        let original = Color::Rgb(200, 100, 50);
+
+        assert_eq!(faded_color(&fx.faded_colors, original, 0.0), Color::Black.tween(&original, 0.0, CircOut));
+        assert_eq!(faded_color(&fx.faded_colors, original, 0.5), Color::Black.tween(&original, 0.5, CircOut));
+        assert_eq!(faded_color(&fx.faded_colors, original, 1.0), original);
+    }
+
+    #[test]
+    fn test_faded_color_gradient_stops() {
+        let purple = Color::Rgb(136, 57, 239);
+        let fx = sweep(vec![(0.5, purple), (0.0, Color::Black)]);
+        let original = Color::Rgb(200, 100, 50);
+
+        assert_eq!(faded_color(&fx.faded_colors, original, 0.0), Color::Black);
+        assert_eq!(faded_color(&fx.faded_colors, original, 0.25), Color::Black.lerp(&purple, 0.5));
+        assert_eq!(faded_color(&fx.faded_colors, original, 0.5), purple);
+        assert_eq!(faded_color(&fx.faded_colors, original, 1.0), original);
+    }
+
+    #[test]
+    fn test_reroll_jitter_on_reset() {
+        let area = Rect::new(0, 0, 40, 10);
+        let half_sweep = |fx: &mut SweepIn| {
+            let mut buf = Buffer::empty(area);
+            buf.set_style(area, ratatui::style::Style::new().bg(Color::White));
+            fx.process(Duration::from_millis(50), &mut buf, area);
+            fx.reset();
+            buf
+        };
+
+        let jitter = SweepJitter::new(8).with_seed(1);
+        let timer = EffectTimer::from_ms(100, Interpolation::Linear);
+        let sweep = |jitter| SweepIn::new(Direction::UpToDown, 2.into(), jitter, vec![(0.0, Color::Black)], timer);
+
+        let mut fx = sweep(jitter);
+        assert_eq!(half_sweep(&mut fx), half_sweep(&mut fx));
+
+        let mut fx = sweep(jitter.with_reroll_on_reset(true));
+        assert_ne!(half_sweep(&mut fx), half_sweep(&mut fx));
+    }
+}
@@ -1,13 +1,13 @@
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use crate::{CellFilter, CellIterator, Duration};
+use crate::{CellFilter, CellIterator, Duration, InputState, RefCount, SelectionPolicy};
 use crate::effect::{Effect, IntoEffect};
 use crate::effect_timer::EffectTimer;
 use crate::widget::EffectSpan;
 use crate::interpolation::Interpolation::Linear;
 use crate::shader::Shader;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct TemporaryEffect {
     effect: Effect,
     timer: EffectTimer,
@@ -60,6 +60,14 @@ impl Shader for TemporaryEffect {
         self.effect.set_cell_selection(strategy);
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.effect.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.effect.set_input(input);
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         Some(&mut self.timer)
     }
@@ -68,6 +76,10 @@ impl Shader for TemporaryEffect {
         Some(self.timer)
     }
 
+    fn dirty_region(&self) -> Option<Rect> {
+        self.effect.dirty_region()
+    }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         EffectSpan::new(self, offset, vec![self.effect.as_effect_span(offset)])
     }
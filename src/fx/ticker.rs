@@ -0,0 +1,224 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::text::Line;
+
+use crate::{ref_count, CellFilter, CellIterator, Duration, EffectTimer, RefCount, Shader};
+use crate::fx::Direction;
+
+/// The content scrolled by a [Ticker]: a line of text, or a shared buffer with
+/// pre-rendered content.
+#[derive(Clone, Debug)]
+pub struct TickerContent(RefCount<Buffer>);
+
+impl From<RefCount<Buffer>> for TickerContent {
+    fn from(buf: RefCount<Buffer>) -> Self {
+        Self(buf)
+    }
+}
+
+impl From<Line<'_>> for TickerContent {
+    fn from(line: Line<'_>) -> Self {
+        let width = line.width() as u16;
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        buf.set_line(0, 0, &line, width);
+        Self(ref_count(buf))
+    }
+}
+
+impl From<&str> for TickerContent {
+    fn from(text: &str) -> Self {
+        Line::from(text).into()
+    }
+}
+
+impl From<String> for TickerContent {
+    fn from(text: String) -> Self {
+        Line::from(text).into()
+    }
+}
+
+/// Continuously scrolls content through the effect area, wrapping around with an
+/// optional gap between repetitions of the content.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::fx::{Direction, Ticker};
+/// use tachyonfx::IntoEffect;
+///
+/// let status = Ticker::new("build #412 passed · 3 warnings", 8.0, Direction::RightToLeft)
+///     .with_gap(6)
+///     .into_effect()
+///     .with_area(Rect::new(0, 23, 80, 1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ticker {
+    content: TickerContent,
+    /// cells per second
+    speed: f32,
+    direction: Direction,
+    gap: u16,
+    /// distance scrolled, in cells, within the period of the content and gap
+    scrolled: f32,
+    area: Option<Rect>,
+    cell_filter: CellFilter,
+}
+
+impl Ticker {
+    /// Scrolls the content at `speed` cells per second, in the given direction.
+    pub fn new<C: Into<TickerContent>>(content: C, speed: f32, direction: Direction) -> Self {
+        Self {
+            content: content.into(),
+            speed,
+            direction,
+            gap: 0,
+            scrolled: 0.0,
+            area: None,
+            cell_filter: CellFilter::All,
+        }
+    }
+
+    /// Leaves `gap` cells between repetitions of the content.
+    pub fn with_gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    fn is_horizontal(&self) -> bool {
+        matches!(self.direction, Direction::LeftToRight | Direction::RightToLeft)
+    }
+}
+
+impl Shader for Ticker {
+    fn name(&self) -> &'static str {
+        "ticker"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        let content_area = {
+            #[cfg(not(feature = "sendable"))]
+            let content = self.content.0.borrow();
+            #[cfg(feature = "sendable")]
+            let content = self.content.0.lock().unwrap();
+            content.area
+        };
+
+        let length = if self.is_horizontal() { content_area.width } else { content_area.height };
+        let period = (length + self.gap) as f32;
+        if period > 0.0 {
+            self.scrolled = (self.scrolled + self.speed * duration.as_secs_f32()).rem_euclid(period);
+        }
+
+        let cells = self.cell_iter(buf, area);
+        self.execute(1.0, area, cells);
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, area: Rect, cell_iter: CellIterator) {
+        #[cfg(not(feature = "sendable"))]
+        let content = self.content.0.borrow();
+        #[cfg(feature = "sendable")]
+        let content = self.content.0.lock().unwrap();
+
+        let src = content.area;
+        let horizontal = self.is_horizontal();
+        let (length, depth) = if horizontal { (src.width, src.height) } else { (src.height, src.width) };
+        let period = (length + self.gap) as i32;
+        if period == 0 {
+            return;
+        }
+
+        let shift = match self.direction {
+            Direction::RightToLeft | Direction::DownToUp => self.scrolled as i32,
+            Direction::LeftToRight | Direction::UpToDown => -(self.scrolled as i32),
+        };
+
+        for (pos, cell) in cell_iter {
+            let (along, across) = if horizontal {
+                (pos.x - area.x, pos.y - area.y)
+            } else {
+                (pos.y - area.y, pos.x - area.x)
+            };
+
+            let u = (along as i32 + shift).rem_euclid(period) as u16;
+            if u >= length || across >= depth {
+                continue;
+            }
+
+            let (x, y) = if horizontal { (u, across) } else { (across, u) };
+            if let Some(src_cell) = content.cell(Position::new(src.x + x, src.y + y)) {
+                if !src_cell.skip {
+                    *cell = src_cell.clone();
+                }
+            }
+        }
+    }
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.cell_filter = strategy;
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        Some(self.cell_filter.clone())
+    }
+
+    fn reset(&mut self) {
+        self.scrolled = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fx, IntoEffect};
+
+    use super::*;
+
+    fn frames(mut fx: crate::Effect, area: Rect, ticks: &[Duration]) -> Vec<Buffer> {
+        crate::testing::effect_frames(&mut fx, area, ticks.iter().copied())
+    }
+
+    #[test]
+    fn test_ticker_scrolls_left_with_gap() {
+        let fx = Ticker::new("abc", 10.0, Direction::RightToLeft).with_gap(2);
+        let frames = frames(fx.into_effect(), Rect::new(0, 0, 7, 1), &[0, 100, 100, 300].map(Duration::from_millis));
+
+        assert_eq!(frames, [
+            Buffer::with_lines(["abc  ab"]),
+            Buffer::with_lines(["bc  abc"]),
+            Buffer::with_lines(["c  abc "]),
+            Buffer::with_lines(["abc  ab"]),
+        ]);
+    }
+
+    #[test]
+    fn test_ticker_scrolls_down() {
+        let content = ref_count(Buffer::with_lines(["x", "y"]));
+        let frames = frames(fx::ticker(content, 10.0, Direction::UpToDown), Rect::new(0, 0, 2, 3), &[0, 100].map(Duration::from_millis));
+
+        assert_eq!(frames, [
+            Buffer::with_lines(["x ", "y ", "x "]),
+            Buffer::with_lines(["y ", "x ", "y "]),
+        ]);
+    }
+}
@@ -0,0 +1,147 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::{CellFilter, CellIterator, Duration, Effect, EffectTimer, InputState, Interpolation, RefCount, SelectionPolicy, Shader};
+use crate::widget::EffectSpan;
+
+/// Remaps the time passed to the inner effect along an easing curve, over the total
+/// duration of the inner effect.
+#[derive(Clone, Debug)]
+pub struct TimeWarp {
+    fx: Effect,
+    timer: Option<EffectTimer>,
+    consumed: Duration,
+}
+
+impl TimeWarp {
+    pub fn new(fx: Effect, curve: Interpolation) -> Self {
+        let timer = fx.timer().map(|t| EffectTimer::new(t.duration(), curve));
+        Self { fx, timer, consumed: Duration::ZERO }
+    }
+}
+
+impl Shader for TimeWarp {
+    fn name(&self) -> &'static str {
+        "time_warp"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        // effects without a duration can't be warped
+        let Some(timer) = self.timer.as_mut() else {
+            return self.fx.process(duration, buf, area);
+        };
+
+        let overflow = timer.process(duration);
+
+        // time only moves forward; curves overshooting backwards hold the inner effect
+        let total = timer.duration();
+        let warped = match overflow {
+            Some(_) => total,
+            None    => Duration::from_secs_f32(total.as_secs_f32() * timer.alpha().clamp(0.0, 1.0)),
+        };
+        let delta = warped.min(total).saturating_sub(self.consumed);
+        self.consumed = self.consumed.saturating_add(delta);
+
+        let inner_overflow = self.fx.process(delta, buf, area);
+        overflow.map(|o| o.saturating_add(inner_overflow.unwrap_or_default()))
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        self.fx.done()
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.fx.area()
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.fx.set_area(area);
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        self.fx.set_cell_selection(strategy);
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        self.fx.propagate_cell_selection(filter, policy);
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        self.fx.set_input(input);
+    }
+
+    fn reverse(&mut self) {
+        self.fx.reverse();
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        self.fx.timer()
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.cell_selection()
+    }
+
+    fn dirty_region(&self) -> Option<Rect> {
+        self.fx.dirty_region()
+    }
+
+    fn as_effect_span(&self, offset: Duration) -> EffectSpan {
+        EffectSpan::new(self, offset, vec![self.fx.as_effect_span(offset)])
+    }
+
+    fn reset(&mut self) {
+        if let Some(timer) = self.timer.as_mut() {
+            timer.reset();
+        }
+        self.consumed = Duration::ZERO;
+        self.fx.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Style};
+
+    use crate::fx;
+    use crate::testing::effect_frames;
+    use crate::Interpolation::{Linear, QuadIn};
+
+    use super::*;
+
+    #[test]
+    fn test_time_warp_eases_inner_time() {
+        let mut content = Buffer::with_lines(["abcd"]);
+        content.set_style(content.area, Style::default().fg(Color::Rgb(0, 0, 0)));
+        let ticks = [Duration::from_millis(100); 2];
+        let fade = |timer: (u32, Interpolation)| fx::fade_to_fg(Color::Rgb(200, 100, 0), timer);
+
+        let frames = effect_frames(&mut fx::time_warp(fade((200, Linear)), QuadIn), &content, ticks);
+        assert_eq!(frames, effect_frames(&mut fade((200, QuadIn)), &content, ticks));
+        assert_ne!(frames, effect_frames(&mut fade((200, Linear)), &content, ticks));
+    }
+
+    #[test]
+    fn test_time_warp_sequence_overflow() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        let area = buf.area;
+        let mut fx = fx::time_warp(fx::sequence(&[fx::sleep(100), fx::sleep(100)]), QuadIn);
+
+        // a quarter of the inner time has passed halfway through
+        assert_eq!(fx.process(Duration::from_millis(100), &mut buf, area), None);
+        assert!(fx.running());
+
+        assert_eq!(fx.process(Duration::from_millis(150), &mut buf, area), Some(Duration::from_millis(50)));
+        assert!(fx.done());
+    }
+}
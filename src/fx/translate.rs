@@ -2,18 +2,20 @@ use ratatui::buffer::Buffer;
 use ratatui::prelude::Rect;
 
 use crate::bounding_box::BoundingBox;
-use crate::{CellFilter, CellIterator, Duration};
+use crate::{CellFilter, CellIterator, Duration, InputState, RefCount, SelectionPolicy};
 use crate::effect::Effect;
 use crate::effect_timer::EffectTimer;
 use crate::interpolation::Interpolatable;
 use crate::shader::Shader;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Debug)]
 pub struct Translate {
     fx: Option<Effect>,
     area: Option<Rect>,
     original_area: Option<BoundingBox>,
     translate_by: (f32, f32),
+    /// translate_by is a fraction of the width and height of the area
+    relative: bool,
     timer: EffectTimer,
 }
 
@@ -27,11 +29,29 @@ impl Translate {
         let translate_by = (dx as f32, dy as f32);
         Self { fx, translate_by, timer: lifetime, ..Self::default() }
     }
+
+    /// Translates by fractions of the width and height of the effect area.
+    pub fn new_relative(
+        fx: Option<Effect>,
+        translate_by: (f32, f32),
+        lifetime: EffectTimer
+    ) -> Self {
+        Self { fx, translate_by, relative: true, timer: lifetime, ..Self::default() }
+    }
+
+    fn translation(&self, area: &BoundingBox) -> (f32, f32) {
+        let (dx, dy) = self.translate_by;
+        if self.relative {
+            (dx * area.width, dy * area.height)
+        } else {
+            (dx, dy)
+        }
+    }
 }
 
 impl Shader for Translate {
     fn name(&self) -> &'static str {
-        "translate_by"
+        if self.relative { "translate_rel" } else { "translate_by" }
     }
 
     fn process(
@@ -47,10 +67,12 @@ impl Shader for Translate {
             self.original_area = Some(BoundingBox::from_rect(area));
         }
 
-        let (dx, dy) = (0.0, 0.0).lerp(&self.translate_by, alpha);
         let translated_area = self.original_area.as_ref()
-            .map(|a| a.translate(dx, dy))
-            .and_then(|a| a.as_rect(buf.area));
+            .map(|a| {
+                let (dx, dy) = (0.0, 0.0).lerp(&self.translation(a), alpha);
+                a.translate(dx, dy)
+            })
+            .and_then(|a| a.to_rect(buf.area));
 
         self.area = translated_area;
 
@@ -98,6 +120,18 @@ impl Shader for Translate {
         }
     }
 
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.propagate_cell_selection(filter, policy);
+        }
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_input(input);
+        }
+    }
+
     fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
         Some(&mut self.timer)
     }
@@ -228,6 +262,35 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_translate_relative() {
+        let fx = Translate::new_relative(None, (0.5, -0.75), EffectTimer::from_ms(100, Linear));
+        assert_translation_fx(fx.clone(), 50, Buffer::with_lines([
+            "                    ",
+            "                    ",
+            "        ┌hello───┐  ",
+            "        │        │  ",
+            "        │        │  ",
+            "        └────────┘  ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+        ]));
+        assert_translation_fx(fx, 100, Buffer::with_lines([
+            "          ┌hello───┐",
+            "          │        │",
+            "          │        │",
+            "          └────────┘",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+            "                    ",
+        ]));
+    }
+
     #[test]
     fn translate_reversed() {
         let mut fx = translate_fx((-5, -3));
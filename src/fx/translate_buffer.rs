@@ -1,21 +1,26 @@
-use ratatui::buffer::Buffer;
-use ratatui::layout::{Offset, Rect};
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Offset, Position, Rect};
+use ratatui::style::Color;
 
-use crate::{BufferRenderer, CellFilter, CellIterator, Duration, EffectTimer, Interpolatable, RefCount, Shader};
+use crate::{BufferRef, BufferRenderer, CellFilter, CellIterator, Duration, EffectTimer, Interpolatable, RefCount, Shader};
 
 /// Translates the contents of an auxiliary buffer onto the main buffer.
 ///
 /// This shader allows for efficient translation of pre-rendered content without
 /// having to re-render it on every frame. It's particularly useful for large or
 /// complex content that doesn't change frequently.
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct TranslateBuffer {
     /// The auxiliary buffer containing the pre-rendered content to be translated.
-    aux_buffer: RefCount<Buffer>,
+    aux_buffer: BufferRef,
     /// The offset to translate the buffer by.
     translate_by: Offset,
     /// Timer controlling the duration and progress of the translation effect.
     timer: EffectTimer,
+    /// Renders vertical motion in half-cell increments when true.
+    half_blocks: bool,
+    /// Wraps content exiting one edge of the aux buffer's area around to the opposite edge.
+    wrap: bool,
 }
 
 impl TranslateBuffer {
@@ -25,9 +30,10 @@ impl TranslateBuffer {
     ///
     /// * `translate_by` - The final offset to translate the buffer by.
     /// * `timer` - The timer controlling the duration and interpolation of the effect.
-    /// * `aux_buffer` - The auxiliary buffer containing the pre-rendered content.
+    /// * `aux_buffer` - The auxiliary buffer containing the pre-rendered content. Nothing
+    ///   is rendered once a weakly referenced buffer has been dropped.
     pub fn new(
-        aux_buffer: RefCount<Buffer>,
+        aux_buffer: BufferRef,
         translate_by: Offset,
         timer: EffectTimer,
     ) -> Self {
@@ -35,6 +41,123 @@ impl TranslateBuffer {
             timer,
             aux_buffer,
             translate_by,
+            half_blocks: false,
+            wrap: false,
+        }
+    }
+
+    /// Enables rendering vertical motion in half-cell increments, composing the
+    /// colors of adjacent rows with `▀` glyphs on in-between frames.
+    pub fn with_half_blocks(mut self) -> Self {
+        self.half_blocks = true;
+        self
+    }
+
+    /// Wraps the translated content around the edges of the aux buffer's area, which
+    /// is rendered in place, at its own position. Motion is rendered in whole cells.
+    pub fn with_wrapping(mut self) -> Self {
+        self.wrap = true;
+        self
+    }
+
+}
+
+fn render_wrapped_frame(aux_buffer: &RefCount<Buffer>, offset: Offset, buf: &mut Buffer) {
+    #[cfg(not(feature = "sendable"))]
+    let aux_buffer = aux_buffer.as_ref().borrow();
+    #[cfg(feature = "sendable")]
+    let aux_buffer = aux_buffer.lock().unwrap();
+
+    render_wrapped(&aux_buffer, offset, buf);
+}
+
+fn render_half_block_frame(aux_buffer: &RefCount<Buffer>, offset: Offset, buf: &mut Buffer) {
+    #[cfg(not(feature = "sendable"))]
+    let aux_buffer = aux_buffer.as_ref().borrow();
+    #[cfg(feature = "sendable")]
+    let aux_buffer = aux_buffer.lock().unwrap();
+
+    render_half_row_offset(&aux_buffer, offset, buf);
+}
+
+/// Renders `src` onto `dst` within the area of `src`, shifted by `offset`. Cells
+/// shifted past an edge re-enter from the opposite edge.
+fn render_wrapped(src: &Buffer, offset: Offset, dst: &mut Buffer) {
+    let src_area = src.area;
+    if src_area.is_empty() {
+        return;
+    }
+
+    let (w, h) = (src_area.width as i32, src_area.height as i32);
+    let window = src_area.intersection(dst.area);
+    for y in window.top()..window.bottom() {
+        for x in window.left()..window.right() {
+            let sx = (x as i32 - src_area.x as i32 - offset.x).rem_euclid(w);
+            let sy = (y as i32 - src_area.y as i32 - offset.y).rem_euclid(h);
+
+            let cell = &src[(src_area.x + sx as u16, src_area.y + sy as u16)];
+            if !cell.skip {
+                dst[(x, y)] = cell.clone();
+            }
+        }
+    }
+}
+
+/// Renders `src` onto `dst`, shifted down by an additional half row.
+///
+/// Each destination cell is composed from the lower half of the source row above and
+/// the upper half of the source row below, as `▀` with the colors of both rows, or as
+/// `▄` when the upper half has no color. Glyphs can't be split, so source cells
+/// containing anything but blanks or full blocks are rendered as-is, at the upper of
+/// the two rows they straddle.
+fn render_half_row_offset(src: &Buffer, offset: Offset, dst: &mut Buffer) {
+    fn is_solid(cell: &Cell) -> bool {
+        matches!(cell.symbol(), " " | "█")
+    }
+
+    fn color_of(cell: &Cell) -> Color {
+        if cell.symbol() == "█" { cell.fg } else { cell.bg }
+    }
+
+    let src_area = src.area;
+    let src_cell = |x: i32, y: i32| -> Option<&Cell> {
+        if x < 0 || y < 0 || x >= src_area.width as i32 || y >= src_area.height as i32 {
+            return None;
+        }
+
+        let cell = &src[(src_area.x + x as u16, src_area.y + y as u16)];
+        (!cell.skip).then_some(cell)
+    };
+
+    let dst_area = dst.area;
+    for y in offset.y..=(offset.y + src_area.height as i32) {
+        for x in offset.x..(offset.x + src_area.width as i32) {
+            let (Ok(dx), Ok(dy)) = (u16::try_from(x), u16::try_from(y)) else { continue };
+            if !dst_area.contains(Position::new(dx, dy)) {
+                continue;
+            }
+
+            let (sx, sy) = (x - offset.x, y - offset.y);
+            let upper = src_cell(sx, sy - 1);
+            let lower = src_cell(sx, sy);
+
+            let cell = &mut dst[(dx, dy)];
+            match lower {
+                Some(lower) if !is_solid(lower) => *cell = lower.clone(),
+                _ if upper.is_none() && lower.is_none() => {},
+                _ => {
+                    let upper = upper.map(color_of).unwrap_or(cell.bg);
+                    let lower = lower.map(color_of).unwrap_or(cell.bg);
+                    cell.reset();
+
+                    // the default foreground color can't stand in for a reset background
+                    match (upper, lower) {
+                        (Color::Reset, Color::Reset) => cell.set_char(' '),
+                        (Color::Reset, lower)        => cell.set_char('▄').set_fg(lower),
+                        (upper, lower)               => cell.set_char('▀').set_fg(upper).set_bg(lower),
+                    };
+                },
+            }
         }
     }
 }
@@ -53,8 +176,27 @@ impl Shader for TranslateBuffer {
         let overflow = self.timer.process(duration);
         let alpha = self.timer.alpha();
 
-        let offset = Offset::default().lerp(&self.translate_by, alpha);
-        self.aux_buffer.render_buffer(offset, buf);
+        let Some(aux_buffer) = self.aux_buffer.upgrade() else {
+            return overflow; // the weakly referenced buffer is gone
+        };
+
+        if self.wrap {
+            let offset = Offset::default().lerp(&self.translate_by, alpha);
+            render_wrapped_frame(&aux_buffer, offset, buf);
+        } else if self.half_blocks {
+            let x = 0.lerp(&self.translate_by.x, alpha);
+            let half_rows = 0.lerp(&(self.translate_by.y * 2), alpha);
+            let offset = Offset { x, y: half_rows.div_euclid(2) };
+
+            if half_rows.rem_euclid(2) == 1 {
+                render_half_block_frame(&aux_buffer, offset, buf);
+            } else {
+                aux_buffer.render_buffer(offset, buf);
+            }
+        } else {
+            let offset = Offset::default().lerp(&self.translate_by, alpha);
+            aux_buffer.render_buffer(offset, buf);
+        }
 
         overflow
     }
@@ -104,12 +246,13 @@ impl Shader for TranslateBuffer {
 mod tests {
     use super::*;
     use ratatui::widgets::{Block, Borders, Widget};
-    use crate::{ref_count, CenteredShrink, Interpolation};
+    use ratatui::style::Style;
+    use crate::{ref_count, ref_count_weak, CenteredShrink, Interpolation};
 
     fn translate_buffer_fx(translate_by: Offset) -> TranslateBuffer {
         let screen = Rect::new(0, 0, 20, 10);
         let aux_buffer = ref_count(Buffer::empty(screen));
-        TranslateBuffer::new(aux_buffer, translate_by, EffectTimer::from_ms(100, Interpolation::Linear))
+        TranslateBuffer::new(aux_buffer.into(), translate_by, EffectTimer::from_ms(100, Interpolation::Linear))
     }
 
     fn assert_translation(
@@ -129,10 +272,11 @@ mod tests {
         let content = screen.inner_centered(10, 4);
 
         // Prepare the auxiliary buffer
+        let aux_buffer = fx.aux_buffer.upgrade().unwrap();
         #[cfg(not(feature = "sendable"))]
-        let mut aux_buffer = fx.aux_buffer.borrow_mut();
+        let mut aux_buffer = aux_buffer.borrow_mut();
         #[cfg(feature = "sendable")]
-        let mut aux_buffer = fx.aux_buffer.lock().unwrap();
+        let mut aux_buffer = aux_buffer.lock().unwrap();
         let block = Block::default()
             .borders(Borders::ALL)
             .title("hello");
@@ -210,6 +354,52 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_translate_half_blocks() {
+        let mut aux_buffer = Buffer::with_lines(["   ", "x  "]);
+        aux_buffer.set_style(Rect::new(0, 0, 3, 1), Style::default().bg(Color::Red));
+        aux_buffer.set_style(Rect::new(0, 1, 3, 1), Style::default().bg(Color::Blue));
+
+        let timer = EffectTimer::from_ms(100, Interpolation::Linear);
+        let mut fx = TranslateBuffer::new(ref_count(aux_buffer).into(), Offset { x: 0, y: 1 }, timer)
+            .with_half_blocks();
+
+        let area = Rect::new(0, 0, 3, 3);
+        let mut buf = Buffer::empty(area);
+        fx.process(Duration::from_millis(50), &mut buf, area);
+
+        let mut expected = Buffer::with_lines(["▄▄▄", "x▀▀", "▀▀▀"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::default().fg(Color::Red));
+        expected.set_style(Rect::new(0, 1, 3, 1), Style::default().fg(Color::Red).bg(Color::Blue));
+        expected.set_style(Rect::new(0, 2, 3, 1), Style::default().fg(Color::Blue));
+        expected[(0, 1)].set_fg(Color::Reset);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn test_translate_wrapping() {
+        let aux_buffer = Buffer {
+            area: Rect::new(1, 1, 6, 1),
+            content: Buffer::with_lines(["ticker"]).content,
+        };
+
+        let timer = EffectTimer::from_ms(600, Interpolation::Linear);
+        let mut fx = TranslateBuffer::new(ref_count(aux_buffer).into(), Offset { x: -6, y: 0 }, timer)
+            .with_wrapping();
+
+        let area = Rect::new(0, 0, 8, 2);
+        let mut frame = |ms| {
+            let mut buf = Buffer::empty(area);
+            fx.process(Duration::from_millis(ms), &mut buf, area);
+            buf
+        };
+
+        assert_eq!(frame(0),   Buffer::with_lines(["        ", " ticker "]));
+        assert_eq!(frame(200), Buffer::with_lines(["        ", " ckerti "]));
+        assert_eq!(frame(300), Buffer::with_lines(["        ", " rticke "]));
+        assert_eq!(frame(100), Buffer::with_lines(["        ", " ticker "]));
+    }
+
     #[test]
     fn test_translate_reversed() {
         let mut fx = translate_buffer_fx(Offset { x: -5, y: -3 });
@@ -385,4 +575,21 @@ mod tests {
             "                    ",
         ]));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_weak_aux_buffer_dropped() {
+        let area = Rect::new(0, 0, 4, 1);
+        let aux_buffer = ref_count(Buffer::with_lines(["abcd"]));
+        let timer = EffectTimer::from_ms(100, Interpolation::Linear);
+        let mut fx = TranslateBuffer::new(ref_count_weak(&aux_buffer).into(), Offset::default(), timer);
+
+        let mut buf = Buffer::empty(area);
+        fx.process(Duration::from_millis(50), &mut buf, area);
+        assert_eq!(buf, Buffer::with_lines(["abcd"]));
+
+        drop(aux_buffer);
+        let mut buf = Buffer::empty(area);
+        assert_eq!(fx.process(Duration::from_millis(60), &mut buf, area), Some(Duration::from_millis(10)));
+        assert_eq!(buf, Buffer::empty(area));
+    }
+}
@@ -0,0 +1,163 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+use crate::bounding_box::BoundingBox;
+use crate::effect::Effect;
+use crate::effect_timer::EffectTimer;
+use crate::interpolation::Interpolatable;
+use crate::shader::Shader;
+use crate::widget::EffectSpan;
+use crate::{CellFilter, CellIterator, Duration, InputState, RefCount, SelectionPolicy};
+
+#[derive(Clone, Debug)]
+pub struct TweenArea {
+    fx: Option<Effect>,
+    area: Option<Rect>,
+    from: BoundingBox,
+    to: BoundingBox,
+    timer: EffectTimer,
+}
+
+impl TweenArea {
+    pub fn new(
+        fx: Option<Effect>,
+        from: Rect,
+        to: Rect,
+        timer: EffectTimer,
+    ) -> Self {
+        Self {
+            fx,
+            area: None,
+            from: BoundingBox::from_rect(from),
+            to: BoundingBox::from_rect(to),
+            timer,
+        }
+    }
+}
+
+impl Shader for TweenArea {
+    fn name(&self) -> &'static str {
+        "tween_area"
+    }
+
+    fn process(
+        &mut self,
+        duration: Duration,
+        buf: &mut Buffer,
+        _area: Rect
+    ) -> Option<Duration> {
+        let overflow = self.timer.process(duration);
+        let alpha = self.timer.alpha();
+
+        let tweened_area = self.from.lerp(&self.to, alpha)
+            .to_rect(buf.area)
+            .unwrap_or_default();
+        self.set_area(tweened_area);
+
+        if let Some(fx) = &mut self.fx {
+            let hosted_overflow = fx.process(duration, buf, tweened_area);
+            // only return the overflow if both the fx and the tween are done
+            match (overflow, hosted_overflow) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                _ => None
+            }
+        } else {
+            overflow
+        }
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {
+        // nothing to do
+    }
+
+    fn done(&self) -> bool {
+        self.timer.done()
+            && self.fx.as_ref().map_or(true, Effect::done)
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_area(area);
+        }
+    }
+
+    fn set_cell_selection(&mut self, strategy: CellFilter) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_cell_selection(strategy);
+        }
+    }
+
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.propagate_cell_selection(filter, policy);
+        }
+    }
+
+    fn set_input(&mut self, input: RefCount<InputState>) {
+        if let Some(fx) = self.fx.as_mut() {
+            fx.set_input(input);
+        }
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        Some(&mut self.timer)
+    }
+
+    fn timer(&self) -> Option<EffectTimer> {
+        Some(self.timer)
+    }
+
+    fn as_effect_span(&self, offset: Duration) -> EffectSpan {
+        match &self.fx {
+            Some(fx) => EffectSpan::new(self, offset, vec![fx.as_effect_span(offset)]),
+            None     => EffectSpan::new(self, offset, Vec::default())
+        }
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        self.fx.as_ref().and_then(Effect::cell_selection)
+    }
+
+    fn reset(&mut self) {
+        self.timer.reset();
+        if let Some(fx) = self.fx.as_mut() {
+            fx.reset();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpolation::Linear;
+
+    use super::*;
+
+    #[test]
+    fn test_tween_area() {
+        let screen = Rect::new(0, 0, 40, 20);
+        let mut buf = Buffer::empty(screen);
+
+        let from = Rect::new(0, 0, 10, 4);
+        let to = Rect::new(20, 10, 20, 10);
+        let mut fx = TweenArea::new(None, from, to, EffectTimer::from_ms(100, Linear));
+
+        fx.process(Duration::from_millis(0), &mut buf, screen);
+        assert_eq!(fx.area(), Some(from));
+
+        fx.process(Duration::from_millis(50), &mut buf, screen);
+        assert_eq!(fx.area(), Some(Rect::new(10, 5, 15, 7)));
+
+        fx.process(Duration::from_millis(50), &mut buf, screen);
+        assert_eq!(fx.area(), Some(to));
+        assert!(fx.done());
+    }
+}
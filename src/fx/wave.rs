@@ -0,0 +1,145 @@
+use core::f32::consts::TAU;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::{Position, Rect};
+
+use crate::{CellFilter, CellIterator, Duration, EffectTimer, Shader};
+
+/// Displaces the cells of each column vertically, following a traveling sine wave.
+#[derive(Clone, Debug)]
+pub struct Wave {
+    /// the maximum displacement, in rows
+    amplitude: u16,
+    /// the length of a full wave, in columns
+    wavelength: f32,
+    /// columns per second, traveling right for positive speeds
+    speed: f32,
+    elapsed: f32,
+    area: Option<Rect>,
+    column: Vec<Cell>,
+}
+
+impl Wave {
+    pub fn new(amplitude: u16, wavelength: f32, speed: f32) -> Self {
+        Self {
+            amplitude,
+            wavelength,
+            speed,
+            elapsed: 0.0,
+            area: None,
+            column: Vec::new(),
+        }
+    }
+
+    /// The number of rows column `x` is displaced by, downwards for positive values.
+    fn displacement(&self, x: u16) -> i32 {
+        if self.wavelength <= 0.0 {
+            return 0;
+        }
+
+        let phase = TAU * (x as f32 - self.speed * self.elapsed) / self.wavelength;
+        (self.amplitude as f32 * phase.sin()).round() as i32
+    }
+}
+
+impl Shader for Wave {
+    fn name(&self) -> &'static str {
+        "wave"
+    }
+
+    fn process(&mut self, duration: Duration, buf: &mut Buffer, area: Rect) -> Option<Duration> {
+        self.elapsed += duration.as_secs_f32();
+        // keep the phase bounded, for precision in long-running effects
+        if self.wavelength > 0.0 && self.speed != 0.0 {
+            self.elapsed %= self.wavelength / self.speed.abs();
+        }
+
+        let area = area.intersection(buf.area);
+        for x in area.left()..area.right() {
+            let dy = self.displacement(x - area.x);
+            if dy == 0 {
+                continue;
+            }
+
+            self.column.clear();
+            self.column.extend((area.top()..area.bottom()).map(|y| buf[(x, y)].clone()));
+
+            for (i, y) in (area.top()..area.bottom()).enumerate() {
+                let src = i as i32 - dy;
+                let cell = &mut buf[Position::new(x, y)];
+                match usize::try_from(src).ok().and_then(|src| self.column.get(src)) {
+                    Some(src) => *cell = src.clone(),
+                    None      => { cell.set_symbol(" "); },
+                }
+            }
+        }
+
+        None
+    }
+
+    fn execute(&mut self, _alpha: f32, _area: Rect, _cell_iter: CellIterator) {}
+
+    fn done(&self) -> bool {
+        false
+    }
+
+    fn clone_box(&self) -> Box<dyn Shader> {
+        Box::new(self.clone())
+    }
+
+    fn area(&self) -> Option<Rect> {
+        self.area
+    }
+
+    fn set_area(&mut self, area: Rect) {
+        self.area = Some(area);
+    }
+
+    fn set_cell_selection(&mut self, _strategy: CellFilter) {
+        // moves whole columns; not applicable
+    }
+
+    fn timer_mut(&mut self) -> Option<&mut EffectTimer> {
+        None
+    }
+
+    fn cell_selection(&self) -> Option<CellFilter> {
+        None
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fx;
+    use crate::testing::effect_frames;
+
+    use super::*;
+
+    #[test]
+    fn test_wave() {
+        let content = Buffer::with_lines([
+            "    ",
+            "abcd",
+            "    ",
+        ]);
+
+        // a wave 4 columns long, traveling one column every 100ms
+        let mut fx = fx::wave(1, 4.0, 10.0);
+        let frames = effect_frames(&mut fx, content, [Duration::ZERO, Duration::from_millis(100)]);
+
+        assert_eq!(frames[0], Buffer::with_lines([
+            "   d",
+            "a c ",
+            " b  ",
+        ]));
+        assert_eq!(frames[1], Buffer::with_lines([
+            "a   ",
+            " b d",
+            "  c ",
+        ]));
+    }
+}
@@ -0,0 +1,75 @@
+use core::fmt;
+
+/// The reason an effect or one of its parameters couldn't be built, as returned by
+/// the `try_` constructors, such as [fx::try_hsl_shift](crate::fx::try_hsl_shift).
+///
+/// Useful when effects are built from user configuration, where invalid input should
+/// be reported rather than panic.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, FxError};
+///
+/// let result = fx::try_hsl_shift(None, None, 500);
+/// assert_eq!(result.unwrap_err(), FxError::NoColorTarget { effect: "hsl_shift" });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum FxError {
+    /// Neither a foreground nor a background color was given to an effect changing
+    /// colors.
+    NoColorTarget {
+        /// The name of the effect.
+        effect: &'static str,
+    },
+    /// A parameter was outside its valid range.
+    OutOfRange {
+        /// The name of the parameter.
+        param: &'static str,
+        /// The value given.
+        value: f32,
+        /// The smallest valid value.
+        min: f32,
+        /// The largest valid value.
+        max: f32,
+    },
+}
+
+impl FxError {
+    /// Returns an [FxError::OutOfRange] if `value` falls outside `min..=max`.
+    pub(crate) fn check_range(param: &'static str, value: f32, min: f32, max: f32) -> Result<(), FxError> {
+        if (min..=max).contains(&value) {
+            Ok(())
+        } else {
+            Err(FxError::OutOfRange { param, value, min, max })
+        }
+    }
+}
+
+impl fmt::Display for FxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FxError::NoColorTarget { effect } => write!(
+                f, "{effect}: at least one of the foreground or background color must be provided"
+            ),
+            FxError::OutOfRange { param, value, min, max } => write!(
+                f, "{param} must be between {min} and {max}, got {value}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FxError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            FxError::check_range("saturation", 120.0, 0.0, 100.0).unwrap_err().to_string(),
+            "saturation must be between 0 and 100, got 120"
+        );
+        assert_eq!(FxError::check_range("saturation", 100.0, 0.0, 100.0), Ok(()));
+    }
+}
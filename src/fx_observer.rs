@@ -0,0 +1,125 @@
+use std::cell::{Cell, RefCell};
+
+use crate::features::Instant;
+use crate::{Duration, ThreadSafetyMarker};
+
+thread_local! {
+    static OBSERVER: RefCell<Option<Box<dyn FxObserver>>> = const { RefCell::new(None) };
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Statistics about processing a single effect during a frame, as reported to an
+/// [FxObserver].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProcessStats {
+    /// The name of the effect.
+    pub name: &'static str,
+    /// The duration the effect was advanced by, excluding any overflow.
+    pub duration: Duration,
+    /// The number of cells the effect may have modified, based on its
+    /// [dirty region](crate::Shader::dirty_region) or, failing that, its area.
+    pub cells_touched: u32,
+    /// The wall-clock time spent processing the effect, including any child effects.
+    pub wall_time: std::time::Duration,
+    /// The nesting depth of the effect: 0 for effects processed directly, such as by an
+    /// [EffectManager](crate::EffectManager), and 1 or more for the child effects of
+    /// e.g. sequences and parallel effects.
+    pub depth: usize,
+}
+
+/// Receives [ProcessStats] from `Effect::process` for every effect processed by an
+/// [EffectManager](crate::EffectManager), including nested effects, for profiling
+/// which effects eat into the frame budget.
+///
+/// Implemented for any `FnMut(&ProcessStats)`.
+///
+/// # Example
+/// ```no_run
+/// use tachyonfx::{EffectManager, ProcessStats};
+///
+/// let mut effects = EffectManager::default();
+/// effects.set_observer(|stats: &ProcessStats| {
+///     if stats.wall_time.as_micros() > 500 {
+///         eprintln!("{} is slow: {:?}", stats.name, stats.wall_time);
+///     }
+/// });
+/// ```
+pub trait FxObserver: ThreadSafetyMarker {
+    /// Called after an effect has been processed; child effects are reported
+    /// before their parents.
+    fn on_process(&mut self, stats: &ProcessStats);
+}
+
+impl<F> FxObserver for F
+where
+    F: FnMut(&ProcessStats) + ThreadSafetyMarker,
+{
+    fn on_process(&mut self, stats: &ProcessStats) {
+        self(stats)
+    }
+}
+
+/// Calls `f` with `observer`, if any, receiving the stats of the effects processed by
+/// the current thread.
+pub(crate) fn observing<R>(
+    observer: &mut Option<Box<dyn FxObserver>>,
+    f: impl FnOnce() -> R,
+) -> R {
+    struct Restore<'a>(&'a mut Option<Box<dyn FxObserver>>);
+    impl Drop for Restore<'_> {
+        fn drop(&mut self) {
+            OBSERVER.with(|o| std::mem::swap(self.0, &mut *o.borrow_mut()));
+        }
+    }
+
+    if observer.is_none() {
+        return f();
+    }
+
+    OBSERVER.with(|o| std::mem::swap(observer, &mut *o.borrow_mut()));
+    let _restore = Restore(observer);
+    f()
+}
+
+/// Tracks the processing of an effect by `Effect::process`, while observed.
+pub(crate) struct Observation {
+    started: Instant,
+    depth: usize,
+}
+
+impl Observation {
+    /// Starts observing the processing of an effect, if there is an observer.
+    pub(crate) fn start() -> Option<Self> {
+        let observed = OBSERVER.with(|o| o.try_borrow().is_ok_and(|o| o.is_some()));
+        observed.then(|| Self {
+            started: Instant::now(),
+            depth: DEPTH.with(|depth| depth.replace(depth.get() + 1)),
+        })
+    }
+
+    /// Reports the processed effect to the observer.
+    pub(crate) fn finish(self, name: &'static str, duration: Duration, cells_touched: u32) {
+        let stats = ProcessStats {
+            name,
+            duration,
+            cells_touched,
+            wall_time: self.started.elapsed(),
+            depth: self.depth,
+        };
+
+        OBSERVER.with(|o| {
+            // skipped if the observer itself processes effects
+            if let Ok(mut observer) = o.try_borrow_mut() {
+                if let Some(observer) = observer.as_mut() {
+                    observer.on_process(&stats);
+                }
+            }
+        });
+    }
+}
+
+impl Drop for Observation {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(self.depth));
+    }
+}
@@ -0,0 +1,260 @@
+//! Raster images for [Compositor](crate::Compositor) layers, displayed with the sixel or
+//! kitty graphics protocols.
+//!
+//! Images aren't part of the cell buffer: the compositor reserves the cells covered by
+//! each image, by marking them as `skip`, and [write_images](crate::Compositor::write_images)
+//! emits the escape sequences placing the images once the frame has been drawn to the
+//! terminal. Placements can be animated with an [EffectTimer], moving the image along
+//! with the cell effects of the frame.
+
+use std::io::Write;
+
+use ratatui::layout::{Offset, Rect, Size};
+
+use crate::{Duration, EffectTimer, Interpolatable};
+
+/// The maximum size of each chunk of base64 data sent with the kitty graphics protocol.
+const KITTY_CHUNK_SIZE: usize = 4096;
+
+/// The encoded image data, in the format of a terminal graphics protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImagePayload {
+    /// A complete sixel sequence, from the introducing `ESC P` to the terminating `ESC \`.
+    Sixel(String),
+    /// A PNG image, transmitted once using the kitty graphics protocol and then placed
+    /// by its id. Ids must be unique among the images shown by the application.
+    Kitty { id: u32, png: Vec<u8> },
+}
+
+/// An image carried by a compositor [Layer](crate::Layer), covering a fixed number
+/// of cells.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::{Offset, Size};
+/// use tachyonfx::{Interpolation, Layer, ref_count};
+/// use tachyonfx::graphics::{Image, ImagePayload};
+/// use ratatui::buffer::Buffer;
+///
+/// let png: Vec<u8> = vec![]; // e.g. std::fs::read("logo.png")
+/// let logo = Image::new(ImagePayload::Kitty { id: 1, png }, Size::new(16, 8))
+///     .with_motion(Offset { x: -16, y: 2 }, Offset { x: 4, y: 2 }, (800, Interpolation::QuadOut));
+///
+/// let layer = Layer::new("logo", ref_count(Buffer::default()))
+///     .with_image(logo);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Image {
+    payload: ImagePayload,
+    size: Size,
+    from: Offset,
+    to: Offset,
+    timer: Option<EffectTimer>,
+    transmitted: bool,
+}
+
+impl Image {
+    /// Creates an image covering `size` cells, placed at the layer's origin.
+    pub fn new(payload: ImagePayload, size: Size) -> Self {
+        Self {
+            payload,
+            size,
+            from: Offset::default(),
+            to: Offset::default(),
+            timer: None,
+            transmitted: false,
+        }
+    }
+
+    /// Places the image at a fixed position, relative to the layer's offset.
+    pub fn with_position(mut self, position: Offset) -> Self {
+        self.from = position;
+        self.to = position;
+        self.timer = None;
+        self
+    }
+
+    /// Moves the image from one position to another, relative to the layer's offset,
+    /// over the duration of the timer.
+    pub fn with_motion<T: Into<EffectTimer>>(mut self, from: Offset, to: Offset, timer: T) -> Self {
+        self.from = from;
+        self.to = to;
+        self.timer = Some(timer.into());
+        self
+    }
+
+    /// Returns the encoded image data.
+    pub fn payload(&self) -> &ImagePayload {
+        &self.payload
+    }
+
+    /// Returns the number of cells covered by the image.
+    pub fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Returns the current position of the image, relative to the layer's offset.
+    pub fn position(&self) -> Offset {
+        match self.timer {
+            Some(timer) => self.from.lerp(&self.to, timer.alpha()),
+            None        => self.from,
+        }
+    }
+
+    /// Returns true if the image is still moving.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_some_and(|t| !t.done())
+    }
+
+    /// Restarts the motion of the image.
+    pub fn reset(&mut self) {
+        if let Some(timer) = self.timer.as_mut() {
+            timer.reset();
+        }
+    }
+
+    pub(crate) fn process(&mut self, duration: Duration) {
+        if let Some(timer) = self.timer.as_mut() {
+            timer.process(duration);
+        }
+    }
+
+    /// Returns the cells covered by the image with the given layer offset. Returns `None`
+    /// unless the image fits entirely on the screen, as images are written as a whole.
+    /// Sixel images must also end above the bottom row, as the cursor moves below the
+    /// image once it's drawn, scrolling the terminal otherwise.
+    pub(crate) fn area(&self, layer_offset: Offset, screen: Rect) -> Option<Rect> {
+        let pos = self.position();
+        let x = screen.x as i32 + layer_offset.x + pos.x;
+        let y = screen.y as i32 + layer_offset.y + pos.y;
+        let bottom = match self.payload {
+            ImagePayload::Sixel(_)     => screen.bottom() as i32 - 1,
+            ImagePayload::Kitty { .. } => screen.bottom() as i32,
+        };
+
+        let fits = x >= screen.x as i32
+            && y >= screen.y as i32
+            && x + self.size.width as i32 <= screen.right() as i32
+            && y + self.size.height as i32 <= bottom;
+
+        let area = Rect::new(x as u16, y as u16, self.size.width, self.size.height);
+        (fits && !area.is_empty()).then_some(area)
+    }
+
+    /// Writes the escape sequences displaying the image with its top-left corner at
+    /// the given cell.
+    pub(crate) fn write_to<W: Write>(&mut self, w: &mut W, x: u16, y: u16) -> std::io::Result<()> {
+        // save cursor, move to the image's position
+        write!(w, "\x1b7\x1b[{};{}H", y + 1, x + 1)?;
+
+        match &self.payload {
+            ImagePayload::Sixel(sixel) => w.write_all(sixel.as_bytes())?,
+            ImagePayload::Kitty { id, png } => {
+                let (cols, rows) = (self.size.width, self.size.height);
+                if self.transmitted {
+                    write!(w, "\x1b_Ga=p,i={id},p=1,c={cols},r={rows},C=1,q=2\x1b\\")?;
+                } else {
+                    let data = base64(png);
+                    let chunks: Vec<&[u8]> = data.as_bytes().chunks(KITTY_CHUNK_SIZE).collect();
+                    let last = chunks.len().saturating_sub(1);
+                    for (i, chunk) in chunks.iter().enumerate() {
+                        let more = u8::from(i != last);
+                        if i == 0 {
+                            write!(w, "\x1b_Ga=T,f=100,i={id},p=1,c={cols},r={rows},C=1,q=2,m={more};")?;
+                        } else {
+                            write!(w, "\x1b_Gm={more};")?;
+                        }
+                        w.write_all(chunk)?;
+                        w.write_all(b"\x1b\\")?;
+                    }
+                    self.transmitted = true;
+                }
+            }
+        }
+
+        // restore cursor
+        w.write_all(b"\x1b8")
+    }
+
+    /// Writes the escape sequence removing the image from the screen, if supported
+    /// by the protocol. Sixel images are overwritten by redrawing the cells beneath.
+    pub(crate) fn write_hide<W: Write>(&self, w: &mut W) -> std::io::Result<()> {
+        match &self.payload {
+            ImagePayload::Kitty { id, .. } if self.transmitted =>
+                write!(w, "\x1b_Ga=d,d=i,i={id},q=2\x1b\\"),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        out.push(ALPHABET[(n >> 18) as usize & 63] as char);
+        out.push(ALPHABET[(n >> 12) as usize & 63] as char);
+        out.push(if chunk.len() > 1 { ALPHABET[(n >> 6) as usize & 63] as char } else { '=' });
+        out.push(if chunk.len() > 2 { ALPHABET[n as usize & 63] as char } else { '=' });
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Interpolation;
+
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn test_kitty_transmits_once() {
+        let mut image = Image::new(ImagePayload::Kitty { id: 7, png: b"png".to_vec() }, Size::new(2, 1));
+
+        let mut out = Vec::new();
+        image.write_to(&mut out, 3, 1).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b7\x1b[2;4H\x1b_Ga=T,f=100,i=7,p=1,c=2,r=1,C=1,q=2,m=0;cG5n\x1b\\\x1b8"
+        );
+
+        let mut out = Vec::new();
+        image.write_to(&mut out, 0, 0).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b7\x1b[1;1H\x1b_Ga=p,i=7,p=1,c=2,r=1,C=1,q=2\x1b\\\x1b8"
+        );
+    }
+
+    #[test]
+    fn test_motion() {
+        let screen = Rect::new(0, 0, 20, 10);
+        let mut image = Image::new(ImagePayload::Sixel(String::new()), Size::new(4, 2))
+            .with_motion(Offset { x: 0, y: 0 }, Offset { x: 10, y: 4 }, (100, Interpolation::Linear));
+
+        image.process(Duration::from_millis(50));
+        assert_eq!(image.position(), Offset { x: 5, y: 2 });
+        assert_eq!(image.area(Offset { x: 1, y: 0 }, screen), Some(Rect::new(6, 2, 4, 2)));
+
+        image.process(Duration::from_millis(50));
+        assert!(!image.is_running());
+        assert_eq!(image.area(Offset { x: 6, y: 0 }, screen), Some(Rect::new(16, 4, 4, 2)));
+        assert_eq!(image.area(Offset { x: -11, y: 0 }, screen), None);
+
+        // images which don't fit entirely are hidden; sixels end above the bottom row
+        assert_eq!(image.area(Offset { x: 8, y: 0 }, screen), None);
+        assert_eq!(image.area(Offset { x: 0, y: 3 }, screen), Some(Rect::new(10, 7, 4, 2)));
+        assert_eq!(image.area(Offset { x: 0, y: 4 }, screen), None);
+    }
+}
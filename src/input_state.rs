@@ -0,0 +1,37 @@
+use ratatui::layout::Position;
+
+use crate::RefCount;
+
+/// The latest user input, supplied by the application for interactive effects, such
+/// as [fx::spotlight](crate::fx::spotlight) or custom effects reading
+/// [ShaderFnContext::input](crate::fx::ShaderFnContext::input).
+///
+/// The state is shared with effects as a `RefCount<InputState>`, attached with
+/// [Effect::with_input](crate::Effect::with_input), and updated by the application as
+/// input events arrive.
+///
+/// # Example
+/// ```
+/// use tachyonfx::{fx, ref_count, InputState};
+///
+/// let input = ref_count(InputState::default());
+/// let spotlight = fx::spotlight(8.0, 0.7).with_input(input.clone());
+///
+/// // on mouse movement, update `mouse_position` through `input`; the spotlight
+/// // follows the pointer from the next frame on
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InputState {
+    /// The position of the mouse pointer, if known.
+    pub mouse_position: Option<Position>,
+    /// The character of the last key pressed, if any.
+    pub last_key: Option<char>,
+}
+
+/// Returns a copy of the shared input state.
+pub(crate) fn read_input(input: &RefCount<InputState>) -> InputState {
+    #[cfg(not(feature = "sendable"))]
+    return *input.borrow();
+    #[cfg(feature = "sendable")]
+    *input.lock().unwrap()
+}
@@ -1,9 +1,15 @@
+#[cfg(feature = "std")]
 use ratatui::layout::Offset;
+#[cfg(feature = "std")]
 use ratatui::style::{Color, Style};
-use simple_easing::{back_in, back_in_out, back_out, bounce_in, bounce_in_out, bounce_out, circ_in, circ_in_out, circ_out, cubic_in, elastic_in, elastic_in_out, elastic_out, expo_in, expo_in_out, expo_out, quad_in, quad_in_out, quad_out, quart_in, quart_in_out, quart_out, quint_in, quint_in_out, quint_out, reverse, sine_in, sine_in_out, sine_out};
+use crate::easing::*;
+use crate::math;
+#[cfg(feature = "std")]
+use crate::color_cache;
+#[cfg(feature = "std")]
 use crate::color_ext::ToRgbComponents;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub enum Interpolation {
     BackIn,
     BackOut,
@@ -119,19 +125,19 @@ impl<T: Interpolatable<T>> Interpolatable<(T, T)> for (T, T) {
 
 impl Interpolatable<u16> for u16 {
     fn lerp(&self, target: &u16, alpha: f32) -> u16 {
-        (*self as f32).lerp(
+        math::round((*self as f32).lerp(
             &(*target as f32),
             alpha
-        ).round() as u16
+        )) as u16
     }
 }
 
 impl Interpolatable<i16> for i16 {
     fn lerp(&self, target: &i16, alpha: f32) -> i16 {
-        (*self as f32).lerp(
+        math::round((*self as f32).lerp(
             &(*target as f32),
             alpha
-        ).round() as i16
+        )) as i16
     }
 }
 
@@ -143,23 +149,29 @@ impl Interpolatable<f32> for f32 {
 
 impl Interpolatable<i32> for i32 {
     fn lerp(&self, target: &i32, alpha: f32) -> i32 {
-        self + ((target - self) as f64 * alpha as f64).round() as i32
+        self + math::round_f64((target - self) as f64 * alpha as f64) as i32
     }
 }
 
+#[cfg(feature = "std")]
 impl Interpolatable<Style> for Style {
     fn lerp(&self, target: &Style, alpha: f32) -> Style {
         let fg = self.fg.lerp(&target.fg, alpha);
         let bg = self.bg.lerp(&target.bg, alpha);
+        #[cfg(feature = "underline-color")]
+        let underline = self.underline_color.lerp(&target.underline_color, alpha);
 
         let mut s = *self;
         if let Some(fg) = fg { s = s.fg(fg) }
         if let Some(bg) = bg { s = s.bg(bg) }
+        #[cfg(feature = "underline-color")]
+        if let Some(c) = underline { s = s.underline_color(c) }
 
         s
     }
 }
 
+#[cfg(feature = "std")]
 impl Interpolatable<Color> for Color {
     fn lerp(&self, target: &Color, alpha: f32) -> Color {
         if alpha == 0.0 {
@@ -178,6 +190,7 @@ impl Interpolatable<Color> for Color {
     }
 }
 
+#[cfg(feature = "std")]
 impl Interpolatable<Option<Color>> for Option<Color> {
     fn lerp(&self, target: &Option<Color>, alpha: f32) -> Option<Color> {
         match (self, target) {
@@ -189,6 +202,7 @@ impl Interpolatable<Option<Color>> for Option<Color> {
     }
 }
 
+#[cfg(feature = "std")]
 impl Interpolatable<Offset> for Offset {
     fn lerp(&self, target: &Offset, alpha: f32) -> Offset {
         Offset {
@@ -198,28 +212,43 @@ impl Interpolatable<Offset> for Offset {
     }
 }
 
+/// Conversions between colors and HSL, with the hue in degrees and the saturation
+/// and lightness as percentages.
 pub trait HslConvertable {
     fn from_hsl(h: f32, s: f32, v: f32) -> Self;
     fn to_hsl(&self) -> (f32, f32, f32);
 }
 
-impl HslConvertable for Color {
+/// RGB components, for color math without ratatui's `Color`.
+impl HslConvertable for (u8, u8, u8) {
     fn from_hsl(h: f32, s: f32, v: f32) -> Self {
         let hsl = colorsys::Hsl::new(h as f64, s as f64, v as f64, None);
         let color: colorsys::Rgb = hsl.as_ref().into();
-        
-        let red = color.red().round();
-        let green = color.green().round();
-        let blue = color.blue().round();
-        
-        Color::Rgb(red as u8, green as u8, blue as u8)
+
+        let red = math::round_f64(color.red());
+        let green = math::round_f64(color.green());
+        let blue = math::round_f64(color.blue());
+
+        (red as u8, green as u8, blue as u8)
     }
 
     fn to_hsl(&self) -> (f32, f32, f32) {
-        let (r, g, b) = self.to_rgb();
-
+        let (r, g, b) = *self;
         let rgb = colorsys::Rgb::from([r, g, b]);
         let hsl: colorsys::Hsl = rgb.as_ref().into();
         (hsl.hue() as f32, hsl.saturation() as f32, hsl.lightness() as f32)
     }
 }
+
+#[cfg(feature = "std")]
+impl HslConvertable for Color {
+    fn from_hsl(h: f32, s: f32, v: f32) -> Self {
+        let (r, g, b) = color_cache::from_hsl((h, s, v), || <(u8, u8, u8)>::from_hsl(h, s, v));
+        Color::Rgb(r, g, b)
+    }
+
+    fn to_hsl(&self) -> (f32, f32, f32) {
+        let rgb = self.to_rgb();
+        color_cache::to_hsl(rgb, || rgb.to_hsl())
+    }
+}
@@ -3,37 +3,98 @@
 //! This library provides a collection of effects that can be used to enhance the visual
 //! appeal of terminal applications, offering capabilities such as color transformations,
 //! animations, and complex effect combinations.
+//!
+//! # `no_std`
+//!
+//! Without the default `std` feature, the crate is `#![no_std]` and depends only on
+//! `core` and `alloc`, leaving the core that doesn't touch ratatui's buffer: [Duration],
+//! [EffectTimer], [Interpolation], [SimpleRng] and HSL color math through
+//! [HslConvertable] on RGB tuples. Effects, cell filters and widgets require `std`.
 
-mod interpolation;
-mod effect;
-mod shader;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+/// Declares items depending on ratatui, and thereby on the `std` feature.
+macro_rules! with_std {
+    ($($item:item)*) => { $(#[cfg(feature = "std")] $item)* };
+}
+
+mod duration;
+mod easing;
 mod effect_timer;
-mod cell_iter;
-mod color_mapper;
-mod color_ext;
-mod rect_ext;
-mod render_effect;
-
-pub mod fx;
-pub mod widget;
-mod bounding_box;
-mod buffer_renderer;
-mod cell_filter;
+mod interpolation;
+mod math;
 mod simple_rng;
-mod duration;
-mod features;
 
-/// `CellIterator` provides an iterator over terminal cells.
-pub use cell_iter::CellIterator;
-pub use color_mapper::ColorMapper;
-pub use cell_filter::CellFilter;
-pub use effect::{Effect, IntoEffect};
+pub use duration::Duration;
 pub use effect_timer::EffectTimer;
-pub use rect_ext::CenteredShrink;
-pub use render_effect::EffectRenderer;
-pub use shader::Shader;
 pub use interpolation::*;
-pub use buffer_renderer::{BufferRenderer, blit_buffer, render_as_ansi_string};
 pub use simple_rng::*;
-pub use duration::Duration;
-pub use features::{ref_count, RefCount, ThreadSafetyMarker};
\ No newline at end of file
+
+with_std! {
+    mod effect;
+    mod effect_manager;
+    mod shader;
+    mod cell_iter;
+    mod color_mapper;
+    mod color_ext;
+    mod color_batch;
+    mod rect_ext;
+    mod render_effect;
+
+    #[cfg(feature = "alloc-audit")]
+    pub mod alloc_audit;
+    pub mod color_cache;
+    pub mod fx;
+    #[cfg(feature = "graphics")]
+    pub mod graphics;
+    pub mod pixel_canvas;
+    pub mod testing;
+    pub mod widget;
+    mod bounding_box;
+    mod buffer_diff;
+    mod buffer_pool;
+    mod buffer_renderer;
+    mod cell_filter;
+    mod cell_filter_registry;
+    mod compositor;
+    mod selection_mask;
+    #[cfg(feature = "underline-color")]
+    mod span_tag;
+    mod sprite;
+    mod features;
+    mod input_state;
+    mod fx_error;
+    mod fx_observer;
+    mod wide_cells;
+
+    pub use bounding_box::BoundingBox;
+    /// `CellIterator` provides an iterator over terminal cells.
+    pub use cell_iter::CellIterator;
+    pub use color_mapper::ColorMapper;
+    pub use color_batch::{lerp_cells, map_cells_bg, map_cells_fg};
+    pub use cell_filter::{CellFilter, CellSelector, SelectionPolicy};
+    pub use cell_filter_registry::CellFilterRegistry;
+    pub use compositor::{BlendMode, Compositor, Layer};
+    pub use effect::{Effect, IntoEffect};
+    pub use effect_manager::EffectCost;
+    pub use effect_manager::{EffectLayer, EffectManager, HandoffPolicy};
+    pub use fx_error::FxError;
+    pub use fx_observer::{FxObserver, ProcessStats};
+    pub use input_state::InputState;
+    pub use rect_ext::{Alignment2D, Anchor, CenteredShrink, RectExt};
+    pub use render_effect::EffectRenderer;
+    pub use shader::Shader;
+    pub use buffer_diff::{buffer_diff, highlight_diff, CellDiff};
+    pub use buffer_pool::BufferPool;
+    pub use buffer_renderer::{AnsiRenderOptions, BufferRef, BufferRenderer, blit_buffer, render_as_ansi_string, render_as_ansi_string_with};
+    #[cfg(feature = "underline-color")]
+    pub use span_tag::SpanTag;
+    pub use sprite::{Sprite, SpritePath};
+    pub use features::{ref_count, ref_count_weak, RefCount, ThreadSafetyMarker, WeakRefCount};
+}
+
+#[cfg(all(test, feature = "alloc-audit"))]
+#[global_allocator]
+static ALLOCATOR: alloc_audit::CountingAllocator = alloc_audit::CountingAllocator;
\ No newline at end of file
@@ -0,0 +1,41 @@
+//! `f32` functions missing from `core`; backed by `std` when available, and
+//! by `libm` otherwise.
+
+macro_rules! float_fns {
+    ($($name:ident($($arg:ident),*) => $libm:ident;)*) => {
+        $(
+            #[inline]
+            pub(crate) fn $name(x: f32 $(, $arg: f32)*) -> f32 {
+                #[cfg(feature = "std")]
+                { x.$name($($arg),*) }
+                #[cfg(not(feature = "std"))]
+                { libm::$libm(x $(, $arg)*) }
+            }
+        )*
+    };
+}
+
+float_fns! {
+    cos() => cosf;
+    exp2() => exp2f;
+    mul_add(a, b) => fmaf;
+    round() => roundf;
+    sin() => sinf;
+    sqrt() => sqrtf;
+}
+
+#[inline]
+pub(crate) fn powi(x: f32, n: i32) -> f32 {
+    #[cfg(feature = "std")]
+    { x.powi(n) }
+    #[cfg(not(feature = "std"))]
+    { libm::powf(x, n as f32) }
+}
+
+#[inline]
+pub(crate) fn round_f64(x: f64) -> f64 {
+    #[cfg(feature = "std")]
+    { x.round() }
+    #[cfg(not(feature = "std"))]
+    { libm::round(x) }
+}
@@ -0,0 +1,206 @@
+//! Sub-cell pixel rendering using braille patterns.
+//!
+//! Each terminal cell is divided into a 2x4 grid of braille dots, giving a
+//! [PixelCanvas] twice the horizontal and four times the vertical resolution of
+//! the area it covers. Fine-grained effects, such as starfields, waves or plasma,
+//! draw individual pixels onto the canvas, which is then rendered to the buffer.
+//!
+//! Use [fx::effect_fn_pixels](crate::fx::effect_fn_pixels) to draw onto a canvas
+//! from within an effect.
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Color;
+
+use crate::cell_filter::CellSelector;
+use crate::CellFilter;
+
+/// First codepoint of the braille patterns block; the dots are encoded in the low byte.
+const BRAILLE_BLANK: u32 = 0x2800;
+
+/// Bit of each braille dot, indexed by `[y][x]` within the cell.
+const DOT_BITS: [[u8; 2]; 4] = [
+    [0x01, 0x08],
+    [0x02, 0x10],
+    [0x04, 0x20],
+    [0x40, 0x80],
+];
+
+/// A canvas of braille dots covering an area of the buffer, at 2x4 pixels per cell.
+///
+/// Pixel coordinates are relative to the top-left corner of the area. Operations on
+/// pixels outside of the canvas are ignored.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::Color;
+/// use tachyonfx::pixel_canvas::PixelCanvas;
+///
+/// let area = Rect::new(0, 0, 2, 1);
+/// let mut canvas = PixelCanvas::new(area);
+/// canvas.set(0, 0);
+/// canvas.set_colored(3, 3, Color::Red);
+///
+/// let mut buf = Buffer::empty(area);
+/// canvas.render(&mut buf);
+///
+/// assert_eq!(buf[(0, 0)].symbol(), "⠁");
+/// assert_eq!(buf[(1, 0)].symbol(), "⢀");
+/// assert_eq!(buf[(1, 0)].fg, Color::Red);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PixelCanvas {
+    area: Rect,
+    dots: Vec<u8>,
+    colors: Vec<Option<Color>>,
+}
+
+impl PixelCanvas {
+    /// Creates an empty canvas covering the given area.
+    pub fn new(area: Rect) -> Self {
+        let cells = area.area() as usize;
+        Self { area, dots: vec![0; cells], colors: vec![None; cells] }
+    }
+
+    /// The area of the buffer covered by the canvas.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// The width of the canvas, in pixels.
+    pub fn width(&self) -> u16 {
+        self.area.width.saturating_mul(2)
+    }
+
+    /// The height of the canvas, in pixels.
+    pub fn height(&self) -> u16 {
+        self.area.height.saturating_mul(4)
+    }
+
+    /// Moves the canvas to a new area and clears all pixels. The pixel storage is
+    /// only reallocated if the new area contains more cells.
+    pub fn resize(&mut self, area: Rect) {
+        let cells = area.area() as usize;
+        self.area = area;
+        self.dots.resize(cells, 0);
+        self.colors.resize(cells, None);
+        self.clear();
+    }
+
+    /// Clears all pixels and colors.
+    pub fn clear(&mut self) {
+        self.dots.fill(0);
+        self.colors.fill(None);
+    }
+
+    /// Sets the pixel at the given position.
+    pub fn set(&mut self, x: u16, y: u16) {
+        if let Some((idx, bit)) = self.locate(x, y) {
+            self.dots[idx] |= bit;
+        }
+    }
+
+    /// Sets the pixel at the given position, and the color of the cell containing it.
+    /// The color applies to all pixels of the cell, with the last color set winning.
+    pub fn set_colored(&mut self, x: u16, y: u16, color: Color) {
+        if let Some((idx, bit)) = self.locate(x, y) {
+            self.dots[idx] |= bit;
+            self.colors[idx] = Some(color);
+        }
+    }
+
+    /// Clears the pixel at the given position.
+    pub fn unset(&mut self, x: u16, y: u16) {
+        if let Some((idx, bit)) = self.locate(x, y) {
+            self.dots[idx] &= !bit;
+        }
+    }
+
+    /// Returns true if the pixel at the given position is set.
+    pub fn is_set(&self, x: u16, y: u16) -> bool {
+        self.locate(x, y)
+            .is_some_and(|(idx, bit)| self.dots[idx] & bit != 0)
+    }
+
+    /// Renders the canvas onto the buffer. Cells without any set pixels are left
+    /// untouched; other cells are replaced by their braille pattern, using the
+    /// color of the cell as the foreground color, if any.
+    pub fn render(&self, buf: &mut Buffer) {
+        self.render_selected(buf, None);
+    }
+
+    /// Renders the canvas onto the cells of the buffer selected by the filter.
+    pub(crate) fn render_selected(&self, buf: &mut Buffer, filter: Option<CellFilter>) {
+        let selector = filter.map(|f| CellSelector::with_buffer(self.area, f, Some(buf)));
+
+        for pos in self.area.intersection(buf.area).positions() {
+            let idx = self.cell_index(pos);
+            if self.dots[idx] == 0 {
+                continue;
+            }
+
+            let cell = &mut buf[pos];
+            if selector.as_ref().is_some_and(|s| !s.is_valid(pos, cell)) {
+                continue;
+            }
+
+            let symbol = char::from_u32(BRAILLE_BLANK + self.dots[idx] as u32).unwrap();
+            cell.set_char(symbol);
+            if let Some(color) = self.colors[idx] {
+                cell.set_fg(color);
+            }
+        }
+    }
+
+    fn cell_index(&self, pos: Position) -> usize {
+        let x = (pos.x - self.area.x) as usize;
+        let y = (pos.y - self.area.y) as usize;
+        y * self.area.width as usize + x
+    }
+
+    fn locate(&self, x: u16, y: u16) -> Option<(usize, u8)> {
+        if x >= self.width() || y >= self.height() {
+            return None;
+        }
+
+        let idx = (y / 4) as usize * self.area.width as usize + (x / 2) as usize;
+        Some((idx, DOT_BITS[(y % 4) as usize][(x % 2) as usize]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_unset() {
+        let mut canvas = PixelCanvas::new(Rect::new(5, 5, 2, 2));
+        assert_eq!((canvas.width(), canvas.height()), (4, 8));
+
+        canvas.set(1, 5);
+        canvas.set(4, 0); // out of bounds
+        assert!(canvas.is_set(1, 5));
+        assert!(!canvas.is_set(0, 5));
+
+        canvas.unset(1, 5);
+        assert!(!canvas.is_set(1, 5));
+    }
+
+    #[test]
+    fn test_render() {
+        let area = Rect::new(1, 0, 2, 2);
+        let mut canvas = PixelCanvas::new(area);
+        (0..4).for_each(|y| canvas.set(0, y));
+        (0..4).for_each(|y| canvas.set(1, y));
+        canvas.set_colored(2, 4, Color::Blue);
+
+        let mut buf = Buffer::with_lines(["abc", "def"]);
+        canvas.render(&mut buf);
+
+        let mut expected = Buffer::with_lines(["a⣿c", "de⠁"]);
+        expected[(2, 1)].set_fg(Color::Blue);
+        assert_eq!(buf, expected);
+    }
+}
@@ -1,4 +1,4 @@
-use ratatui::layout::Rect;
+use ratatui::layout::{Rect, Size};
 
 /// A trait that provides a method to calculate a centered, shrunk rectangle
 /// within the bounds of the original rectangle.
@@ -14,6 +14,9 @@ pub trait CenteredShrink {
     /// # Returns
     /// * A new `Rect` that is centered within the original rectangle with the specified dimensions.
     ///
+    /// See [inner_aligned](CenteredShrink::inner_aligned) for aligning the rectangle to
+    /// an edge or corner instead.
+    ///
     /// # Example
     /// ```
     /// use ratatui::layout::Rect;
@@ -25,6 +28,26 @@ pub trait CenteredShrink {
     /// assert_eq!(centered_rect, Rect::new(25, 25, 50, 50));
     /// ```
     fn inner_centered(&self, width: u16, height: u16) -> Rect;
+
+    /// Calculates a new rectangle within the original rectangle with the specified
+    /// width and height, aligned to an edge or corner, e.g. for popups hugging an edge.
+    ///
+    /// # Arguments
+    /// * `width` - The width of the new rectangle, clamped to the original width.
+    /// * `height` - The height of the new rectangle, clamped to the original height.
+    /// * `alignment` - The edge or corner of the original rectangle to align to.
+    ///
+    /// # Example
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tachyonfx::{Alignment2D, CenteredShrink};
+    ///
+    /// let original_rect = Rect::new(0, 0, 100, 100);
+    ///
+    /// assert_eq!(original_rect.inner_aligned(50, 20, Alignment2D::TopRight), Rect::new(50, 0, 50, 20));
+    /// assert_eq!(original_rect.inner_aligned(50, 20, Alignment2D::Bottom), Rect::new(25, 80, 50, 20));
+    /// ```
+    fn inner_aligned(&self, width: u16, height: u16, alignment: Alignment2D) -> Rect;
 }
 
 impl CenteredShrink for Rect {
@@ -33,4 +56,130 @@ impl CenteredShrink for Rect {
         let y = self.y + (self.height.saturating_sub(height) / 2);
         Rect::new(x, y, width.min(self.width), height.min(self.height))
     }
+
+    fn inner_aligned(&self, width: u16, height: u16, alignment: Alignment2D) -> Rect {
+        alignment.place(*self, width, height)
+    }
+}
+
+/// Geometry helpers for animating areas, as used by the resizing and tweening effects.
+///
+/// Interpolating between two rects is provided by [Interpolatable::lerp](crate::Interpolatable::lerp).
+///
+/// # Example
+/// ```
+/// use ratatui::layout::{Rect, Size};
+/// use tachyonfx::{Anchor, Interpolatable, RectExt};
+///
+/// let screen = Rect::new(0, 0, 80, 24);
+/// let popup = screen.anchored(Anchor::BottomRight, Size::new(20, 6));
+/// assert_eq!(popup, Rect::new(60, 18, 20, 6));
+///
+/// // halfway through moving the popup off-screen, kept within the screen
+/// let moving = popup.lerp(&Rect::new(80, 18, 20, 6), 0.5);
+/// assert_eq!(moving.clamped_within(screen), popup);
+/// ```
+pub trait RectExt {
+    /// Returns a rect of the given size, placed within this rect and aligned to the
+    /// anchor. The size is clamped to the size of this rect.
+    fn anchored(&self, anchor: Anchor, size: Size) -> Rect;
+
+    /// Returns this rect moved to lie within `outer`, shrinking it only if it is
+    /// larger than `outer`. Unlike [Rect::intersection], the size is preserved
+    /// whenever possible.
+    fn clamped_within(&self, outer: Rect) -> Rect;
+}
+
+impl RectExt for Rect {
+    fn anchored(&self, anchor: Anchor, size: Size) -> Rect {
+        anchor.place(*self, size.width, size.height)
+    }
+
+    fn clamped_within(&self, outer: Rect) -> Rect {
+        let width = self.width.min(outer.width);
+        let height = self.height.min(outer.height);
+        let x = self.x.clamp(outer.x, outer.right() - width);
+        let y = self.y.clamp(outer.y, outer.bottom() - height);
+
+        Rect::new(x, y, width, height)
+    }
+}
+
+/// The point of an area which stays fixed while the area is resized.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    #[default]
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+/// The alignment of a rect within another, for [CenteredShrink::inner_aligned]. The
+/// same edges and corners as the [Anchor] of resizing effects.
+pub type Alignment2D = Anchor;
+
+impl Anchor {
+    /// Places a rect of the given size within `area`, aligned to this anchor.
+    /// The size is clamped to the size of `area`.
+    pub(crate) fn place(self, area: Rect, width: u16, height: u16) -> Rect {
+        let width = width.min(area.width);
+        let height = height.min(area.height);
+        let (dw, dh) = (area.width - width, area.height - height);
+
+        let (dx, dy) = match self {
+            Anchor::TopLeft     => (0, 0),
+            Anchor::Top         => (dw / 2, 0),
+            Anchor::TopRight    => (dw, 0),
+            Anchor::Left        => (0, dh / 2),
+            Anchor::Center      => (dw / 2, dh / 2),
+            Anchor::Right       => (dw, dh / 2),
+            Anchor::BottomLeft  => (0, dh),
+            Anchor::Bottom      => (dw / 2, dh),
+            Anchor::BottomRight => (dw, dh),
+        };
+
+        Rect::new(area.x + dx, area.y + dy, width, height)
+    }
+}
+
+/// Returns the union of two regions, ignoring empty regions.
+pub(crate) fn union_regions(a: Rect, b: Rect) -> Rect {
+    match (a.is_empty(), b.is_empty()) {
+        (true, _) => b,
+        (_, true) => a,
+        _         => a.union(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchor_place() {
+        let area = Rect::new(10, 10, 10, 6);
+        assert_eq!(Anchor::TopLeft.place(area, 4, 2),     Rect::new(10, 10, 4, 2));
+        assert_eq!(Anchor::Center.place(area, 4, 2),      Rect::new(13, 12, 4, 2));
+        assert_eq!(Anchor::Bottom.place(area, 4, 2),      Rect::new(13, 14, 4, 2));
+        assert_eq!(Anchor::BottomRight.place(area, 4, 2), Rect::new(16, 14, 4, 2));
+        assert_eq!(Anchor::Right.place(area, 20, 2),      Rect::new(10, 12, 10, 2));
+        assert_eq!(Anchor::Center.place(area, 4, 2), area.inner_centered(4, 2));
+        assert_eq!(area.anchored(Anchor::Left, Size::new(4, 2)), Rect::new(10, 12, 4, 2));
+        assert_eq!(area.inner_aligned(4, 2, Alignment2D::Left), Rect::new(10, 12, 4, 2));
+    }
+
+    #[test]
+    fn test_clamped_within() {
+        let outer = Rect::new(10, 10, 20, 10);
+        assert_eq!(Rect::new(12, 12, 4, 4).clamped_within(outer), Rect::new(12, 12, 4, 4));
+        assert_eq!(Rect::new(0, 0, 4, 4).clamped_within(outer),   Rect::new(10, 10, 4, 4));
+        assert_eq!(Rect::new(28, 18, 4, 4).clamped_within(outer), Rect::new(26, 16, 4, 4));
+        assert_eq!(Rect::new(0, 12, 40, 4).clamped_within(outer), Rect::new(10, 12, 20, 4));
+    }
 }
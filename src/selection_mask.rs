@@ -0,0 +1,129 @@
+//! Precomputed cell selections for filters which only depend on the area.
+//!
+//! Evaluating nested filters, including any `Layout` splits, for every cell on every
+//! frame adds up. Instead, the positional part of a filter is evaluated once per area
+//! and stored as a bitmask, which is cached per thread for subsequent frames.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use ratatui::layout::{Position, Rect};
+
+use crate::CellFilter;
+
+/// Maximum number of cached masks per thread; the least recently used mask is evicted
+/// first.
+const MAX_ENTRIES: usize = 16;
+
+thread_local! {
+    static MASKS: RefCell<Vec<(Rect, CellFilter, Arc<SelectionMask>)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The selected positions of an area, one bit per cell.
+pub(crate) struct SelectionMask {
+    area: Rect,
+    bits: Vec<u64>,
+}
+
+impl SelectionMask {
+    fn new(area: Rect, is_selected: impl Fn(Position) -> bool) -> Self {
+        let mut bits = vec![0u64; (area.area() as usize).div_ceil(64)];
+        area.positions()
+            .enumerate()
+            .filter(|(_, pos)| is_selected(*pos))
+            .for_each(|(i, _)| bits[i / 64] |= 1 << (i % 64));
+
+        Self { area, bits }
+    }
+
+    /// Returns whether the position is selected, or `None` if the position
+    /// is outside the area of the mask.
+    pub(crate) fn get(&self, pos: Position) -> Option<bool> {
+        if !self.area.contains(pos) {
+            return None;
+        }
+
+        let i = (pos.y - self.area.y) as usize * self.area.width as usize
+            + (pos.x - self.area.x) as usize;
+        Some(self.bits[i / 64] & (1 << (i % 64)) != 0)
+    }
+}
+
+/// Returns the cached mask for the area and filter, computing it with
+/// `is_selected` when absent.
+pub(crate) fn cached(
+    area: Rect,
+    filter: &CellFilter,
+    is_selected: impl Fn(Position) -> bool,
+) -> Arc<SelectionMask> {
+    MASKS.with(|masks| {
+        let mut masks = masks.borrow_mut();
+        let cached = masks.iter()
+            .position(|(a, f, _)| *a == area && f.selects_same_positions(filter));
+
+        // most recently used masks are kept last
+        if let Some(idx) = cached {
+            let entry = masks.remove(idx);
+            let mask = entry.2.clone();
+            masks.push(entry);
+            return mask;
+        }
+
+        if masks.len() >= MAX_ENTRIES {
+            masks.remove(0);
+        }
+
+        let mask = Arc::new(SelectionMask::new(area, is_selected));
+        masks.push((area, filter.clone(), mask.clone()));
+        mask
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::{Constraint, Layout, Margin};
+
+    use super::*;
+
+    #[test]
+    fn test_mask_matches_filter() {
+        let area = Rect::new(2, 3, 10, 8);
+        let filter = CellFilter::AllOf(vec![
+            CellFilter::Outer(Margin::new(1, 1)),
+            CellFilter::Layout(Layout::vertical([Constraint::Length(3), Constraint::Fill(1)]), 1),
+        ]);
+
+        let selector = filter.selector(area);
+        let mask = cached(area, &filter, |_| panic!("expected cached mask"));
+
+        let cell = Default::default();
+        for pos in area.positions() {
+            let expected = pos.x == 2 || pos.x == 11 || pos.y == 10;
+            let expected = expected && pos.y >= 6;
+            assert_eq!(mask.get(pos), Some(expected), "{pos:?}");
+            assert_eq!(selector.is_valid(pos, &cell), expected, "{pos:?}");
+        }
+
+        assert_eq!(mask.get(Position::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_least_recently_used_evicted() {
+        let filter = CellFilter::Outer(Margin::new(1, 1));
+        let area = |i: u16| Rect::new(i, 0, 4, 4);
+
+        cached(area(0), &filter, |_| true);
+        for i in 1..MAX_ENTRIES as u16 {
+            cached(area(i), &filter, |_| true);
+            cached(area(0), &filter, |_| panic!("expected cached mask"));
+        }
+
+        // evicts area(1), as area(0) was used more recently
+        cached(area(MAX_ENTRIES as u16), &filter, |_| true);
+        cached(area(0), &filter, |_| panic!("expected cached mask"));
+
+        let recomputed = std::cell::Cell::new(false);
+        cached(area(1), &filter, |_| { recomputed.set(true); true });
+        assert!(recomputed.get());
+    }
+}
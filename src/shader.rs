@@ -3,7 +3,7 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 
 use crate::widget::EffectSpan;
-use crate::{CellFilter, Duration, ThreadSafetyMarker};
+use crate::{CellFilter, Duration, InputState, RefCount, SelectionPolicy, ThreadSafetyMarker};
 use crate::EffectTimer;
 
 
@@ -22,6 +22,8 @@ pub trait Shader: ThreadSafetyMarker {
     /// * `duration` - The duration to process the shader for.
     /// * `buf` - A mutable reference to the `Buffer` where the shader will be applied.
     /// * `area` - The rectangular area within the buffer where the shader will be applied.
+    ///   When processed through an [Effect](crate::Effect), the area is clipped to the
+    ///   buffer, and may be empty.
     ///
     /// # Returns
     /// * An `Option` containing the overflow duration if the shader is done, or `None`
@@ -169,6 +171,26 @@ pub trait Shader: ThreadSafetyMarker {
     ///   from the current remaining time.
     fn timer(&self) -> Option<EffectTimer> { None }
 
+    /// Sets the cell selection strategy, combining `filter` with the existing cell
+    /// selection according to `policy`. Shaders hosting other effects should
+    /// propagate the filter and policy to their child effects.
+    ///
+    /// # Arguments
+    /// * `filter` - The cell selection strategy to apply.
+    /// * `policy` - How `filter` combines with the existing cell selection.
+    fn propagate_cell_selection(&mut self, filter: CellFilter, policy: SelectionPolicy) {
+        let combined = policy.combine(filter, self.cell_selection());
+        self.set_cell_selection(combined);
+    }
+
+    /// Attaches the shared input state, for shaders reacting to user input. Ignored
+    /// by other shaders; shaders hosting other effects should pass it on to their
+    /// child effects.
+    ///
+    /// # Arguments
+    /// * `input` - The input state, updated by the application.
+    fn set_input(&mut self, _input: RefCount<InputState>) {}
+
     /// Returns the cell selection strategy for the shader, if any.
     ///
     /// # Returns
@@ -185,6 +207,24 @@ pub trait Shader: ThreadSafetyMarker {
         }
     }
 
+    /// Returns the area of cells modified during the last call to [process](Shader::process).
+    ///
+    /// Used by [EffectManager](crate::EffectManager) to aggregate the regions that
+    /// need to be redrawn, e.g. to limit diffing work for mostly static screens.
+    ///
+    /// # Returns
+    /// * `None` if the shader doesn't track modified cells; the whole effect area
+    ///   should be assumed to have been modified.
+    /// * `Some(Rect)` with the modified area; an empty `Rect` when no cells were modified.
+    fn dirty_region(&self) -> Option<Rect> { None }
+
+    /// Returns the message of a panic caught while processing the shader, as recorded
+    /// by [fx::isolated](crate::fx::isolated).
+    ///
+    /// # Returns
+    /// * `None` for shaders not catching panics, or if no panic has occurred.
+    fn panic_message(&self) -> Option<&str> { None }
+
     fn as_effect_span(&self, offset: Duration) -> EffectSpan {
         EffectSpan::new(self, offset, Vec::default())
     }
@@ -1,5 +1,6 @@
-use std::ops::Range;
-use std::time::SystemTime;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+use core::ops::{Range, RangeInclusive};
 
 /// A simple pseudo-random number generator using the Linear Congruential Generator algorithm.
 ///
@@ -16,7 +17,7 @@ use std::time::SystemTime;
 /// let random_float = rng.gen_f32();
 /// println!("u32={} f32={}", random_u32, random_float);
 /// ```
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct SimpleRng {
     state: u32,
 }
@@ -56,14 +57,64 @@ impl SimpleRng {
         f32::from_bits(EXPONENT | mantissa) - 1.0
     }
 
+    /// Generates a pseudo-random boolean, which is `true` with probability `p`.
+    ///
+    /// Values of `p` less than or equal to `0.0` always yield `false`, while values
+    /// greater than or equal to `1.0` always yield `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tachyonfx::SimpleRng;
+    ///
+    /// let mut rng = SimpleRng::new(12345);
+    /// assert!(!rng.gen_bool(0.0));
+    /// assert!(rng.gen_bool(1.0));
+    /// ```
+    pub fn gen_bool(&mut self, p: f32) -> bool {
+        self.gen_f32() < p
+    }
+
     fn gen_usize(&mut self) -> usize {
-        let mut g = || self.gen() as usize;
-        g() << 32 | g()
+        let mut g = || self.gen() as u64;
+        (g() << 32 | g()) as usize
+    }
+}
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// Seeds the RNGs created by `SimpleRng::default()` while set, see [with_default_seed].
+    static DEFAULT_SEEDER: Cell<Option<SimpleRng>> = const { Cell::new(None) };
+}
+
+/// Runs `f` with `SimpleRng::default()` returning deterministically seeded RNGs on the
+/// current thread, instead of seeding them from the system time.
+#[cfg(feature = "std")]
+pub(crate) fn with_default_seed<R>(seed: u32, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<SimpleRng>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            DEFAULT_SEEDER.set(self.0);
+        }
     }
+
+    let _restore = Restore(DEFAULT_SEEDER.replace(Some(SimpleRng::new(seed))));
+    f()
 }
 
+/// Seeds RNGs from the system time. Without the `std` feature, there is no clock to
+/// seed from; each RNG is instead seeded from a fixed sequence.
 impl Default for SimpleRng {
+    #[cfg(feature = "std")]
     fn default() -> Self {
+        use crate::features::SystemTime;
+
+        if let Some(mut seeder) = DEFAULT_SEEDER.get() {
+            let seed = seeder.gen();
+            DEFAULT_SEEDER.set(Some(seeder));
+            return SimpleRng::new(seed);
+        }
+
         let seed = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .unwrap()
@@ -71,63 +122,81 @@ impl Default for SimpleRng {
 
         SimpleRng::new(seed)
     }
+
+    #[cfg(not(feature = "std"))]
+    fn default() -> Self {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        static NEXT_SEED: AtomicU32 = AtomicU32::new(0x9e37_79b9);
+        SimpleRng::new(NEXT_SEED.fetch_add(0x9e37_79b9, Ordering::Relaxed))
+    }
 }
 
+/// Samples values from ranges using a random number generator.
+///
+/// Empty ranges, where `start == end`, are considered valid and always sample
+/// `start`. This makes it safe to derive ranges from user-configurable parameters,
+/// such as an effect area which may have a width or height of zero.
+///
+/// # Panics
+///
+/// Panics if `range.end` is less than `range.start`.
 pub trait RangeSampler<T> {
+    /// Samples a value from the half-open range `[start, end)`. Returns `start`
+    /// if the range is empty.
     fn gen_range(&mut self, range: Range<T>) -> T;
-}
-
-impl RangeSampler<u16> for SimpleRng {
-    fn gen_range(&mut self, range: Range<u16>) -> u16 {
-        let range_size = range.end.wrapping_sub(range.start);
-        assert!(range_size > 0, "range.end must be greater than range.start");
 
-        range.start + (self.gen() >> 16) as u16 % range_size
-    }
+    /// Samples a value from the closed range `[start, end]`.
+    fn gen_range_inclusive(&mut self, range: RangeInclusive<T>) -> T;
 }
 
-impl RangeSampler<u32> for SimpleRng {
-    fn gen_range(&mut self, range: Range<u32>) -> u32 {
-        let range_size = range.end.wrapping_sub(range.start);
-        assert!(range_size > 0, "range.end must be greater than range.start");
+macro_rules! impl_range_sampler {
+    ($t:ty, $sample:expr) => {
+        impl RangeSampler<$t> for SimpleRng {
+            fn gen_range(&mut self, range: Range<$t>) -> $t {
+                assert!(range.start <= range.end, "range.end must be greater than range.start");
+                if range.start == range.end {
+                    return range.start;
+                }
+
+                let range_size = range.end.abs_diff(range.start) as u64;
+                let sample: fn(&mut SimpleRng) -> u64 = $sample;
+                range.start.wrapping_add((sample(self) % range_size) as $t)
+            }
 
-        range.start + self.gen() % range_size
-    }
-}
+            fn gen_range_inclusive(&mut self, range: RangeInclusive<$t>) -> $t {
+                let (start, end) = range.into_inner();
+                assert!(start <= end, "range.end must be greater than range.start");
 
-impl RangeSampler<usize> for SimpleRng {
-    fn gen_range(&mut self, range: Range<usize>) -> usize {
-        let range_size = range.end.wrapping_sub(range.start);
-        assert!(range_size > 0, "range.end must be greater than range.start");
+                let sample: fn(&mut SimpleRng) -> u64 = $sample;
+                let offset = match (end.abs_diff(start) as u64).checked_add(1) {
+                    Some(range_size) => sample(self) % range_size,
+                    None             => sample(self), // spans the entire u64 domain
+                };
 
-        range.start + self.gen_usize() % range_size
-    }
+                start.wrapping_add(offset as $t)
+            }
+        }
+    };
 }
 
+impl_range_sampler!(u16,   |rng| (rng.gen() >> 16) as u64);
+impl_range_sampler!(u32,   |rng| rng.gen() as u64);
+impl_range_sampler!(usize, |rng| rng.gen_usize() as u64);
+impl_range_sampler!(i16,   |rng| rng.gen() as u64);
+impl_range_sampler!(i32,   |rng| rng.gen() as u64);
+
 impl RangeSampler<f32> for SimpleRng {
     fn gen_range(&mut self, range: Range<f32>) -> f32 {
         let range_size = range.end - range.start;
-        assert!(range_size > 0.0, "range.end must be greater than range.start");
+        assert!(range_size >= 0.0, "range.end must be greater than range.start");
 
-        range.start + self.gen_f32() % range_size
+        range.start + self.gen_f32() * range_size
     }
-}
-
-impl RangeSampler<i16> for SimpleRng {
-    fn gen_range(&mut self, range: Range<i16>) -> i16 {
-        let range_size = range.end.wrapping_sub(range.start);
-        assert!(range_size > 0, "range.end must be greater than range.start");
-
-        range.start + (self.gen() % range_size as u32) as i16
-    }
-}
-
-impl RangeSampler<i32> for SimpleRng {
-    fn gen_range(&mut self, range: Range<i32>) -> i32 {
-        let range_size = range.end.wrapping_sub(range.start);
-        assert!(range_size > 0, "range.end must be greater than range.start");
 
-        range.start + (self.gen() % range_size as u32) as i32
+    fn gen_range_inclusive(&mut self, range: RangeInclusive<f32>) -> f32 {
+        let (start, end) = range.into_inner();
+        self.gen_range(start..end)
     }
 }
 
@@ -144,7 +213,7 @@ mod tests {
     {
         let mut success = false;
         for _ in 0..RETRY_COUNT {
-            if panic::catch_unwind(|| test()).is_ok() {
+            if panic::catch_unwind(&test).is_ok() {
                 success = true;
                 break;
             }
@@ -176,7 +245,7 @@ mod tests {
 
         for _ in 0..1000 {
             let value = lcg.gen_f32();
-            assert!(value >= 0.0 && value < 1.0);
+            assert!((0.0..1.0).contains(&value));
         }
     }
 
@@ -187,7 +256,7 @@ mod tests {
 
         for _ in 0..1000 {
             let value = lcg.gen_range(range.clone());
-            assert!(value >= 10 && value < 20);
+            assert!((10..20).contains(&value));
         }
     }
 
@@ -198,11 +267,12 @@ mod tests {
 
         for _ in 0..1000 {
             let value = lcg.gen_range(range.clone());
-            assert!(value >= 0.0 && value < 1.0);
+            assert!((0.0..1.0).contains(&value));
         }
     }
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     #[should_panic(expected = "range.end must be greater than range.start")]
     fn test_gen_range_invalid() {
         let mut lcg = SimpleRng::new(12345);
@@ -221,7 +291,7 @@ mod tests {
     fn test_uniform_distribution_u32() {
         run_test(|| {
             let mut lcg = SimpleRng::new(12345);
-            let mut counts = [0; 10];
+            let mut counts = [0i32; 10];
             let num_samples = 100000;
 
             for _ in 0..num_samples {
@@ -231,7 +301,7 @@ mod tests {
 
             let expected = num_samples / 10;
             for &count in &counts {
-                assert!((count as i32 - expected as i32).abs() < 500,
+                assert!((count - expected).abs() < 500,
                     "Distribution is not uniform: {:?}", counts);
             }
         });
@@ -241,7 +311,7 @@ mod tests {
     fn test_uniform_distribution_f32() {
         run_test(|| {
             let mut lcg = SimpleRng::new(12345);
-            let mut counts = [0; 10];
+            let mut counts = [0i32; 10];
             let num_samples = 100000;
 
             for _ in 0..num_samples {
@@ -252,7 +322,7 @@ mod tests {
 
             let expected = num_samples / 10;
             for &count in &counts {
-                assert!((count as i32 - expected as i32).abs() < 500,
+                assert!((count - expected).abs() < 500,
                     "Distribution is not uniform: {:?}", counts);
             }
         });
@@ -281,7 +351,75 @@ mod tests {
 
         for _ in 0..1000 {
             let value = lcg.gen_range(range.clone());
-            assert!(value >= -10 && value < 10);
+            assert!((-10..10).contains(&value));
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_gen_range_empty() {
+        let mut lcg = SimpleRng::new(12345);
+
+        assert_eq!(lcg.gen_range(7u16..7), 7);
+        assert_eq!(lcg.gen_range(7u32..7), 7);
+        assert_eq!(lcg.gen_range(7usize..7), 7);
+        assert_eq!(lcg.gen_range(-7i16..-7), -7);
+        assert_eq!(lcg.gen_range(-7i32..-7), -7);
+        assert_eq!(lcg.gen_range(0.5f32..0.5), 0.5);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    #[should_panic(expected = "range.end must be greater than range.start")]
+    fn test_gen_range_unsigned_invalid() {
+        let mut lcg = SimpleRng::new(12345);
+        lcg.gen_range(20u32..10);
+    }
+
+    #[test]
+    fn test_gen_range_inclusive() {
+        let mut lcg = SimpleRng::new(12345);
+        let mut seen = [false; 5];
+
+        for _ in 0..1000 {
+            let value: u32 = lcg.gen_range_inclusive(10..=14);
+            assert!((10..=14).contains(&value));
+            seen[(value - 10) as usize] = true;
+        }
+
+        assert!(seen.iter().all(|&s| s), "all values should be sampled: {:?}", seen);
+        assert_eq!(lcg.gen_range_inclusive(3u16..=3), 3);
+        assert_eq!(lcg.gen_range_inclusive(-3i16..=-3), -3);
+    }
+
+    #[test]
+    fn test_gen_range_inclusive_full_range() {
+        let mut lcg = SimpleRng::new(12345);
+
+        for _ in 0..1000 {
+            lcg.gen_range_inclusive(i32::MIN..=i32::MAX);
+            lcg.gen_range_inclusive(0u32..=u32::MAX);
+            lcg.gen_range_inclusive(0usize..=usize::MAX);
+        }
+    }
+
+    #[test]
+    fn test_gen_range_f32_scaled() {
+        let mut lcg = SimpleRng::new(12345);
+
+        for _ in 0..1000 {
+            let value = lcg.gen_range(-5.0..5.0);
+            assert!((-5.0..5.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_gen_bool() {
+        let mut lcg = SimpleRng::new(12345);
+
+        assert!((0..100).all(|_| !lcg.gen_bool(0.0)));
+        assert!((0..100).all(|_| lcg.gen_bool(1.0)));
+
+        let hits = (0..10000).filter(|_| lcg.gen_bool(0.25)).count();
+        assert!((2000..3000).contains(&hits), "unexpected hit count: {}", hits);
+    }
+}
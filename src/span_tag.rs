@@ -0,0 +1,113 @@
+use ratatui::buffer::Cell;
+use ratatui::style::{Color, Style};
+use ratatui::text::Span;
+
+use crate::CellFilter;
+
+/// The red channel of underline colors encoding a tag.
+const MARKER: u8 = 0x01;
+
+/// Tags text with an id, so that effects can target it wherever it ends up in the
+/// buffer: the tag is carried by the style of the rendered cells, following the text
+/// through layout and wrapping.
+///
+/// The tag is encoded as the underline color of the cells, `Color::Rgb(1, hi, lo)`,
+/// which is only visible on cells with the `UNDERLINED` modifier. Tagging underlined
+/// text replaces its underline color.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::text::Line;
+/// use ratatui::widgets::{Paragraph, Widget, Wrap};
+/// use tachyonfx::{fx, CellFilter, SpanTag};
+///
+/// const LINK: SpanTag = SpanTag::new(1);
+///
+/// let text = Line::from(vec!["see ".into(), LINK.tag("the docs"), " for details".into()]);
+/// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+/// Paragraph::new(text).wrap(Wrap { trim: true }).render(buf.area, &mut buf);
+///
+/// // only the cells of "the docs", wherever wrapping put them
+/// let effect = fx::dissolve(500).with_cell_selection(CellFilter::tagged(LINK));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpanTag(u16);
+
+impl SpanTag {
+    /// Creates a tag with the given id.
+    pub const fn new(id: u16) -> Self {
+        Self(id)
+    }
+
+    /// Returns the id of the tag.
+    pub const fn id(self) -> u16 {
+        self.0
+    }
+
+    /// Returns a style carrying the tag, for patching onto other styles.
+    pub fn style(self) -> Style {
+        let [hi, lo] = self.0.to_be_bytes();
+        Style::new().underline_color(Color::Rgb(MARKER, hi, lo))
+    }
+
+    /// Returns the span with the tag added to its style.
+    pub fn tag<'a>(self, span: impl Into<Span<'a>>) -> Span<'a> {
+        let span = span.into();
+        let style = span.style.patch(self.style());
+        span.style(style)
+    }
+
+    /// Returns the tag of a cell, if it is tagged.
+    pub fn of(cell: &Cell) -> Option<Self> {
+        match cell.underline_color {
+            Color::Rgb(MARKER, hi, lo) => Some(Self(u16::from_be_bytes([hi, lo]))),
+            _ => None,
+        }
+    }
+}
+
+impl CellFilter {
+    /// Selects the cells tagged with the [SpanTag].
+    pub fn tagged(tag: SpanTag) -> Self {
+        CellFilter::eval_fn(move |cell| SpanTag::of(cell) == Some(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::Stylize;
+    use ratatui::text::Line;
+    use ratatui::widgets::{Paragraph, Widget, Wrap};
+
+    use crate::{Duration, Shader};
+    use crate::fx;
+
+    use super::*;
+
+    #[test]
+    fn test_tagged_cells_follow_wrapping() {
+        let tag = SpanTag::new(300);
+        let text = Line::from(vec!["one ".into(), tag.tag("two three".red()), " four".into()]);
+
+        let area = Rect::new(0, 0, 9, 3);
+        let mut buf = Buffer::empty(area);
+        Paragraph::new(text).wrap(Wrap { trim: true }).render(area, &mut buf);
+        assert_eq!(buf[(4, 0)].fg, Color::Red);
+
+        let mut effect = fx::never_complete(fx::effect_fn((), 100, |_, _, cells| {
+            cells.for_each(|(_, cell)| { cell.set_symbol("x"); });
+        })).with_cell_selection(CellFilter::tagged(tag));
+        effect.process(Duration::from_millis(10), &mut buf, area);
+
+        let lines: Vec<String> = (0..3)
+            .map(|y| (0..9).map(|x| buf[(x, y)].symbol()).collect())
+            .collect();
+        assert_eq!(lines, ["one xxx  ", "xxxxx    ", "four     "]);
+        assert_eq!(SpanTag::of(&buf[(0, 1)]), Some(tag));
+        assert_eq!(SpanTag::of(&buf[(0, 2)]), None);
+    }
+}
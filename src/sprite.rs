@@ -0,0 +1,336 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Offset, Position, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use crate::Interpolatable;
+
+/// A small image of styled cells, rendered on top of other content by
+/// [fx::sprite](crate::fx::sprite).
+///
+/// Cells beyond the end of each line are transparent. Additionally, a color key can be
+/// set: cells with the color key as their background color are transparent too.
+///
+/// # Example
+/// ```
+/// use ratatui::style::Color;
+/// use tachyonfx::Sprite;
+///
+/// let sprite = Sprite::from_ansi("\x1b[38;2;255;128;0m/\\_/\\\n\x1b[0m(o.o)")
+///     .with_color_key(Color::Black);
+///
+/// assert_eq!(sprite.width(), 5);
+/// assert_eq!(sprite.height(), 2);
+/// assert_eq!(sprite.buffer()[(0, 0)].fg, Color::Rgb(255, 128, 0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Sprite {
+    buffer: Buffer,
+    color_key: Option<Color>,
+}
+
+impl Sprite {
+    /// Creates a sprite from a buffer. The buffer is moved to the origin, and cells
+    /// marked as `skip` are transparent.
+    pub fn new(mut buffer: Buffer) -> Self {
+        buffer.area = Rect { x: 0, y: 0, ..buffer.area };
+        Self { buffer, color_key: None }
+    }
+
+    /// Parses text containing ANSI SGR escape sequences, such as the output of
+    /// [render_as_ansi_string](crate::render_as_ansi_string) or ANSI art editors.
+    ///
+    /// Colors (16 named, 256 indexed and RGB) and text modifiers are supported; all
+    /// other escape sequences are ignored. Styles carry over between lines until reset.
+    pub fn from_ansi(ansi: &str) -> Self {
+        let lines: Vec<Line> = ansi.lines().map(parse_ansi_line_with_state()).collect();
+
+        let width = lines.iter().map(Line::width).max().unwrap_or(0) as u16;
+        let height = lines.len() as u16;
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        for (y, line) in lines.iter().enumerate() {
+            let y = y as u16;
+            buffer.set_line(0, y, line, width);
+            (line.width() as u16..width).for_each(|x| buffer[(x, y)].skip = true);
+        }
+
+        Self::new(buffer)
+    }
+
+    /// Sets the background color treated as transparent.
+    pub fn with_color_key(mut self, color: Color) -> Self {
+        self.color_key = Some(color);
+        self
+    }
+
+    /// Returns the width of the sprite, in cells.
+    pub fn width(&self) -> u16 {
+        self.buffer.area.width
+    }
+
+    /// Returns the height of the sprite, in cells.
+    pub fn height(&self) -> u16 {
+        self.buffer.area.height
+    }
+
+    /// Returns the cells of the sprite.
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Renders the opaque cells of the sprite with its top-left corner at `offset`,
+    /// relative to the top-left corner of `area`. Cells outside of `area` are clipped.
+    pub fn render(&self, offset: Offset, area: Rect, buf: &mut Buffer) {
+        let clip = area.intersection(buf.area);
+
+        for pos in self.buffer.area.positions() {
+            let cell = &self.buffer[pos];
+            if cell.skip || self.color_key.is_some_and(|key| cell.bg == key) {
+                continue;
+            }
+
+            let x = area.x as i32 + offset.x + pos.x as i32;
+            let y = area.y as i32 + offset.y + pos.y as i32;
+            let (Ok(x), Ok(y)) = (u16::try_from(x), u16::try_from(y)) else { continue };
+            if clip.contains(Position::new(x, y)) {
+                buf[(x, y)] = cell.clone();
+            }
+        }
+    }
+}
+
+/// The path followed by a sprite, relative to the top-left corner of the effect area.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SpritePath {
+    /// The sprite stays at a fixed position.
+    Fixed(Offset),
+    /// The sprite moves in a straight line between two positions.
+    Line(Offset, Offset),
+    /// The sprite moves through each waypoint in turn, spending an equal share of
+    /// the effect's duration on each segment.
+    Waypoints(Vec<Offset>),
+}
+
+impl SpritePath {
+    /// Returns the position of the sprite at the given alpha.
+    pub fn position(&self, alpha: f32) -> Offset {
+        match self {
+            SpritePath::Fixed(offset) => *offset,
+            SpritePath::Line(from, to) => from.lerp(to, alpha),
+            SpritePath::Waypoints(points) => match points.len() {
+                0 => Offset::default(),
+                1 => points[0],
+                n => {
+                    let t = alpha.clamp(0.0, 1.0) * (n - 1) as f32;
+                    let segment = (t as usize).min(n - 2);
+                    points[segment].lerp(&points[segment + 1], t - segment as f32)
+                }
+            },
+        }
+    }
+}
+
+impl From<Offset> for SpritePath {
+    fn from(offset: Offset) -> Self {
+        SpritePath::Fixed(offset)
+    }
+}
+
+impl From<(Offset, Offset)> for SpritePath {
+    fn from((from, to): (Offset, Offset)) -> Self {
+        SpritePath::Line(from, to)
+    }
+}
+
+impl From<Vec<Offset>> for SpritePath {
+    fn from(points: Vec<Offset>) -> Self {
+        SpritePath::Waypoints(points)
+    }
+}
+
+/// Returns a parser for lines of ANSI text, carrying the style over between lines.
+fn parse_ansi_line_with_state() -> impl FnMut(&str) -> Line<'static> {
+    let mut style = Style::default();
+    move |line| {
+        let mut spans = Vec::new();
+        let mut text = String::new();
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                if !c.is_control() {
+                    text.push(c);
+                }
+                continue;
+            }
+
+            // only CSI sequences are recognized
+            if chars.peek() != Some(&'[') {
+                continue;
+            }
+            chars.next();
+
+            let mut params = String::new();
+            let mut terminator = None;
+            for c in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&c) {
+                    terminator = Some(c);
+                    break;
+                }
+                params.push(c);
+            }
+
+            if terminator == Some('m') {
+                if !text.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut text), style));
+                }
+                style = apply_sgr(style, &params);
+            }
+        }
+
+        if !text.is_empty() {
+            spans.push(Span::styled(text, style));
+        }
+
+        Line::from(spans)
+    }
+}
+
+/// Applies the parameters of an SGR escape sequence to the style.
+fn apply_sgr(mut style: Style, params: &str) -> Style {
+    let mut codes = params.split(';').map(|p| p.parse::<u8>().unwrap_or(0));
+
+    while let Some(code) = codes.next() {
+        style = match code {
+            0        => Style::default(),
+            1        => style.add_modifier(Modifier::BOLD),
+            2        => style.add_modifier(Modifier::DIM),
+            3        => style.add_modifier(Modifier::ITALIC),
+            4        => style.add_modifier(Modifier::UNDERLINED),
+            5        => style.add_modifier(Modifier::SLOW_BLINK),
+            6        => style.add_modifier(Modifier::RAPID_BLINK),
+            7        => style.add_modifier(Modifier::REVERSED),
+            8        => style.add_modifier(Modifier::HIDDEN),
+            9        => style.add_modifier(Modifier::CROSSED_OUT),
+            22       => style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+            23       => style.remove_modifier(Modifier::ITALIC),
+            24       => style.remove_modifier(Modifier::UNDERLINED),
+            25       => style.remove_modifier(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27       => style.remove_modifier(Modifier::REVERSED),
+            28       => style.remove_modifier(Modifier::HIDDEN),
+            29       => style.remove_modifier(Modifier::CROSSED_OUT),
+            30..=37  => style.fg(Color::Indexed(code - 30)),
+            38       => match extended_color(&mut codes) {
+                Some(c) => style.fg(c),
+                None    => style,
+            },
+            39       => style.fg(Color::Reset),
+            40..=47  => style.bg(Color::Indexed(code - 40)),
+            48       => match extended_color(&mut codes) {
+                Some(c) => style.bg(c),
+                None    => style,
+            },
+            49       => style.bg(Color::Reset),
+            90..=97  => style.fg(Color::Indexed(code - 90 + 8)),
+            100..=107 => style.bg(Color::Indexed(code - 100 + 8)),
+            _        => style,
+        };
+    }
+
+    style.fg = style.fg.map(named_color);
+    style.bg = style.bg.map(named_color);
+    style
+}
+
+/// Parses the color of a `38;5;n` or `38;2;r;g;b` sequence, after the initial 38 or 48.
+fn extended_color(codes: &mut impl Iterator<Item = u8>) -> Option<Color> {
+    match codes.next()? {
+        5 => Some(Color::Indexed(codes.next()?)),
+        2 => Some(Color::Rgb(codes.next()?, codes.next()?, codes.next()?)),
+        _ => None,
+    }
+}
+
+/// Maps the 16 indexed system colors to their named counterparts.
+fn named_color(color: Color) -> Color {
+    match color {
+        Color::Indexed(0)  => Color::Black,
+        Color::Indexed(1)  => Color::Red,
+        Color::Indexed(2)  => Color::Green,
+        Color::Indexed(3)  => Color::Yellow,
+        Color::Indexed(4)  => Color::Blue,
+        Color::Indexed(5)  => Color::Magenta,
+        Color::Indexed(6)  => Color::Cyan,
+        Color::Indexed(7)  => Color::Gray,
+        Color::Indexed(8)  => Color::DarkGray,
+        Color::Indexed(9)  => Color::LightRed,
+        Color::Indexed(10) => Color::LightGreen,
+        Color::Indexed(11) => Color::LightYellow,
+        Color::Indexed(12) => Color::LightBlue,
+        Color::Indexed(13) => Color::LightMagenta,
+        Color::Indexed(14) => Color::LightCyan,
+        Color::Indexed(15) => Color::White,
+        c                  => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render_as_ansi_string;
+
+    use super::*;
+
+    #[test]
+    fn test_from_ansi() {
+        let sprite = Sprite::from_ansi("\x1b[31;1mab\x1b[0mc\n\x1b[48;5;202md");
+
+        let mut expected = Buffer::with_lines(["abc", "d  "]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().fg(Color::Red).add_modifier(Modifier::BOLD));
+        expected[(0, 1)].set_bg(Color::Indexed(202));
+        expected[(1, 1)].skip = true;
+        expected[(2, 1)].skip = true;
+
+        assert_eq!(sprite.buffer(), &expected);
+    }
+
+    #[test]
+    fn test_ansi_round_trip() {
+        let mut buf = Buffer::with_lines(["hello", "world"]);
+        buf.set_style(Rect::new(1, 0, 3, 2), Style::new().fg(Color::Rgb(1, 2, 3)).bg(Color::Blue)
+            .add_modifier(Modifier::ITALIC));
+
+        let sprite = Sprite::from_ansi(&render_as_ansi_string(&buf));
+        assert_eq!(sprite.buffer(), &buf);
+    }
+
+    #[test]
+    fn test_render_with_color_key() {
+        let mut sprite_buf = Buffer::with_lines(["ab", "cd"]);
+        sprite_buf[(1, 0)].set_bg(Color::Magenta);
+        let sprite = Sprite::new(sprite_buf).with_color_key(Color::Magenta);
+
+        let mut buf = Buffer::with_lines(["....", "...."]);
+        sprite.render(Offset { x: 1, y: 0 }, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([".a..", ".cd."]));
+
+        // clipped to the area
+        let mut buf = Buffer::with_lines(["....", "...."]);
+        sprite.render(Offset { x: -1, y: 0 }, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["....", "d..."]));
+    }
+
+    #[test]
+    fn test_path_position() {
+        let path = SpritePath::from(vec![
+            Offset { x: 0, y: 0 },
+            Offset { x: 10, y: 0 },
+            Offset { x: 10, y: 10 },
+        ]);
+
+        assert_eq!(path.position(0.0), Offset { x: 0, y: 0 });
+        assert_eq!(path.position(0.25), Offset { x: 5, y: 0 });
+        assert_eq!(path.position(0.75), Offset { x: 10, y: 5 });
+        assert_eq!(path.position(1.0), Offset { x: 10, y: 10 });
+    }
+}
@@ -0,0 +1,343 @@
+//! Utilities for testing effects.
+//!
+//! Effects are typically tested by processing them frame by frame, and comparing the
+//! resulting buffers to the expected output - see [assert_effect_frames](crate::assert_effect_frames).
+//! The helpers in this module strip styling for text-only comparisons, and describe
+//! the differences between buffers, including styles, when a comparison fails.
+
+use std::fmt::Write;
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier};
+
+use crate::{buffer_diff, CellDiff, Duration, Effect, Shader};
+
+/// Asserts that processing an effect yields the expected buffer for each tick.
+///
+/// Each frame starts over from the initial content - either an empty buffer covering a
+/// [Rect], or a [Buffer] - mirroring applications which render their widgets before
+/// applying the effects. The effect is processed for each duration in `ticks`, and the
+/// resulting frames are compared to the expected buffers. On failure, the differences
+/// are listed cell by cell, including styles.
+///
+/// Append `ignore_styles` to only compare the symbols of the frames.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::style::{Color, Style};
+/// use tachyonfx::{assert_effect_frames, fx, Duration, Interpolation};
+///
+/// let content = Buffer::with_lines(["abc"]);
+/// let ticks = [Duration::from_millis(50), Duration::from_millis(50)];
+///
+/// let mut red = Buffer::with_lines(["abc"]);
+/// red.set_style(red.area, Style::default().fg(Color::Red));
+///
+/// assert_effect_frames!(
+///     fx::fade_to_fg(Color::Red, (100, Interpolation::Linear)),
+///     &content,
+///     ticks,
+///     [Buffer::with_lines(["abc"]), red.clone()],
+///     ignore_styles
+/// );
+///
+/// assert_effect_frames!(
+///     fx::fade_to_fg(Color::Red, (50, Interpolation::Linear)),
+///     &content,
+///     ticks,
+///     [red.clone(), red]
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_effect_frames {
+    ($effect:expr, $initial:expr, $ticks:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_frames($effect, $initial, $ticks, $expected, false)
+    };
+    ($effect:expr, $initial:expr, $ticks:expr, $expected:expr, ignore_styles $(,)?) => {
+        $crate::testing::assert_frames($effect, $initial, $ticks, $expected, true)
+    };
+}
+
+/// The initial content of each frame when testing an effect.
+pub trait IntoTestBuffer {
+    fn into_test_buffer(self) -> Buffer;
+}
+
+impl IntoTestBuffer for Rect {
+    fn into_test_buffer(self) -> Buffer {
+        Buffer::empty(self)
+    }
+}
+
+impl IntoTestBuffer for Buffer {
+    fn into_test_buffer(self) -> Buffer {
+        self
+    }
+}
+
+impl IntoTestBuffer for &Buffer {
+    fn into_test_buffer(self) -> Buffer {
+        self.clone()
+    }
+}
+
+/// Runs the effect at a fixed timestep until it's done, or `max_frames` frames have
+/// been processed, and returns every frame. Each frame starts over from the initial
+/// content.
+///
+/// The same effect always produces the same frames, but effects drawing on random
+/// numbers are seeded when they're created. Create them with [seeded] for frames
+/// which are reproducible across runs, e.g. for golden-file tests.
+///
+/// # Example
+/// ```
+/// use ratatui::layout::Rect;
+/// use tachyonfx::{fx, Duration, Interpolation};
+/// use tachyonfx::testing::{run_effect, seeded};
+///
+/// let effect = seeded(42, || fx::dissolve((100, Interpolation::Linear)));
+/// let frames = run_effect(effect.clone(), Rect::new(0, 0, 20, 5), Duration::from_millis(20), 60);
+///
+/// assert_eq!(frames.len(), 5);
+/// assert_eq!(frames, run_effect(effect, Rect::new(0, 0, 20, 5), Duration::from_millis(20), 60));
+/// ```
+pub fn run_effect(
+    mut effect: Effect,
+    initial: impl IntoTestBuffer,
+    tick: Duration,
+    max_frames: usize,
+) -> Vec<Buffer> {
+    let mut frames = Vec::new();
+    let ticks = std::iter::repeat(tick).take(max_frames);
+    drive(&mut effect, initial, ticks, |effect, frame| {
+        frames.push(frame);
+        effect.running()
+    });
+
+    frames
+}
+
+/// Runs `f` with the random number generators of effects created within it seeded
+/// from `seed`, rather than from the system time. Only applies to the current thread.
+pub fn seeded<R>(seed: u32, f: impl FnOnce() -> R) -> R {
+    crate::simple_rng::with_default_seed(seed, f)
+}
+
+/// Processes the effect once for each tick, starting each frame over from the
+/// initial content, and returns the frames.
+pub fn effect_frames(
+    effect: &mut Effect,
+    initial: impl IntoTestBuffer,
+    ticks: impl IntoIterator<Item = Duration>,
+) -> Vec<Buffer> {
+    let mut frames = Vec::new();
+    drive(effect, initial, ticks, |_, frame| {
+        frames.push(frame);
+        true
+    });
+
+    frames
+}
+
+/// Processes the effect for each tick, passing each frame to `on_frame` until it
+/// returns false.
+fn drive(
+    effect: &mut Effect,
+    initial: impl IntoTestBuffer,
+    ticks: impl IntoIterator<Item = Duration>,
+    mut on_frame: impl FnMut(&Effect, Buffer) -> bool,
+) {
+    let initial = initial.into_test_buffer();
+    let area = effect.area().unwrap_or(initial.area);
+
+    for tick in ticks {
+        let mut buf = initial.clone();
+        effect.process(tick, &mut buf, area);
+        if !on_frame(effect, buf) {
+            break;
+        }
+    }
+}
+
+/// The implementation of [assert_effect_frames](crate::assert_effect_frames).
+#[track_caller]
+pub fn assert_frames(
+    mut effect: Effect,
+    initial: impl IntoTestBuffer,
+    ticks: impl IntoIterator<Item = Duration>,
+    expected: impl IntoIterator<Item = Buffer>,
+    ignore_styles: bool,
+) {
+    let mut frames = effect_frames(&mut effect, initial, ticks);
+    let mut expected: Vec<Buffer> = expected.into_iter().collect();
+    if ignore_styles {
+        frames.iter_mut().for_each(strip_styles);
+        expected.iter_mut().for_each(strip_styles);
+    }
+
+    assert_eq!(frames.len(), expected.len(), "number of frames differs from expected");
+
+    let report = frames.iter()
+        .zip(expected.iter())
+        .enumerate()
+        .filter(|(_, (actual, expected))| actual != expected)
+        .fold(String::new(), |mut report, (frame, (actual, expected))| {
+            let _ = writeln!(report, "frame {frame}:");
+            report.push_str(&styled_diff(actual, expected));
+            report
+        });
+
+    assert!(report.is_empty(), "effect frames differ from expected\n{report}");
+}
+
+/// Resets the styles of all cells, leaving only the symbols.
+pub fn strip_styles(buf: &mut Buffer) {
+    buf.content.iter_mut().for_each(|cell| {
+        let symbol = cell.symbol().to_string();
+        cell.reset();
+        cell.set_symbol(&symbol);
+    });
+}
+
+/// Returns a copy of the buffer with the styles of all cells reset.
+pub fn without_styles(buf: &Buffer) -> Buffer {
+    let mut buf = buf.clone();
+    strip_styles(&mut buf);
+    buf
+}
+
+/// Describes the differences between two buffers: the text of each row with
+/// differing cells, with the differing columns marked, followed by the symbol and
+/// style of each differing cell. Returns an empty string when the buffers are equal.
+pub fn styled_diff(actual: &Buffer, expected: &Buffer) -> String {
+    let mut out = String::new();
+    if actual.area != expected.area {
+        let _ = writeln!(out, "  area: {} != expected {}", actual.area, expected.area);
+        return out;
+    }
+
+    let diff = buffer_diff(actual, expected);
+    let mut remaining = diff.as_slice();
+    while let Some(first) = remaining.first() {
+        let y = first.position.y;
+        let row_len = remaining.iter().take_while(|d| d.position.y == y).count();
+        let (row, rest) = remaining.split_at(row_len);
+        remaining = rest;
+
+        let _ = writeln!(out, "  row {y}: {:?}", row_text(actual, y));
+        let _ = writeln!(out, "   expected {:?}", row_text(expected, y));
+        let _ = writeln!(out, "{}", diff_markers(actual.area, row));
+
+        for CellDiff { position, before, after } in row {
+            let _ = writeln!(out, "    ({}, {y}): {} != expected {}",
+                position.x,
+                describe_cell(before),
+                describe_cell(after));
+        }
+    }
+
+    out
+}
+
+/// Returns a line marking the differing columns of a row, aligned with the row text
+/// printed by [styled_diff].
+fn diff_markers(area: Rect, row: &[CellDiff]) -> String {
+    let indent = "   expected \"".len();
+    let mut markers = " ".repeat(indent + area.width as usize).into_bytes();
+    row.iter().for_each(|d| markers[indent + (d.position.x - area.x) as usize] = b'^');
+
+    String::from_utf8(markers).unwrap().trim_end().to_string()
+}
+
+fn row_text(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf[(x, y)].symbol())
+        .collect()
+}
+
+fn describe_cell(cell: &Cell) -> String {
+    let mut s = format!("{:?}", cell.symbol());
+    if cell.fg != Color::Reset {
+        let _ = write!(s, " fg={:?}", cell.fg);
+    }
+    if cell.bg != Color::Reset {
+        let _ = write!(s, " bg={:?}", cell.bg);
+    }
+    if cell.modifier != Modifier::empty() {
+        let _ = write!(s, " mod={:?}", cell.modifier);
+    }
+    if cell.skip {
+        s.push_str(" skip");
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Style;
+
+    use crate::{fx, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_styled_diff() {
+        let expected = Buffer::with_lines(["ab", "cd"]);
+        let mut actual = expected.clone();
+        actual[(1, 1)].set_fg(Color::Red);
+
+        assert_eq!(styled_diff(&actual, &actual), "");
+        assert_eq!(styled_diff(&actual, &expected), "  row 1: \"cd\"\n   expected \"cd\"\n              ^\n    (1, 1): \"d\" fg=Red != expected \"d\"\n");
+        assert_eq!(without_styles(&actual), expected);
+    }
+
+    #[test]
+    fn test_assert_effect_frames() {
+        let tick = Duration::from_millis(100);
+        let mut dark = Buffer::with_lines(["xy"]);
+        dark.set_style(dark.area, Style::default().bg(Color::Black));
+
+        assert_effect_frames!(
+            fx::fade_to(Color::Reset, Color::Black, (100, Interpolation::Linear)),
+            Rect::new(0, 0, 2, 1),
+            [Duration::ZERO, tick],
+            [Buffer::with_lines(["  "]), Buffer::with_lines(["  "])],
+            ignore_styles
+        );
+
+        assert_effect_frames!(
+            fx::fade_to(Color::Reset, Color::Black, (100, Interpolation::Linear)),
+            Buffer::with_lines(["xy"]),
+            [tick, tick],
+            [dark.clone(), dark]
+        );
+    }
+
+    #[test]
+    fn test_seeded_effects_are_reproducible() {
+        let content = Buffer::with_lines(["abcdefghijklmnop"; 4]);
+        let tick = Duration::from_millis(25);
+        let run = |seed| {
+            let fx = seeded(seed, || fx::dissolve((100, Interpolation::Linear)));
+            run_effect(fx, &content, tick, 10)
+        };
+
+        let frames = run(7);
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames, run(7));
+        assert_ne!(frames[1], run(8)[1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 0): \"x\" bg=Black != expected \"x\"")]
+    fn test_assert_effect_frames_reports_styles() {
+        assert_effect_frames!(
+            fx::fade_to(Color::Reset, Color::Black, 0),
+            Buffer::with_lines(["x"]),
+            [Duration::from_millis(10)],
+            [Buffer::with_lines(["x"])]
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Width-aware cell handling. A wide character, e.g. CJK or emoji, is stored in its
+//! leading cell, followed by blank trailing cells covered by the character when it's
+//! drawn. Effects changing symbols treat these cells as a single unit, so partial
+//! transitions never leave half of a wide character behind.
+
+use ratatui::buffer::{Buffer, Cell};
+use ratatui::layout::Position;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Returns the number of columns covered by the symbol of the cell.
+pub(crate) fn symbol_width(cell: &Cell) -> u16 {
+    cell.symbol().width() as u16
+}
+
+/// Returns the number of columns covered by `ch`.
+pub(crate) fn char_width(ch: char) -> u16 {
+    ch.width().unwrap_or(0) as u16
+}
+
+/// Returns true if the cell at `pos` is covered by a wide character to its left.
+pub(crate) fn is_trailing_cell(buf: &Buffer, pos: Position) -> bool {
+    (1..=pos.x.saturating_sub(buf.area.x))
+        .take(3)
+        .any(|dx| buf.cell(Position::new(pos.x - dx, pos.y)).is_some_and(|c| symbol_width(c) > dx))
+}
+
+/// Returns true if the cell holds a single, narrow character - the only cells which
+/// can be moved around or swapped without breaking up a wide character.
+pub(crate) fn is_narrow(buf: &Buffer, pos: Position) -> bool {
+    buf.cell(pos).is_some_and(|c| symbol_width(c) == 1) && !is_trailing_cell(buf, pos)
+}
+
+/// Carries the value computed for the leading cell of a wide character over to its
+/// trailing cells, for effects visiting cells one by one, in reading order.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct WideCells<T> {
+    lead: Option<(Position, u16, T)>,
+}
+
+impl<T: Copy> WideCells<T> {
+    /// Returns the value of the cell at `pos`: computed with `f` for narrow and
+    /// leading cells, and the value of the leading cell for trailing cells.
+    pub(crate) fn resolve(&mut self, pos: Position, cell: &Cell, f: impl FnOnce() -> T) -> T {
+        if let Some((lead, width, value)) = self.lead {
+            if pos.y == lead.y && pos.x > lead.x && pos.x - lead.x < width {
+                return value;
+            }
+        }
+
+        let value = f();
+        let width = symbol_width(cell);
+        self.lead = (width > 1).then_some((pos, width, value));
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailing_cells() {
+        let buf = Buffer::with_lines(["a中b"]);
+
+        assert!(!is_trailing_cell(&buf, Position::new(1, 0)));
+        assert!(is_trailing_cell(&buf, Position::new(2, 0)));
+        assert!(!is_trailing_cell(&buf, Position::new(3, 0)));
+
+        assert!(is_narrow(&buf, Position::new(0, 0)));
+        assert!(!is_narrow(&buf, Position::new(1, 0)));
+        assert!(!is_narrow(&buf, Position::new(2, 0)));
+    }
+
+    #[test]
+    fn test_wide_cells_carry_lead_value() {
+        let buf = Buffer::with_lines(["中ab"]);
+        let mut units = WideCells::default();
+        let mut next = 0;
+        let values: Vec<u32> = (0..4)
+            .map(|x| Position::new(x, 0))
+            .map(|pos| units.resolve(pos, &buf[pos], || { next += 1; next }))
+            .collect();
+
+        assert_eq!(values, [1, 1, 2, 3]);
+    }
+}
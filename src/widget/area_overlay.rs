@@ -0,0 +1,125 @@
+use bon::bon;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Widget};
+
+use crate::widget::area_registry::AreaRegistry;
+use crate::{Duration, Effect, HslConvertable, Shader};
+
+/// A widget that outlines the areas assigned to an effect and its children with
+/// [with_area](crate::Effect::with_area).
+///
+/// Each distinct area is drawn as a labeled outline on top of already rendered content,
+/// making it easy to verify that effect areas line up with the widgets they target. The
+/// labels match the area ids shown by [EffectTimeline](crate::widget::EffectTimeline),
+/// followed by the name of the outermost effect using the area.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Widget;
+/// use tachyonfx::{fx, Interpolation};
+/// use tachyonfx::widget::AreaOverlay;
+///
+/// let screen = Rect::new(0, 0, 40, 10);
+/// let effect = fx::dissolve((500, Interpolation::Linear))
+///     .with_area(Rect::new(5, 2, 20, 5));
+///
+/// let mut buf = Buffer::empty(screen);
+/// AreaOverlay::new(&effect).render(screen, &mut buf);
+/// assert_eq!(buf[(5, 2)].symbol(), "┌");
+/// ```
+#[derive(Clone)]
+pub struct AreaOverlay {
+    areas: Vec<(Rect, String)>,
+    border_style: Style,
+    label_style: Style,
+}
+
+#[bon]
+impl AreaOverlay {
+    #[builder(finish_fn = build)]
+    pub fn builder(
+        effect: &Effect,
+
+        #[builder(default = Style::default().fg(Color::from_hsl(40.0, 80.0, 60.0)))]
+        border_style: Style,
+
+        #[builder(default = Style::default().fg(Color::Black).bg(Color::from_hsl(40.0, 80.0, 60.0)))]
+        label_style: Style,
+    ) -> Self {
+        let span = effect.as_effect_span(Duration::default());
+        let registry = AreaRegistry::from(&span);
+
+        let areas = registry.rects().iter()
+            .map(|rect| {
+                let name = span.iter()
+                    .find(|s| s.area == Some(*rect))
+                    .map(|s| s.label.clone())
+                    .unwrap_or_default();
+
+                (*rect, format!("{} {}", registry.id_of(Some(*rect)), name))
+            })
+            .collect();
+
+        Self { areas, border_style, label_style }
+    }
+
+    /// Creates an overlay of the areas of the effect, using the default styles.
+    pub fn new(effect: &Effect) -> Self {
+        Self::builder().effect(effect).build()
+    }
+}
+
+impl Widget for AreaOverlay {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let clip = area.intersection(buf.area);
+
+        for (rect, label) in self.areas {
+            // areas extending beyond the frame are outlined along the frame's edges
+            let outline = rect.intersection(clip);
+            if outline.is_empty() {
+                continue;
+            }
+
+            Block::bordered()
+                .border_style(self.border_style)
+                .title(Line::from(label).style(self.label_style))
+                .render(outline, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fx, Interpolation};
+
+    use super::*;
+
+    #[test]
+    fn test_outlines_effect_areas() {
+        let timer = (100, Interpolation::Linear);
+        let fx = fx::parallel(&[
+            fx::dissolve(timer).with_area(Rect::new(0, 0, 8, 3)),
+            fx::coalesce(timer),
+            fx::fade_to_fg(Color::Red, timer).with_area(Rect::new(9, 1, 6, 4)),
+        ]);
+
+        let area = Rect::new(0, 0, 14, 4);
+        let mut buf = Buffer::empty(area);
+        AreaOverlay::new(&fx).render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines([
+            "┌r#1 di┐      ",
+            "│      │ ┌r#2┐",
+            "└──────┘ │   │",
+            "         └───┘",
+        ]);
+        buf.content.iter_mut().for_each(|c| { c.set_style(Style::reset()); });
+        expected.content.iter_mut().for_each(|c| { c.set_style(Style::reset()); });
+        assert_eq!(buf, expected);
+    }
+}
@@ -38,6 +38,10 @@ impl AreaRegistry {
         }
     }
 
+    pub(crate) fn rects(&self) -> &[Rect] {
+        &self.rects
+    }
+
     pub(crate) fn entries(&self) -> Vec<(String, String)> {
         self.rects.iter()
             .map(|area| (self.id_of(Some(*area)), area.to_string()))
@@ -0,0 +1,91 @@
+use bon::Builder;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+
+use crate::cell_filter::CellSelector;
+use crate::CellFilter;
+
+/// A widget that highlights the cells selected by a [CellFilter].
+///
+/// The preview is rendered on top of already rendered content, patching the style of
+/// selected and unselected cells respectively. It is useful for debugging complex
+/// compositions of `AllOf`, `Not`, `Layout` and other filters, without having to
+/// observe the effect they're applied to.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::{Margin, Rect};
+/// use ratatui::widgets::Widget;
+/// use tachyonfx::CellFilter;
+/// use tachyonfx::widget::CellFilterPreview;
+///
+/// let area = Rect::new(0, 0, 20, 5);
+/// let mut buf = Buffer::empty(area);
+///
+/// CellFilterPreview::new(CellFilter::Outer(Margin::new(1, 1)))
+///     .render(area, &mut buf);
+/// ```
+#[derive(Builder, Clone)]
+pub struct CellFilterPreview {
+    /// The filter to preview.
+    filter: CellFilter,
+    /// The style patched onto selected cells.
+    #[builder(default = Style::default().bg(Color::Rgb(96, 48, 128)))]
+    selected_style: Style,
+    /// The style patched onto cells which aren't selected.
+    #[builder(default = Style::default().fg(Color::DarkGray))]
+    unselected_style: Style,
+}
+
+impl CellFilterPreview {
+    /// Creates a preview of the filter, using the default styles.
+    pub fn new(filter: CellFilter) -> Self {
+        Self::builder().filter(filter).build()
+    }
+}
+
+impl Widget for CellFilterPreview {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let selector = CellSelector::with_buffer(area, self.filter, Some(buf));
+
+        for pos in area.intersection(buf.area).positions() {
+            let cell = &mut buf[pos];
+            let style = if selector.is_valid(pos, cell) {
+                self.selected_style
+            } else {
+                self.unselected_style
+            };
+
+            cell.set_style(style);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::layout::Margin;
+
+    use super::*;
+
+    #[test]
+    fn test_preview_highlights_selected_cells() {
+        let area = Rect::new(0, 0, 4, 3);
+        let mut buf = Buffer::with_lines(["abcd", "e  f", "ghij"]);
+
+        CellFilterPreview::builder()
+            .filter(CellFilter::Inner(Margin::new(1, 1)))
+            .selected_style(Style::default().bg(Color::Red))
+            .unselected_style(Style::default().bg(Color::Blue))
+            .build()
+            .render(area, &mut buf);
+
+        let mut expected = Buffer::with_lines(["abcd", "e  f", "ghij"]);
+        expected.set_style(area, Style::default().bg(Color::Blue));
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::default().bg(Color::Red));
+
+        assert_eq!(buf, expected);
+    }
+}
@@ -1,10 +1,11 @@
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use ratatui::layout::Rect;
 use ratatui::prelude::Line;
 use ratatui::style::Style;
 use ratatui::text::Span;
 use crate::widget::ColorResolver;
-use crate::{CellFilter, Duration, Shader};
+use crate::{CellFilter, Duration, Interpolation, Shader};
 
 pub(crate) fn effect_span_tree<'a>(
     colorizer: &ColorResolver,
@@ -76,6 +77,8 @@ pub struct EffectSpan {
     pub(crate) label: String,
     pub(crate) cell_filter: CellFilter,
     pub(crate) area: Option<Rect>,
+    pub(crate) interpolation: Option<Interpolation>,
+    pub(crate) iteration: Option<SpanIteration>,
     pub(crate) start: f32,
     pub(crate) end: f32,
     pub(crate) children: Vec<EffectSpan>,
@@ -105,6 +108,8 @@ impl EffectSpan {
             label: effect.name().to_string(),
             cell_filter: effect.cell_selection().unwrap_or_default(),
             area: effect.area(),
+            interpolation: effect.timer().map(|timer| timer.interpolation()),
+            iteration: None,
             start,
             end: start + end,
             children,
@@ -122,11 +127,139 @@ impl EffectSpan {
         span
     }
 
-    pub(crate) fn iter(&self) -> EffectSpanIterator {
+    /// Marks the span as iterating over its children, e.g. when repeating or
+    /// ping-ponging an effect.
+    pub(crate) fn with_iteration(mut self, iteration: SpanIteration) -> Self {
+        self.iteration = Some(iteration);
+        self
+    }
+
+    /// Appends the span and its children to `out`, one line per span.
+    pub(crate) fn describe(&self, out: &mut String, prefix: &str, child_prefix: &str) {
+        use std::fmt::Write;
+
+        let millis = ((self.end - self.start) * 1000.0).round() as u32;
+        let _ = write!(out, "{prefix}{} {millis}ms", self.label);
+        if !matches!(self.cell_filter, CellFilter::All) {
+            let _ = write!(out, " filter={}", self.cell_filter.to_string());
+        }
+        if let Some(area) = self.area {
+            let _ = write!(out, " area={area}");
+        }
+        out.push('\n');
+
+        let last = self.children.len().saturating_sub(1);
+        for (i, child) in self.children.iter().enumerate() {
+            let (branch, indent) = if i == last { ("└ ", "  ") } else { ("├ ", "│ ") };
+            child.describe(
+                out,
+                &format!("{child_prefix}{branch}"),
+                &format!("{child_prefix}{indent}"),
+            );
+        }
+    }
+
+    /// Returns true if both spans have the same name, duration, interpolation, cell
+    /// filter, area and iteration, and structurally equal children.
+    pub(crate) fn structural_eq(&self, other: &EffectSpan) -> bool {
+        self.label == other.label
+            && self.start == other.start
+            && self.end == other.end
+            && self.interpolation == other.interpolation
+            && self.iteration == other.iteration
+            && self.area == other.area
+            && format!("{:?}", self.cell_filter) == format!("{:?}", other.cell_filter)
+            && self.children.len() == other.children.len()
+            && self.children.iter().zip(&other.children).all(|(a, b)| a.structural_eq(b))
+    }
+
+    /// Feeds the structure of the span and its children to `state`, consistent
+    /// with [EffectSpan::structural_eq].
+    pub(crate) fn structural_hash<H: Hasher>(&self, state: &mut H) {
+        self.label.hash(state);
+        self.start.to_bits().hash(state);
+        self.end.to_bits().hash(state);
+        self.interpolation.hash(state);
+        self.area.hash(state);
+        format!("{:?}", self.cell_filter).hash(state);
+        self.children.len().hash(state);
+        self.children.iter().for_each(|child| child.structural_hash(state));
+    }
+
+    pub(crate) fn iter(&self) -> EffectSpanIterator<'_> {
         EffectSpanIterator::new(self)
     }
 }
 
+/// A 64-bit FNV-1a hasher; unlike the std hashers, its output is the same across
+/// runs, platforms and Rust versions.
+pub(crate) struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 = (self.0 ^ *b as u64).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        // hashed as u64, for the same output on 32-bit platforms
+        self.write(&(n as u64).to_le_bytes());
+    }
+}
+
+/// How the children of a span play out over the duration of the span.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum SpanIteration {
+    /// The children are played repeatedly, each iteration lasting `period` seconds.
+    Repeat { period: f32 },
+    /// The children are played forward, then in reverse.
+    PingPong,
+}
+
+impl SpanIteration {
+    /// Returns the intervals, in seconds, during which a descendant spanning
+    /// `start..end` replays after its first iteration, within `parent`.
+    pub(crate) fn replays(&self, parent: &EffectSpan, start: f32, end: f32) -> Vec<(f32, f32)> {
+        match *self {
+            SpanIteration::Repeat { period } if period > 0.0 => (1..)
+                .map(|n| n as f32 * period)
+                .map(|offset| (start + offset, (end + offset).min(parent.end)))
+                .take_while(|(s, _)| *s < parent.end)
+                .collect(),
+            SpanIteration::Repeat { .. } => Vec::new(),
+            SpanIteration::PingPong => {
+                // mirrored around the midpoint of the parent
+                let pivot = parent.start + parent.end;
+                vec![(pivot - end, pivot - start)]
+            }
+        }
+    }
+
+    /// Returns the points in time, in seconds, at which each iteration of
+    /// `parent` after the first begins.
+    pub(crate) fn iteration_starts(&self, parent: &EffectSpan) -> Vec<f32> {
+        match *self {
+            SpanIteration::Repeat { period } if period > 0.0 => (1..)
+                .map(|n| parent.start + n as f32 * period)
+                .take_while(|t| *t < parent.end)
+                .collect(),
+            SpanIteration::Repeat { .. } => Vec::new(),
+            SpanIteration::PingPong => vec![(parent.start + parent.end) / 2.0],
+        }
+    }
+}
+
 impl fmt::Display for EffectSpan {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.label)
@@ -7,10 +7,9 @@ use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Widget};
-use std::fs::File;
-use std::io::Write;
-use std::ops::Range;
+use core::ops::Range;
 use crate::widget::area_registry::AreaRegistry;
+use crate::widget::interpolation_registry::InterpolationRegistry;
 use crate::widget::color_resolver::color_registry;
 
 /// A widget that visualizes the timeline of effects in a `tachyonfx` Effect.
@@ -18,18 +17,24 @@ use crate::widget::color_resolver::color_registry;
 /// `EffectTimeline` creates a graphical representation of the structure and timing of effects
 /// within the effect chain. It displays a hierarchical view of effects, their durations,
 /// and any cell filters applied to them.
+///
+/// With `show_interpolation` enabled, an additional column lists the interpolation and
+/// duration of each leaf effect, along with a legend previewing each easing curve.
 #[derive(Clone)]
 pub struct EffectTimeline {
     span: EffectSpan,
     color_resolver: ColorResolver,
     area_resolver: AreaRegistry,
     cell_filter_resolver: CellFilterRegistry,
+    interpolation_resolver: InterpolationRegistry,
     chart_style: Style,
     interval_style: Style,
     area_column_style: Style,
     area_legend_style: Style,
     cell_filter_column_style: Style,
     cell_filter_legend_style: Style,
+    interpolation_column_style: Style,
+    interpolation_legend_style: Style,
 }
 
 #[bon]
@@ -65,6 +70,15 @@ impl EffectTimeline {
 
         #[builder(default = Style::default().fg(Color::from_hsl(170.0, 40.0, 47.0)))]
         cell_filter_legend_style: Style,
+
+        #[builder(default = false)]
+        show_interpolation: bool,
+
+        #[builder(default = Style::default().fg(Color::from_hsl(280.0, 20.0, 35.0)))]
+        interpolation_column_style: Style,
+
+        #[builder(default = Style::default().fg(Color::from_hsl(280.0, 40.0, 47.0)))]
+        interpolation_legend_style: Style,
     ) -> Self {
         let span = effect.as_effect_span(Duration::default());
         let color_resolver = color_registry()
@@ -76,18 +90,26 @@ impl EffectTimeline {
 
         let area_resolver = AreaRegistry::from(&span);
         let cell_filter_resolver = CellFilterRegistry::from(&span);
+        let interpolation_resolver = if show_interpolation {
+            InterpolationRegistry::from(&span)
+        } else {
+            InterpolationRegistry::empty()
+        };
 
         Self {
             span,
             color_resolver,
             area_resolver,
             cell_filter_resolver,
+            interpolation_resolver,
             interval_style,
             area_column_style,
             area_legend_style,
             cell_filter_column_style,
             chart_style,
             cell_filter_legend_style,
+            interpolation_column_style,
+            interpolation_legend_style,
         }
     }
 
@@ -148,18 +170,42 @@ impl EffectTimeline {
     /// timeline.save_to_file("effect_timeline.txt", 100)?;
     /// ```
     pub fn save_to_file(self, path: &str, width: u16) -> std::io::Result<()> {
+        let mut file = std::fs::File::create(path)?;
+        self.write_to(&mut file, width)
+    }
+
+    /// Writes the EffectTimeline as an ANSI-encoded string to any writer, such as
+    /// stdout, a log file or a network stream.
+    ///
+    /// The timeline is rendered with the specified width, and the height required to fit
+    /// the content; see [to_ansi_string](EffectTimeline::to_ansi_string).
+    ///
+    /// # Example
+    /// ```
+    /// use tachyonfx::{fx, Interpolation};
+    /// use tachyonfx::widget::EffectTimeline;
+    ///
+    /// let effect = fx::sequence(&[fx::sleep(200), fx::dissolve((500, Interpolation::Linear))]);
+    /// let timeline = EffectTimeline::builder().effect(&effect).build();
+    ///
+    /// timeline.write_to(&mut std::io::stdout(), 80).unwrap();
+    /// ```
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W, width: u16) -> std::io::Result<()> {
+        writer.write_all(self.to_ansi_string(width).as_bytes())?;
+        writer.flush()
+    }
+
+    /// Renders the EffectTimeline with the specified width as an ANSI-encoded string. The
+    /// height is calculated to fit the chart and its legends.
+    pub fn to_ansi_string(&self, width: u16) -> String {
         let layout = self.layout(Rect::new(0, 0, width, 200));
-        let height = layout.areas_legend.y + layout.areas_legend.height;
+        let height = layout.legend.y + layout.legend.height;
 
         let area = Rect::new(0, 0, width, height);
         let mut buffer = Buffer::empty(area);
 
-        self.render(area, &mut buffer);
-        let content = crate::render_as_ansi_string(&buffer);
-
-        let mut file = File::create(path)?;
-        file.write_all(content.as_bytes())?;
-        Ok(())
+        self.clone().render(area, &mut buffer);
+        crate::render_as_ansi_string(&buffer)
     }
 
     fn render_timeline_divisions(&self, root: &EffectSpan, axis_row: Rect, buf: &mut Buffer) {
@@ -260,6 +306,15 @@ impl EffectTimeline {
         }
     }
 
+    fn render_interpolation_column(&self, area: Rect, buf: &mut Buffer) {
+        let style = self.interpolation_column_style;
+        for (span, row) in self.span.iter().zip(area.rows()) {
+            Line::from(self.interpolation_resolver.id_of(span))
+                .style(style)
+                .render(row, buf);
+        }
+    }
+
     fn render_cell_filter_legend(
         &self,
         area: Rect,
@@ -310,6 +365,31 @@ impl EffectTimeline {
             });
     }
 
+    fn render_interpolation_legend(
+        &self,
+        area: Rect,
+        buf: &mut Buffer
+    ) {
+        let col_style = self.interpolation_column_style;
+        let legend_style = self.interpolation_legend_style;
+
+        self.interpolation_resolver.entries()
+            .iter()
+            .zip(area.rows())
+            .for_each(|((id, interpolation), row)| {
+                let mut row = row;
+
+                Span::from(id)
+                    .style(col_style)
+                    .render(row, buf);
+
+                row.x += 6;
+                Span::from(interpolation)
+                    .style(legend_style)
+                    .render(row, buf);
+            });
+    }
+
     fn render_chart(&self, chart_area: Rect, buf: &mut Buffer) {
         let scale = chart_area.width as f32 / self.span.end;
         let span_area = |row: Rect, span: &EffectSpan| -> Rect {
@@ -339,13 +419,10 @@ impl EffectTimeline {
                     .style(self.chart_style.fg(c))
                     .render(bar_area, buf);
 
-                // draw background bars (area)
+                // draw dividers below the leaf children
                 let children = span.iter().skip(1).count();
                 if children > 0 && bar.len() > 1 {
-                    let bg_bar = as_background_area_line(&bar, c);
-
                     for offset in 1..=children {
-                        // draw divider for leaf
                         let child_span = spans[i + offset];
                         if child_span.is_leaf {
                             let divider = "▁".repeat(chart_area.width as usize);
@@ -353,17 +430,51 @@ impl EffectTimeline {
                                 .style(self.chart_style.fg(c))
                                 .render(chart_rows[i + offset], buf);
                         }
+                    }
+                }
+            });
+    }
+
+    /// Draws the iterations of repeating and ping-ponging effects: a marker at the
+    /// start of each iteration, and the replays of all descendants - mirrored for
+    /// the reverse leg of a ping-pong.
+    fn render_iterations(&self, chart_area: Rect, buf: &mut Buffer) {
+        let scale = chart_area.width as f32 / self.span.end;
+        let to_x = |t: f32| chart_area.x + ((t * scale) as u16).min(chart_area.width);
 
-                        // cloning area of original bar
-                        let mut child_row = bar_area;
-                        child_row.y += offset as u16;
+        let chart_rows: Vec<Rect> = chart_area.rows().collect();
+        let spans = self.span.iter().collect::<Vec<_>>();
+        for (i, span) in spans.iter().enumerate() {
+            let Some(iteration) = span.iteration else { continue };
+            let Some(row) = chart_rows.get(i) else { break };
+
+            let c = self.color_resolver.color_of(&span.label);
+            for t in iteration.iteration_starts(span) {
+                if let Some(cell) = buf.cell_mut(Position::new(to_x(t), row.y)) {
+                    cell.set_symbol(ITERATION_MARKER);
+                    cell.fg = c;
+                }
+            }
 
-                        if bg_bar.width() < row.width as usize {
-                            // bg_bar.clone().render(child_row, buf);
+            let descendants = span.iter().skip(1).count();
+            for offset in 1..=descendants {
+                let Some(row) = chart_rows.get(i + offset) else { break };
+                let child = spans[i + offset];
+                let c = self.color_resolver.color_of(&child.label);
+
+                for (start, end) in iteration.replays(span, child.start, child.end) {
+                    let from = to_x(start);
+                    let to = to_x(end).max(from + 1);
+                    for x in from..to {
+                        let Some(cell) = buf.cell_mut(Position::new(x, row.y)) else { break };
+                        if !is_bar_symbol(cell.symbol()) {
+                            cell.set_symbol(REPLAY_SYMBOL);
+                            cell.fg = c;
                         }
                     }
                 }
-            });
+            }
+        }
     }
 
     pub fn layout(&self, area: Rect) -> EffectTimelineRects {
@@ -375,29 +486,34 @@ impl EffectTimeline {
 
         // 1 row of padding between chart and legend
         legend_rect.y = chart_rows + 2;
-        legend_rect.x = (clamped_area.width - legend_rect.width) / 2;
+        legend_rect.x = clamped_area.width.saturating_sub(legend_rect.width) / 2;
         clamped_area.height = chart_rows;
 
         let areas_col_w = if self.area_resolver.entries().is_empty() { 0 } else { 4 };
+        let interpolation_col_w = self.interpolation_column_width();
 
         let layout = Layout::horizontal([
-            Constraint::Length(label_len + 1), // label
-            Constraint::Length(6),             // cell filter
-            Constraint::Length(areas_col_w),   // overridden areas
-            Constraint::Percentage(100),       // chart
+            Constraint::Length(label_len + 1),       // label
+            Constraint::Length(6),                   // cell filter
+            Constraint::Length(areas_col_w),         // overridden areas
+            Constraint::Length(interpolation_col_w), // interpolation and duration
+            Constraint::Percentage(100),             // chart
         ]).split(clamped_area);
 
         let layout_legend = Layout::horizontal([
             Constraint::Length(self.legend_cell_filter_width()),
             Constraint::Length(LEGEND_PADDING),
             Constraint::Length(self.legend_areas_width()),
+            Constraint::Length(self.interpolation_legend_padding()),
+            Constraint::Length(self.legend_interpolation_width()),
         ]).split(legend_rect);
 
         EffectTimelineRects {
             tree: layout[0],
             cell_filter: layout[1],
             areas: layout[2],
-            chart: layout[3],
+            interpolation: layout[3],
+            chart: layout[4],
             legend: legend_rect,
             cell_filter_legend: Rect {
                 x: layout_legend[0].x,
@@ -411,6 +527,7 @@ impl EffectTimeline {
                 width: layout_legend[2].width,
                 height: layout_legend[2].height,
             },
+            interpolation_legend: layout_legend[4],
         }
     }
 
@@ -432,20 +549,52 @@ impl EffectTimeline {
             .unwrap_or(0)
     }
 
+    fn legend_interpolation_width(&self) -> u16 {
+        self.interpolation_resolver.entries()
+            .iter()
+            .map(|(id, i)| id.chars().count() + 1 + i.chars().count())
+            .map(|n| n as u16)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn interpolation_column_width(&self) -> u16 {
+        self.span.iter()
+            .map(|span| self.interpolation_resolver.id_of(span).chars().count() as u16)
+            .max()
+            .map(|w| if w > 0 { w + 1 } else { 0 })
+            .unwrap_or(0)
+    }
+
+    fn interpolation_legend_padding(&self) -> u16 {
+        if self.interpolation_resolver.entries().is_empty() { 0 } else { LEGEND_PADDING }
+    }
+
     fn legend_rect(&self) -> Rect {
         let cf_rows = self.cell_filter_resolver.entries().len() as u16;
         let a_rows = self.area_resolver.entries().len() as u16;
+        let ip_rows = self.interpolation_resolver.entries().len() as u16;
 
         Rect {
             x: 0,
             y: 0,
-            width: self.legend_cell_filter_width() + LEGEND_PADDING + self.legend_areas_width(),
-            height: u16::max(cf_rows, a_rows),
+            width: self.legend_cell_filter_width()
+                + LEGEND_PADDING
+                + self.legend_areas_width()
+                + self.interpolation_legend_padding()
+                + self.legend_interpolation_width(),
+            height: cf_rows.max(a_rows).max(ip_rows),
         }
     }
 }
 
 const LEGEND_PADDING: u16 = 5;
+const ITERATION_MARKER: &str = "▌";
+const REPLAY_SYMBOL: &str = "░";
+
+fn is_bar_symbol(symbol: &str) -> bool {
+    matches!(symbol, "█" | "▌" | "▐")
+}
 
 impl Widget for EffectTimeline {
     fn render(self, area: Rect, buf: &mut Buffer)
@@ -476,37 +625,22 @@ impl Widget for EffectTimeline {
             .collect();
         self.render_areas_column(areas, layout.areas, buf);
 
+        // interpolation and duration column
+        self.render_interpolation_column(layout.interpolation, buf);
+
         // chart
         Block::new()
             .style(self.chart_style)
             .render(layout.chart, buf);
 
         self.render_chart(layout.chart, buf);
+        self.render_iterations(layout.chart, buf);
         self.render_timeline_intervals(&self.span, layout.time_intervals(), buf);
 
         // legends
         self.render_cell_filter_legend(layout.cell_filter_legend, buf);
         self.render_areas_legend(layout.areas_legend, buf);
-    }
-}
-
-fn as_background_area_line(bar: &str, base_color: Color) -> Line<'static> {
-    let (h, s, l) = base_color.to_hsl();
-    let color = Color::from_hsl(h, s * 0.4, l * 0.4);
-    let first = bar.chars().next().unwrap_or(' ').to_string();
-    let last = bar.chars().last().unwrap_or(' ').to_string();
-
-    match bar.chars().count() {
-        1 => Line::from(first).style(Style::default().fg(color)),
-        2 => Line::from(vec![
-            Span::from(first).style(Style::default().fg(color)),
-            Span::from(last).style(Style::default().fg(color)),
-        ]),
-        n => Line::from(vec![
-            Span::from(first).style(Style::default().fg(color)),
-            Span::from(" ".repeat(n - 2)).style(Style::default().bg(color)),
-            Span::from(last).style(Style::default().fg(color)),
-        ])
+        self.render_interpolation_legend(layout.interpolation_legend, buf);
     }
 }
 
@@ -516,9 +650,11 @@ pub struct EffectTimelineRects {
     pub chart: Rect,
     pub cell_filter: Rect,
     pub areas: Rect,
+    pub interpolation: Rect,
     pub legend: Rect,
     pub cell_filter_legend: Rect,
     pub areas_legend: Rect,
+    pub interpolation_legend: Rect,
 }
 
 impl EffectTimelineRects {
@@ -545,7 +681,7 @@ fn span_as_bar_line(
         n => {
             let l = if start.round() > start { "▐" } else { "█" };
             let r = if end.round() < end { "▌" } else { "█" };
-            format!("{}{}{}", l, "█".repeat(n as usize - 2), r)
+            format!("{}{}{}", l, "█".repeat((n as usize).saturating_sub(2)), r)
         },
     }
 }
@@ -554,10 +690,10 @@ fn span_as_bar_line(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::fx::{never_complete, parallel, repeating, sequence, with_duration, Direction};
+    use crate::fx::{never_complete, parallel, repeating, sequence, with_duration, Direction, RepeatMode};
     use crate::CellFilter::{AllOf, Inner, Not, Outer, Text};
     use crate::Interpolation::{BounceIn, BounceOut, CircInOut, ElasticOut, QuadOut};
-    use crate::{fx, render_as_ansi_string, CellFilter};
+    use crate::{fx, CellFilter};
     use ratatui::prelude::Margin;
     use ratatui::style::Color::Black;
 
@@ -681,6 +817,85 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_widget_interpolation_column() {
+        let fx = sequence(&[
+            fx::dissolve((500, QuadOut)),
+            parallel(&[
+                fx::coalesce((1000, QuadOut)),
+                fx::fade_to_fg(Black, (1500, BounceIn)),
+            ]),
+        ]);
+
+        let timeline = EffectTimeline::builder()
+            .effect(&fx)
+            .show_interpolation(true)
+            .build();
+        let area = Rect::new(0, 0, 60, 10);
+        let mut buf = Buffer::empty(area);
+        timeline.render(area, &mut buf);
+
+        clear_styling(&mut buf);
+        assert_eq!(buf, Buffer::with_lines([
+            "sequential       *               ███████████████████████████",
+            "├ dissolve       * ip-01   500ms ██████       ▏            ▕",
+            "└ parallel       *               ▏     ▐████████████████████",
+            "  ├ coalesce     * ip-01  1000ms ▏     ▐████████████▌      ▕",
+            "  └ fade_to      * ip-02  1500ms ▁▁▁▁▁▁▐████████████████████",
+            "                                 0ms          1000ms  2000ms",
+            "                                                            ",
+            "             * all          ip-01 QuadOut ▁▃▄▆▇▇██          ",
+            "                            ip-02 BounceIn ▁▁▁▃▂▄▇█         ",
+            "                                                            ",
+        ]));
+    }
+
+    #[test]
+    fn test_widget_iterations() {
+        let fx = sequence(&[
+            fx::repeat(sequence(&[
+                fx::sleep(200),
+                fx::dissolve(300),
+            ]), RepeatMode::Times(3)),
+            fx::ping_pong(sequence(&[
+                fx::sleep(500),
+                fx::coalesce(500),
+            ])),
+        ]);
+
+        let timeline = EffectTimeline::builder().effect(&fx).build();
+        let area = Rect::new(0, 0, 50, 10);
+        let mut buf = Buffer::empty(area);
+        timeline.render(area, &mut buf);
+
+        clear_styling(&mut buf);
+        assert_eq!(buf, Buffer::with_lines([
+            "sequential         * █████████████████████████████",
+            "├ repeat           * ████▌███▌██▌  ▏             ▕",
+            "│ └ sequential     * ███▌░░░░░░░░  ▏             ▕",
+            "│   ├ sleep        * ██  ░   ░     ▏             ▕",
+            "│   └ dissolve     * ▁▐█▌▁░░░▁░░░▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁",
+            "└ ping_pong        * ▏           ████████▌████████",
+            "  └ sequential     * ▏           ████████░░░░░░░░░",
+            "    ├ sleep        * ▏           ████        ░░░░░",
+            "    └ coalesce     * ▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▐███░░░░▁▁▁▁▁",
+            "                     0ms           1750ms   3500ms",
+        ]));
+    }
+
+    #[test]
+    fn test_write_to() {
+        let fx = example_complex_fx();
+        let timeline = EffectTimeline::builder().effect(&fx).build();
+
+        let mut out: Vec<u8> = Vec::new();
+        timeline.write_to(&mut out, 100).unwrap();
+
+        let ansi = timeline.to_ansi_string(100);
+        assert_eq!(String::from_utf8(out).unwrap(), ansi);
+        assert!(ansi.lines().all(|line| line.contains("\x1b[0m")));
+    }
+
     fn clear_styling(buf: &mut Buffer) {
         buf.content.iter_mut().for_each(|cell| {
             cell.set_fg(Color::Reset);
@@ -702,7 +917,7 @@ mod tests {
         let mut buf = Buffer::empty(area);
         timeline.render(area, &mut buf);
 
-        let ansi_escaped_string = render_as_ansi_string(&buf);
+        let ansi_escaped_string = crate::render_as_ansi_string(&buf);
         println!("{}", ansi_escaped_string);
     }
 }
@@ -0,0 +1,206 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{StatefulWidget, Widget};
+
+use crate::{Duration, Effect, Shader};
+
+/// The phase of a widget managed by [FxLifecycle].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LifecyclePhase {
+    /// The intro effect is playing; the initial phase.
+    #[default]
+    Entering,
+    /// The intro has completed, and the widget is rendered as is.
+    Visible,
+    /// The widget has been [dismissed](FxLifecycleState::dismiss) and the outro
+    /// effect is playing.
+    Exiting,
+    /// The outro has completed; the widget is no longer rendered and may be removed.
+    Removed,
+}
+
+/// Wraps a widget with intro and outro effects, played when the widget first renders
+/// and when it is dismissed, respectively.
+///
+/// `FxLifecycle` is a [StatefulWidget], created anew for each frame, while the effects
+/// and the current [phase](LifecyclePhase) are kept in a [FxLifecycleState] across
+/// frames. Once the outro has completed, the widget is no longer rendered and the
+/// state reports it as [removable](FxLifecycleState::is_removable).
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::{Block, StatefulWidget};
+/// use tachyonfx::{fx, Duration, Interpolation};
+/// use tachyonfx::widget::{FxLifecycle, FxLifecycleState};
+///
+/// let area = Rect::new(0, 0, 20, 5);
+/// let mut buf = Buffer::empty(area);
+///
+/// let mut popup = FxLifecycleState::default()
+///     .with_intro(fx::coalesce((300, Interpolation::QuadOut)))
+///     .with_outro(fx::dissolve((300, Interpolation::QuadIn)));
+///
+/// // each frame
+/// let elapsed = Duration::from_millis(16);
+/// FxLifecycle::new(Block::bordered(), elapsed).render(area, &mut buf, &mut popup);
+///
+/// // e.g. on a key press
+/// popup.dismiss();
+///
+/// // once the outro has completed
+/// if popup.is_removable() {
+///     // drop the popup
+/// }
+/// ```
+pub struct FxLifecycle<W> {
+    widget: W,
+    last_tick: Duration,
+}
+
+impl<W> FxLifecycle<W> {
+    /// Wraps the widget, advancing the lifecycle effects by `last_tick` when rendered.
+    pub fn new(widget: W, last_tick: Duration) -> Self {
+        Self { widget, last_tick }
+    }
+}
+
+/// The state of a [FxLifecycle], holding the intro and outro effects.
+#[derive(Default)]
+pub struct FxLifecycleState {
+    intro: Option<Effect>,
+    outro: Option<Effect>,
+    phase: LifecyclePhase,
+}
+
+impl FxLifecycleState {
+    /// Sets the effect played when the widget is first rendered.
+    pub fn with_intro(mut self, intro: Effect) -> Self {
+        self.intro = Some(intro);
+        self
+    }
+
+    /// Sets the effect played after the widget has been dismissed.
+    pub fn with_outro(mut self, outro: Effect) -> Self {
+        self.outro = Some(outro);
+        self
+    }
+
+    /// Returns the current phase.
+    pub fn phase(&self) -> LifecyclePhase {
+        self.phase
+    }
+
+    /// Starts the outro, interrupting the intro if it is still playing. Has no effect
+    /// if the widget has already been dismissed.
+    pub fn dismiss(&mut self) {
+        if matches!(self.phase, LifecyclePhase::Entering | LifecyclePhase::Visible) {
+            self.phase = LifecyclePhase::Exiting;
+        }
+    }
+
+    /// Returns `true` once the outro has completed, after which the widget is no
+    /// longer rendered.
+    pub fn is_removable(&self) -> bool {
+        self.phase == LifecyclePhase::Removed
+    }
+
+    /// Resets the effects and returns to the [entering](LifecyclePhase::Entering)
+    /// phase, replaying the intro on the next render.
+    pub fn remount(&mut self) {
+        self.intro.iter_mut().chain(self.outro.iter_mut()).for_each(Effect::reset);
+        self.phase = LifecyclePhase::Entering;
+    }
+
+    fn process(&mut self, last_tick: Duration, buf: &mut Buffer, area: Rect) {
+        let effect = match self.phase {
+            LifecyclePhase::Entering => self.intro.as_mut(),
+            LifecyclePhase::Exiting  => self.outro.as_mut(),
+            _                        => return,
+        };
+
+        let done = match effect {
+            Some(effect) => {
+                effect.process(last_tick, buf, area);
+                effect.done()
+            },
+            None => true,
+        };
+
+        if done {
+            self.phase = match self.phase {
+                LifecyclePhase::Entering => LifecyclePhase::Visible,
+                _                        => LifecyclePhase::Removed,
+            };
+        }
+    }
+}
+
+impl<W: Widget> StatefulWidget for FxLifecycle<W> {
+    type State = FxLifecycleState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if state.is_removable() {
+            return;
+        }
+
+        self.widget.render(area, buf);
+        state.process(self.last_tick, buf, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+    use ratatui::widgets::Paragraph;
+
+    use crate::fx;
+
+    use super::*;
+
+    fn render(state: &mut FxLifecycleState, elapsed: Duration) -> Buffer {
+        let area = Rect::new(0, 0, 2, 1);
+        let mut buf = Buffer::empty(area);
+        FxLifecycle::new(Paragraph::new("ab"), elapsed)
+            .render(area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn test_lifecycle_phases() {
+        let mut state = FxLifecycleState::default()
+            .with_intro(fx::fade_from_fg(Color::Black, 100))
+            .with_outro(fx::fade_to_fg(Color::Black, 100));
+        assert_eq!(state.phase(), LifecyclePhase::Entering);
+
+        render(&mut state, Duration::from_millis(50));
+        assert_eq!(state.phase(), LifecyclePhase::Entering);
+        render(&mut state, Duration::from_millis(50));
+        assert_eq!(state.phase(), LifecyclePhase::Visible);
+        assert_eq!(render(&mut state, Duration::from_millis(50)), Buffer::with_lines(["ab"]));
+
+        state.dismiss();
+        assert_eq!(state.phase(), LifecyclePhase::Exiting);
+        let buf = render(&mut state, Duration::from_millis(100));
+        assert_eq!(buf[(0, 0)].fg, Color::Black);
+        assert!(state.is_removable());
+
+        // removed widgets render nothing
+        assert_eq!(render(&mut state, Duration::from_millis(50)), Buffer::with_lines(["  "]));
+
+        state.remount();
+        assert_eq!(state.phase(), LifecyclePhase::Entering);
+    }
+
+    #[test]
+    fn test_dismiss_without_outro() {
+        let mut state = FxLifecycleState::default();
+        render(&mut state, Duration::from_millis(10));
+        assert_eq!(state.phase(), LifecyclePhase::Visible);
+
+        state.dismiss();
+        render(&mut state, Duration::from_millis(10));
+        assert!(state.is_removable());
+    }
+}
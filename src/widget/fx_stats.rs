@@ -0,0 +1,193 @@
+use std::collections::VecDeque;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, Widget};
+
+use crate::{Duration, EffectCost, EffectManager};
+
+/// The number of frames the frame rate is averaged over.
+const FPS_WINDOW: usize = 30;
+
+/// A performance overlay showing the frame rate, the number of active effects and the
+/// time spent processing each effect.
+///
+/// `FxStats` is kept across frames: call [record](FxStats::record) once per frame, after
+/// processing the effects, then render it on top of the frame. Per-effect timings are
+/// only available when the [EffectManager] is
+/// [instrumented](EffectManager::set_instrumented). The overlay can be toggled at
+/// runtime; hidden overlays render nothing.
+///
+/// # Example
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Widget;
+/// use tachyonfx::{fx, Duration, EffectManager, Interpolation};
+/// use tachyonfx::widget::FxStats;
+///
+/// let screen = Rect::new(0, 0, 60, 20);
+/// let mut buf = Buffer::empty(screen);
+///
+/// let mut effects = EffectManager::default();
+/// effects.set_instrumented(true);
+/// effects.add_effect(fx::dissolve((500, Interpolation::Linear)));
+///
+/// let mut stats = FxStats::default();
+///
+/// // each frame
+/// let elapsed = Duration::from_millis(16);
+/// effects.process_effects(elapsed, &mut buf, screen);
+/// stats.record(elapsed, &effects);
+/// stats.render(Rect::new(36, 0, 24, 5), &mut buf);
+///
+/// // e.g. on a key press
+/// stats.toggle();
+/// ```
+#[derive(Clone, Debug)]
+pub struct FxStats {
+    visible: bool,
+    frame_times: VecDeque<Duration>,
+    effect_count: usize,
+    costs: Vec<EffectCost>,
+    style: Style,
+    label_style: Style,
+    value_style: Style,
+}
+
+impl Default for FxStats {
+    fn default() -> Self {
+        Self {
+            visible: true,
+            frame_times: VecDeque::with_capacity(FPS_WINDOW),
+            effect_count: 0,
+            costs: Vec::new(),
+            style: Style::default().bg(Color::Black),
+            label_style: Style::default().fg(Color::DarkGray),
+            value_style: Style::default().fg(Color::Gray),
+        }
+    }
+}
+
+impl FxStats {
+    /// Sets the style of the overlay's background and border.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the styles of the labels and values.
+    pub fn with_text_styles(mut self, label_style: Style, value_style: Style) -> Self {
+        self.label_style = label_style;
+        self.value_style = value_style;
+        self
+    }
+
+    /// Records the duration of the last frame, along with the active effect count and
+    /// effect costs of the manager.
+    pub fn record(&mut self, frame_time: Duration, effects: &EffectManager) {
+        if self.frame_times.len() == FPS_WINDOW {
+            self.frame_times.pop_front();
+        }
+        self.frame_times.push_back(frame_time);
+
+        self.effect_count = effects.effect_count();
+        self.costs.clear();
+        self.costs.extend_from_slice(effects.effect_costs());
+    }
+
+    /// Returns the frame rate, averaged over the most recently recorded frames.
+    pub fn fps(&self) -> f32 {
+        let total: f32 = self.frame_times.iter().map(Duration::as_secs_f32).sum();
+        if total > 0.0 {
+            self.frame_times.len() as f32 / total
+        } else {
+            0.0
+        }
+    }
+
+    /// Shows or hides the overlay.
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Shows or hides the overlay.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    /// Returns `true` if the overlay is shown.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn stat_line(&self, label: &str, value: String, width: usize) -> Line<'static> {
+        let padding = width.saturating_sub(label.chars().count() + value.chars().count());
+        Line::from(vec![
+            Span::styled(label.to_string(), self.label_style),
+            Span::raw(" ".repeat(padding)),
+            Span::styled(value, self.value_style),
+        ])
+    }
+}
+
+impl Widget for &FxStats {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        if !self.visible {
+            return;
+        }
+
+        let block = Block::bordered()
+            .title(" fx stats ")
+            .style(self.style);
+        let inner = block.inner(area);
+        Clear.render(area, buf);
+        block.render(area, buf);
+
+        let width = inner.width as usize;
+        let lines = [
+            self.stat_line("fps", format!("{:.1}", self.fps()), width),
+            self.stat_line("effects", self.effect_count.to_string(), width),
+        ].into_iter().chain(self.costs.iter().map(|cost| {
+            let micros = cost.process_time.as_secs_f64() * 1_000_000.0;
+            self.stat_line(cost.name, format!("{:.0}µs", micros), width)
+        }));
+
+        lines.zip(inner.rows())
+            .for_each(|(line, row)| line.render(row, buf));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_stats() {
+        let area = Rect::new(0, 0, 16, 4);
+        let mut buf = Buffer::with_lines(["xxxxxxxxxxxxxxxx"; 5]);
+
+        let mut stats = FxStats::default();
+        let effects = EffectManager::default();
+        (0..4).for_each(|_| stats.record(Duration::from_millis(20), &effects));
+        assert_eq!(stats.fps().round(), 50.0);
+
+        stats.render(area, &mut buf);
+        buf.content.iter_mut().for_each(|c| { c.set_style(Style::reset()); });
+        assert_eq!(buf, Buffer::with_lines([
+            "┌ fx stats ────┐",
+            "│fps       50.0│",
+            "│effects      0│",
+            "└──────────────┘",
+            "xxxxxxxxxxxxxxxx",
+        ]));
+
+        // hidden overlays leave the buffer untouched
+        let mut buf = Buffer::with_lines(["xxxxxxxxxxxxxxxx"; 4]);
+        stats.toggle();
+        stats.render(area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["xxxxxxxxxxxxxxxx"; 4]));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::widget::EffectSpan;
+use crate::Interpolation;
+
+/// Sparkline characters used for previewing interpolation curves in the legend.
+const CURVE_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+const CURVE_SAMPLES: usize = 8;
+
+#[derive(Clone)]
+pub(crate) struct InterpolationRegistry {
+    interpolations: Vec<Interpolation>,
+}
+
+impl InterpolationRegistry {
+    pub(crate) fn from(root_span: &EffectSpan) -> Self {
+        let mut this = Self { interpolations: Vec::new() };
+
+        root_span.iter()
+            .filter(|span| span.children.is_empty())
+            .filter_map(|span| span.interpolation)
+            .for_each(|interpolation| this.register(interpolation));
+
+        this
+    }
+
+    pub(crate) fn empty() -> Self {
+        Self { interpolations: Vec::new() }
+    }
+
+    /// Returns the id and duration of a leaf span's interpolation, or blanks for
+    /// spans without a timer or with children.
+    pub(crate) fn id_of(&self, span: &EffectSpan) -> String {
+        let interpolation = span.interpolation
+            .filter(|_| span.children.is_empty())
+            .and_then(|i| self.position(i));
+
+        match interpolation {
+            None => String::new(),
+            Some(idx) => {
+                let ms = ((span.end - span.start) * 1000.0).round() as u32;
+                format!("{} {:>5}ms", format_id(idx), ms)
+            }
+        }
+    }
+
+    pub(crate) fn entries(&self) -> Vec<(String, String)> {
+        self.interpolations.iter().enumerate()
+            .map(|(idx, i)| (format_id(idx), format!("{:?} {}", i, curve_of(*i))))
+            .collect()
+    }
+
+    fn register(&mut self, interpolation: Interpolation) {
+        if self.position(interpolation).is_none() {
+            self.interpolations.push(interpolation);
+        }
+    }
+
+    fn position(&self, interpolation: Interpolation) -> Option<usize> {
+        self.interpolations.iter().position(|i| *i == interpolation)
+    }
+}
+
+fn format_id(idx: usize) -> String {
+    format!("ip-{:02}", idx + 1)
+}
+
+/// Renders the curve of the interpolation as a small sparkline.
+fn curve_of(interpolation: Interpolation) -> String {
+    let last = CURVE_LEVELS.len() - 1;
+    (0..CURVE_SAMPLES)
+        .map(|i| interpolation.alpha(i as f32 / (CURVE_SAMPLES - 1) as f32))
+        .map(|a| (a.clamp(0.0, 1.0) * last as f32).round() as usize)
+        .map(|level| CURVE_LEVELS[level])
+        .collect()
+}
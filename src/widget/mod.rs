@@ -3,11 +3,20 @@ mod effect_span;
 mod color_resolver;
 mod cell_filter_registry;
 mod area_registry;
+mod interpolation_registry;
+mod cell_filter_preview;
+mod area_overlay;
+mod fx_lifecycle;
+mod fx_stats;
 
-pub(crate) use effect_span::EffectSpan;
+pub(crate) use effect_span::{EffectSpan, SpanIteration, StableHasher};
 pub(crate) use cell_filter_registry::CellFilterRegistry;
 pub(crate) use color_resolver::ColorResolver;
 
+pub use area_overlay::{AreaOverlay, AreaOverlayBuilderBuilder};
+pub use cell_filter_preview::CellFilterPreview;
+pub use fx_lifecycle::{FxLifecycle, FxLifecycleState, LifecyclePhase};
+pub use fx_stats::FxStats;
 pub use effect_timeline::{
     EffectTimeline,
     EffectTimelineBuilderBuilder,